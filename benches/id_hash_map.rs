@@ -0,0 +1,42 @@
+//! Compares lookups in `NameId` keyed maps using the default SipHash hasher
+//! and `IdentityBuildHasher`.
+//!
+//! Run with `cargo bench --bench id_hash_map --features std`.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use name_id::{IdentityBuildHasher, NameId};
+
+const ENTRIES: u64 = 10_000;
+const ROUNDS: u32 = 200;
+
+fn bench<S: BuildHasher + Default>(name: &str, ids: &[NameId]) -> Duration {
+    let map: HashMap<NameId, u64, S> = ids.iter().map(|id| (*id, id.value())).collect();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for id in ids {
+            black_box(map.get(black_box(id)));
+        }
+    }
+    let elapsed = start.elapsed();
+    let per_lookup = elapsed / (ROUNDS * ids.len() as u32);
+    println!("{name:>24}: {per_lookup:?} per lookup");
+    elapsed
+}
+
+fn main() {
+    let ids: Vec<NameId> = (0..ENTRIES)
+        .map(|i| NameId::new(format!("bench_entity_{i}")))
+        .collect();
+
+    let sip = bench::<std::collections::hash_map::RandomState>("SipHash", &ids);
+    let identity = bench::<IdentityBuildHasher>("IdentityBuildHasher", &ids);
+    println!(
+        "{:>24}: {:.2}x",
+        "speedup",
+        sip.as_secs_f64() / identity.as_secs_f64()
+    );
+}
@@ -0,0 +1,68 @@
+//! Compares building and querying `std::collections::HashMap<NameId, V>` with
+//! the default hasher against `IdMap<V>`.
+//!
+//! Run with `cargo bench --bench id_map --features std,collections`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use name_id::{IdMap, NameId};
+
+const ENTRIES: u64 = 10_000;
+const ROUNDS: u32 = 200;
+
+fn bench(name: &str, mut round: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        round();
+    }
+    let elapsed = start.elapsed();
+    let per_entry = elapsed / (ROUNDS * ENTRIES as u32);
+    println!("{name:>24}: {per_entry:?} per entry");
+    elapsed
+}
+
+fn compare(what: &str, std: Duration, id_map: Duration) {
+    println!(
+        "{:>24}: {:.2}x",
+        format!("{what} speedup"),
+        std.as_secs_f64() / id_map.as_secs_f64()
+    );
+}
+
+fn main() {
+    let ids: Vec<NameId> = (0..ENTRIES)
+        .map(|i| NameId::new(format!("bench_entity_{i}")))
+        .collect();
+
+    let std_insert = bench("HashMap insert", || {
+        let mut map = HashMap::with_capacity(ids.len());
+        for id in &ids {
+            map.insert(*id, id.value());
+        }
+        black_box(map);
+    });
+    let id_map_insert = bench("IdMap insert", || {
+        let mut map = IdMap::with_capacity(ids.len());
+        for id in &ids {
+            map.insert(*id, id.value());
+        }
+        black_box(map);
+    });
+    compare("insert", std_insert, id_map_insert);
+
+    let std_map: HashMap<NameId, u64> = ids.iter().map(|id| (*id, id.value())).collect();
+    let id_map: IdMap<u64> = ids.iter().map(|id| (*id, id.value())).collect();
+    let std_get = bench("HashMap get", || {
+        for id in &ids {
+            black_box(std_map.get(black_box(id)));
+        }
+    });
+    let id_map_get = bench("IdMap get", || {
+        for id in &ids {
+            black_box(id_map.get(black_box(id)));
+        }
+    });
+    compare("get", std_get, id_map_get);
+}
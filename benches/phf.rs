@@ -0,0 +1,41 @@
+//! Compares lookups in a `NameIdPhf` perfect hash table against binary search
+//! in a `NameIdTable` with the same entries.
+//!
+//! Run with `cargo bench --bench phf`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use name_id::NameId;
+
+include!("../tests/fixtures/phf_table.rs");
+
+const ROUNDS: u32 = 2_000;
+
+fn bench(name: &str, ids: &[NameId], get: impl Fn(NameId) -> Option<&'static u32>) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for id in ids {
+            black_box(get(black_box(*id)));
+        }
+    }
+    let elapsed = start.elapsed();
+    let per_lookup = elapsed / (ROUNDS * ids.len() as u32);
+    println!("{name:>24}: {per_lookup:?} per lookup");
+    elapsed
+}
+
+fn main() {
+    // half of the lookups miss
+    let ids: Vec<NameId> = (0..ENTRIES * 2)
+        .map(|i| NameId::new(format!("command_{i}")))
+        .collect();
+
+    let table = bench("NameIdTable", &ids, |id| TABLE.get(id));
+    let phf = bench("NameIdPhf", &ids, |id| PHF.get(id));
+    println!(
+        "{:>24}: {:.2}x",
+        "speedup",
+        table.as_secs_f64() / phf.as_secs_f64()
+    );
+}
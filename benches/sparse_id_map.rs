@@ -0,0 +1,66 @@
+//! Compares iteration over values of `SparseIdMap` and `IdMap`.
+//!
+//! Run with `cargo bench --bench sparse_id_map --features std,collections`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use name_id::{IdMap, NameId, SparseIdMap};
+
+const ENTRIES: u64 = 10_000;
+const ROUNDS: u32 = 2_000;
+
+#[derive(Clone, Copy)]
+struct Transform {
+    position: [f32; 3],
+    velocity: [f32; 3],
+}
+
+fn bench(name: &str, mut round: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        round();
+    }
+    let elapsed = start.elapsed();
+    let per_value = elapsed / (ROUNDS * ENTRIES as u32);
+    println!("{name:>24}: {per_value:?} per value");
+    elapsed
+}
+
+fn step(transform: &mut Transform) {
+    for axis in 0..3 {
+        transform.position[axis] += transform.velocity[axis];
+    }
+}
+
+fn main() {
+    let entries: Vec<(NameId, Transform)> = (0..ENTRIES)
+        .map(|i| {
+            let transform = Transform {
+                position: [0.0; 3],
+                velocity: [i as f32, 1.0, -1.0],
+            };
+            (NameId::new(format!("bench_entity_{i}")), transform)
+        })
+        .collect();
+    let mut id_map: IdMap<Transform> = entries.iter().copied().collect();
+    let mut sparse: SparseIdMap<Transform> = entries.iter().copied().collect();
+
+    let id_map_time = bench("IdMap values_mut", || {
+        for transform in id_map.values_mut() {
+            step(transform);
+        }
+        black_box(&id_map);
+    });
+    let sparse_time = bench("SparseIdMap values_mut", || {
+        for transform in sparse.values_mut() {
+            step(transform);
+        }
+        black_box(&sparse);
+    });
+    println!(
+        "{:>24}: {:.2}x",
+        "speedup",
+        id_map_time.as_secs_f64() / sparse_time.as_secs_f64()
+    );
+}
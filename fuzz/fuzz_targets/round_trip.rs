@@ -0,0 +1,25 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use name_id::NameId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Arbitrary, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
+struct Packet {
+    kind: NameId,
+    #[arbitrary(with = NameId::arbitrary_named)]
+    source: NameId,
+    targets: Vec<NameId>,
+}
+
+fuzz_target!(|packet: Packet| {
+    let json = serde_json::to_string(&packet).unwrap();
+    assert_eq!(serde_json::from_str::<Packet>(&json).unwrap(), packet);
+
+    let config = bincode::config::standard();
+    let bytes = bincode::encode_to_vec(&packet, config).unwrap();
+    let (decoded, read) = bincode::decode_from_slice::<Packet, _>(&bytes, config).unwrap();
+    assert_eq!(read, bytes.len());
+    assert_eq!(decoded, packet);
+});
@@ -0,0 +1,437 @@
+//! Parsing and expansion of `ids!` blocks.
+
+use std::collections::BTreeMap;
+use std::hash::Hasher as _;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Attribute, Ident, LitStr, Token, Visibility,
+};
+
+use crate::keys::KeyFile;
+use crate::{expand_id, hash_name, Hasher};
+
+/// Separator used to join group names when it isn't overridden.
+const DEFAULT_SEPARATOR: &str = ".";
+
+/// Contents of an `ids!` block or group.
+pub(crate) struct IdsInput {
+    items: Vec<IdsItem>,
+}
+
+enum IdsItem {
+    Group(IdsGroup),
+    Const(IdsConst),
+}
+
+/// `name { ... }` entry, generating a module.
+struct IdsGroup {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    options: GroupOptions,
+    body: IdsInput,
+}
+
+/// Options specified with `#[name_id(...)]` group attributes.
+#[derive(Default)]
+struct GroupOptions {
+    /// Overrides the separator used to join names of group members.
+    separator: Option<String>,
+    /// Generates `from_name` and `from_id` functions.
+    from_name: bool,
+    /// Path of a key file that must list names of all group members.
+    verify: Option<LitStr>,
+    /// Generates `COUNT` constant and `ordinal` and `from_ordinal` functions.
+    ordinal: bool,
+}
+
+/// Joined name and hash of a constant, used to check for duplicates.
+struct DeclaredName {
+    name: String,
+    hash: u64,
+    span: Span,
+}
+
+/// `NAME;` or `NAME = "name";` entry, generating a constant.
+struct IdsConst {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    name: Option<LitStr>,
+}
+
+impl Parse for IdsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            let mut attrs = input.call(Attribute::parse_outer)?;
+            let vis: Visibility = input.parse()?;
+            let ident: Ident = input.parse()?;
+            if input.peek(syn::token::Brace) {
+                let options = take_group_options(&mut attrs)?;
+                let content;
+                syn::braced!(content in input);
+                items.push(IdsItem::Group(IdsGroup {
+                    attrs,
+                    vis,
+                    ident,
+                    options,
+                    body: content.parse()?,
+                }));
+            } else {
+                let name = if input.parse::<Option<Token![=]>>()?.is_some() {
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+                input.parse::<Token![;]>()?;
+                items.push(IdsItem::Const(IdsConst {
+                    attrs,
+                    vis,
+                    ident,
+                    name,
+                }));
+            }
+        }
+        Ok(IdsInput { items })
+    }
+}
+
+/// Removes `#[name_id(...)]` attributes from a group, returning the options
+/// they specify.
+fn take_group_options(attrs: &mut Vec<Attribute>) -> syn::Result<GroupOptions> {
+    let mut options = GroupOptions::default();
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("name_id") {
+            return true;
+        }
+        let parsed = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("separator") {
+                options.separator = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("from_name") {
+                options.from_name = true;
+                Ok(())
+            } else if meta.path.is_ident("verify") {
+                options.verify = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("ordinal") {
+                options.ordinal = true;
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown ids group option, expected `separator`, `from_name`, `verify` or `ordinal`",
+                ))
+            }
+        });
+        if let Err(err) = parsed {
+            match &mut result {
+                Ok(()) => result = Err(err),
+                Err(first) => first.combine(err),
+            }
+        }
+        false
+    });
+    result.map(|_| options)
+}
+
+impl IdsInput {
+    /// Expands items of a group whose joined name is `prefix`.
+    ///
+    /// Items without explicit visibility are made `pub` when `nested`, so
+    /// generated modules are reachable through their parent.
+    fn expand(
+        &self,
+        prefix: &str,
+        separator: &str,
+        nested: bool,
+        declared: &mut Vec<DeclaredName>,
+    ) -> TokenStream {
+        let items = self.items.iter().map(|item| match item {
+            IdsItem::Group(group) => {
+                let IdsGroup {
+                    attrs, ident, body, ..
+                } = group;
+                let vis = item_visibility(&group.vis, nested);
+                let name = join(prefix, separator, &ident.to_string());
+                let separator = group.options.separator.as_deref().unwrap_or(separator);
+                let lookup = if group.options.from_name {
+                    body.name_lookup(&name, separator)
+                } else {
+                    TokenStream::new()
+                };
+                let ordinals = if group.options.ordinal {
+                    body.ordinals(&name, separator, ident.span())
+                } else {
+                    TokenStream::new()
+                };
+                let start = declared.len();
+                let body = body.expand(&name, separator, true, declared);
+                let members = &declared[start..];
+                let verify = match &group.options.verify {
+                    Some(path) => verify_members(path, members),
+                    None => TokenStream::new(),
+                };
+                let fingerprint = fingerprint(members);
+                let len = members.len();
+                quote! {
+                    #(#attrs)*
+                    #vis mod #ident {
+                        #body
+                        #lookup
+                        #ordinals
+                        #verify
+
+                        /// Fingerprint of names of all constants declared in
+                        /// this group and its nested groups.
+                        ///
+                        /// It doesn't depend on the order of declarations,
+                        /// but changes if any of the names changes.
+                        pub const GROUP_FINGERPRINT: u64 = #fingerprint;
+                        /// Number of constants declared in this group and its
+                        /// nested groups.
+                        pub const GROUP_LEN: usize = #len;
+                    }
+                }
+            }
+            IdsItem::Const(constant) => {
+                let IdsConst { attrs, ident, .. } = constant;
+                let vis = item_visibility(&constant.vis, nested);
+                let (own, span) = constant.own_name();
+                let name = join(prefix, separator, &own);
+                let value = expand_id(&name, span);
+                declared.push(DeclaredName {
+                    hash: hash_name(&name),
+                    name,
+                    span,
+                });
+                quote! {
+                    #(#attrs)*
+                    #vis const #ident: name_id::NameId = #value;
+                }
+            }
+        });
+        quote! { #(#items)* }
+    }
+}
+
+impl IdsConst {
+    /// Returns the name of this constant without the group prefix, and the
+    /// span it's declared at.
+    fn own_name(&self) -> (String, Span) {
+        match &self.name {
+            Some(name) => (name.value(), name.span()),
+            None => (self.ident.to_string().to_lowercase(), self.ident.span()),
+        }
+    }
+}
+
+impl IdsInput {
+    /// Generates `from_name` and `from_id` functions for constants declared
+    /// directly in a group whose joined name is `prefix`.
+    fn name_lookup(&self, prefix: &str, separator: &str) -> TokenStream {
+        let mut members = Vec::new();
+        for item in &self.items {
+            if let IdsItem::Const(constant) = item {
+                let (own, _) = constant.own_name();
+                let hash = hash_name(&join(prefix, separator, &own));
+                members.push((own, hash, &constant.ident));
+            }
+        }
+
+        // match on length and first byte before comparing whole names
+        let mut buckets = BTreeMap::<_, Vec<(&str, &Ident)>>::new();
+        for (own, _, ident) in &members {
+            let key = (own.len(), own.as_bytes().first().copied());
+            buckets.entry(key).or_default().push((own.as_str(), *ident));
+        }
+        let name_arms = buckets.iter().map(|((len, first), names)| {
+            let first = match first {
+                Some(byte) => quote! { Some(#byte) },
+                None => quote! { None },
+            };
+            let (names, idents): (Vec<&str>, Vec<&Ident>) = names.iter().copied().unzip();
+            quote! {
+                (#len, #first) => match name {
+                    #(#names => Some(#idents),)*
+                    _ => None,
+                },
+            }
+        });
+        let id_arms = members.iter().map(|(own, hash, _)| {
+            quote! { #hash => Some(#own), }
+        });
+
+        quote! {
+            /// Returns the constant of this group declared with `name`.
+            ///
+            /// Only names declared in the group match exactly, without the
+            /// group prefix. Unlike constructing a `NameId` from `name`, this
+            /// doesn't hash it, so undeclared names are never matched, even
+            /// if their hash is equal to one of a declared constant.
+            pub fn from_name(name: &str) -> Option<name_id::NameId> {
+                match (name.len(), name.as_bytes().first().copied()) {
+                    #(#name_arms)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the name (without the group prefix) of the constant of
+            /// this group whose hash value is equal to `id`.
+            ///
+            /// Matches any id with the same hash value, including ids of
+            /// undeclared names which collide with a declared constant.
+            pub const fn from_id(id: name_id::NameId) -> Option<&'static str> {
+                match id.value() {
+                    #(#id_arms)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+impl IdsInput {
+    /// Generates `COUNT` constant and `ordinal` and `from_ordinal` functions
+    /// for constants declared directly in a group whose joined name is
+    /// `prefix`, declared at `span`.
+    fn ordinals(&self, prefix: &str, separator: &str, span: Span) -> TokenStream {
+        let mut hashes = Vec::new();
+        let mut idents = Vec::new();
+        for item in &self.items {
+            if let IdsItem::Const(constant) = item {
+                let (own, _) = constant.own_name();
+                hashes.push(hash_name(&join(prefix, separator, &own)));
+                idents.push(&constant.ident);
+            }
+        }
+        let Ok(count) = u16::try_from(idents.len()) else {
+            let message = "groups with `ordinal` option can't declare more than 65535 constants";
+            return syn::Error::new(span, message).to_compile_error();
+        };
+        let ordinals: Vec<u16> = (0..count).collect();
+
+        quote! {
+            /// Number of constants declared directly in this group.
+            pub const COUNT: u16 = #count;
+
+            /// Returns the position of the constant equal to `id` among
+            /// constants declared directly in this group.
+            ///
+            /// Ordinals follow declaration order, so reordering or removing
+            /// constants changes them.
+            pub const fn ordinal(id: name_id::NameId) -> Option<u16> {
+                match id.value() {
+                    #(#hashes => Some(#ordinals),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the constant declared at position `ordinal` in this
+            /// group, or `None` if `ordinal` isn't less than `COUNT`.
+            pub const fn from_ordinal(ordinal: u16) -> Option<name_id::NameId> {
+                match ordinal {
+                    #(#ordinals => Some(#idents),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+fn item_visibility(vis: &Visibility, nested: bool) -> TokenStream {
+    match vis {
+        Visibility::Inherited if nested => quote! { pub },
+        other => quote! { #other },
+    }
+}
+
+fn join(prefix: &str, separator: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}{separator}{name}")
+    }
+}
+
+/// Returns the order-insensitive fingerprint of a group with `members`: the
+/// number of members and their sorted hashes fed into the hasher.
+fn fingerprint(members: &[DeclaredName]) -> u64 {
+    let mut hashes: Vec<u64> = members.iter().map(|it| it.hash).collect();
+    hashes.sort_unstable();
+    let mut hasher = Hasher::default();
+    hasher.write_u64(hashes.len() as u64);
+    for hash in hashes {
+        hasher.write_u64(hash);
+    }
+    hasher.finish()
+}
+
+/// Checks joined names of group `members` against the key file at `path`,
+/// returning the item tracking the file and errors for unlisted names.
+fn verify_members(path: &LitStr, members: &[DeclaredName]) -> TokenStream {
+    let file = match KeyFile::read(path) {
+        Ok(it) => it,
+        Err(err) => return err.to_compile_error(),
+    };
+    let track = file.track();
+    let errors = members
+        .iter()
+        .filter_map(|member| file.check(&member.name, member.span).err())
+        .map(|err| err.to_compile_error());
+    quote! {
+        #track
+        #(#errors)*
+    }
+}
+
+/// Reports constants declared more than once, and constants whose names have
+/// equal hashes.
+///
+/// Errors point at the later declaration, with an additional error pointing
+/// at the first one.
+fn check_duplicates(declared: &mut [DeclaredName]) -> syn::Result<()> {
+    // stable sort keeps declaration order of equal hashes
+    declared.sort_by_key(|it| it.hash);
+    let mut result: syn::Result<()> = Ok(());
+    let mut push = |err: syn::Error| match &mut result {
+        Ok(()) => result = Err(err),
+        Err(errors) => errors.combine(err),
+    };
+    for run in declared.chunk_by(|a, b| a.hash == b.hash) {
+        let first = &run[0];
+        for other in &run[1..] {
+            let message = if other.name == first.name {
+                format!("duplicate id name `{}`", other.name)
+            } else {
+                format!(
+                    "id collision: `{}` has the same hash as `{}` ({:#018x})",
+                    other.name, first.name, other.hash
+                )
+            };
+            push(syn::Error::new(other.span, message));
+            push(syn::Error::new(
+                first.span,
+                format!("`{}` first declared here", first.name),
+            ));
+        }
+    }
+    result
+}
+
+pub(crate) fn expand_ids(input: &IdsInput) -> TokenStream {
+    let mut declared = Vec::new();
+    let items = input.expand("", DEFAULT_SEPARATOR, false, &mut declared);
+    // items are kept on errors, so uses of them don't cause further errors
+    let errors = check_duplicates(&mut declared)
+        .err()
+        .map(|err| err.to_compile_error());
+    quote! {
+        #items
+        #errors
+    }
+}
@@ -0,0 +1,150 @@
+//! Checking of names against key files, for `verify_ids_in!` and `verify`
+//! option of `ids!` groups.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    LitStr, Token,
+};
+
+/// `"keys.txt"; "name", ...` input of `verify_ids_in!`.
+pub(crate) struct VerifyInput {
+    path: LitStr,
+    names: Punctuated<LitStr, Token![,]>,
+}
+
+impl Parse for VerifyInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(VerifyInput {
+            path,
+            names: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// Names listed in a key file.
+pub(crate) struct KeyFile {
+    /// Path as written in the macro input.
+    relative: String,
+    /// Canonical path of the file, used to track changes to it.
+    full_path: String,
+    keys: BTreeSet<String>,
+}
+
+impl KeyFile {
+    /// Reads the key file at `path`, which is relative to the directory of
+    /// the source file it's written in.
+    ///
+    /// Each non-empty line of the file is a key, with surrounding whitespace
+    /// removed. Lines starting with `#` are comments.
+    pub(crate) fn read(path: &LitStr) -> syn::Result<Self> {
+        let relative = path.value();
+        let dir = path
+            .span()
+            .unwrap()
+            .local_file()
+            .and_then(|file| file.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| {
+                PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+            });
+        let error = |err: std::io::Error| {
+            syn::Error::new(
+                path.span(),
+                format!("can't read key file `{relative}`: {err}"),
+            )
+        };
+        let full_path = std::fs::canonicalize(dir.join(&relative)).map_err(error)?;
+        let contents = std::fs::read_to_string(&full_path).map_err(error)?;
+        let Some(full_path) = full_path.to_str() else {
+            return Err(syn::Error::new(path.span(), "path must be valid utf-8"));
+        };
+        let keys = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+        Ok(KeyFile {
+            relative,
+            full_path: full_path.to_string(),
+            keys,
+        })
+    }
+
+    /// Returns an error pointing at `span` if `name` isn't listed in the
+    /// file, suggesting the most similar key.
+    pub(crate) fn check(&self, name: &str, span: Span) -> syn::Result<()> {
+        if self.keys.contains(name) {
+            return Ok(());
+        }
+        let mut message = format!("`{name}` isn't listed in key file `{}`", self.relative);
+        if let Some(similar) = self.most_similar(name) {
+            message.push_str(&format!(", did you mean `{similar}`?"));
+        }
+        Err(syn::Error::new(span, message))
+    }
+
+    /// Returns an item that makes the crate depend on the file, so changes
+    /// to it trigger a rebuild.
+    pub(crate) fn track(&self) -> TokenStream {
+        let full_path = &self.full_path;
+        quote! {
+            const _: &[u8] = include_bytes!(#full_path);
+        }
+    }
+
+    /// Returns the key with the smallest edit distance to `name`, if it's
+    /// close enough to be a likely typo.
+    fn most_similar(&self, name: &str) -> Option<&str> {
+        let name: Vec<char> = name.chars().collect();
+        let threshold = (name.len() / 3).max(2);
+        let mut row = Vec::with_capacity(name.len() + 1);
+        self.keys
+            .iter()
+            .map(|key| (edit_distance(&name, key, &mut row), key.as_str()))
+            .filter(|(distance, _)| *distance <= threshold)
+            .min()
+            .map(|(_, key)| key)
+    }
+}
+
+/// Levenshtein distance between `a` and `b` in characters, using `row` as
+/// scratch space.
+fn edit_distance(a: &[char], b: &str, row: &mut Vec<usize>) -> usize {
+    row.clear();
+    row.extend(0..=a.len());
+    for (j, b) in b.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = j + 1;
+        for (i, a) in a.iter().enumerate() {
+            let substitution = diagonal + usize::from(*a != b);
+            diagonal = row[i + 1];
+            row[i + 1] = substitution.min(row[i] + 1).min(diagonal + 1);
+        }
+    }
+    row[a.len()]
+}
+
+pub(crate) fn expand_verify(input: &VerifyInput) -> TokenStream {
+    let file = match KeyFile::read(&input.path) {
+        Ok(it) => it,
+        Err(err) => return err.to_compile_error(),
+    };
+    let track = file.track();
+    let errors = input
+        .names
+        .iter()
+        .filter_map(|name| file.check(&name.value(), name.span()).err())
+        .map(|err| err.to_compile_error());
+    quote! {
+        #track
+        #(#errors)*
+    }
+}
@@ -1,8 +1,16 @@
-use std::hash::{Hasher as _, Hash};
+use std::hash::{Hash, Hasher as _};
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, parse::Parse, Lit};
+use syn::{parse::Parse, parse_macro_input, Lit};
+
+mod ids;
+mod keys;
+#[cfg(feature = "portable_hash")]
+mod portable;
+mod registry;
+#[cfg(all(feature = "ahash", not(feature = "portable_hash")))]
+mod seeded;
 
 macro_rules! assert_unique_feature {
     () => {};
@@ -16,11 +24,15 @@ macro_rules! assert_unique_feature {
 }
 assert_unique_feature!("ahash");
 
-#[cfg(feature = "ahash")]
-type Hasher = ahash::AHasher;
+#[cfg(all(feature = "ahash", not(feature = "portable_hash")))]
+type Hasher = seeded::SeededHasher;
+#[cfg(feature = "portable_hash")]
+type Hasher = portable::PortableHasher;
 
 struct IdInput {
-    name: String
+    /// Key specified with `key = ...;` prefix.
+    key: Option<u64>,
+    name: String,
 }
 
 fn stringify_stream(input: &syn::parse::ParseStream) -> syn::Result<String> {
@@ -31,15 +43,20 @@ fn stringify_stream(input: &syn::parse::ParseStream) -> syn::Result<String> {
             Ok(Lit::Str(s)) => s.value(),
             Ok(Lit::ByteStr(s)) => match std::str::from_utf8(&s.value()) {
                 Ok(it) => it.to_string(),
-                Err(_) => return Err(syn::Error::new(s.span(), "id must be a valid utf-8 string"))
+                Err(_) => return Err(syn::Error::new(s.span(), "id must be a valid utf-8 string")),
             },
             Ok(Lit::CStr(s)) => match s.value().into_string() {
                 Ok(it) => it,
-                Err(_) => return Err(syn::Error::new(s.span(), "id must be a valid utf-8 string"))
+                Err(_) => return Err(syn::Error::new(s.span(), "id must be a valid utf-8 string")),
             },
             Ok(Lit::Byte(b)) => match std::str::from_utf8(&[b.value()]) {
                 Ok(it) => it.to_string(),
-                Err(_) => return Err(syn::Error::new(b.span(), "byte not a valid utf-8 character"))
+                Err(_) => {
+                    return Err(syn::Error::new(
+                        b.span(),
+                        "byte not a valid utf-8 character",
+                    ))
+                }
             },
             Ok(Lit::Char(c)) => c.value().to_string(),
             Ok(Lit::Int(int)) => int.base10_digits().to_string(),
@@ -49,21 +66,29 @@ fn stringify_stream(input: &syn::parse::ParseStream) -> syn::Result<String> {
                 // - some values in the source code will differ to what is actually stored due to rounding errors
                 //   - which will cause unexpected behaviors as compile time and runtime floats will be differently handled
                 //   - formatting floats to strings is also not an option because it will differ from actual input
-                return Err(syn::Error::new(f.span(), "can't make id from floats due to non-injective source->value mapping"));
-            },
-            Ok(Lit::Bool(b)) => if b.value() {
-                "true".to_string()
-            } else {
-                "false".to_string()
-            },
+                return Err(syn::Error::new(
+                    f.span(),
+                    "can't make id from floats due to non-injective source->value mapping",
+                ));
+            }
+            Ok(Lit::Bool(b)) => {
+                if b.value() {
+                    "true".to_string()
+                } else {
+                    "false".to_string()
+                }
+            }
             Ok(other) => {
-                return Err(syn::Error::new(other.span(), "can't make id from this literal"));
+                return Err(syn::Error::new(
+                    other.span(),
+                    "can't make id from this literal",
+                ));
             }
-            _ => unreachable!("unexpected value instead of literal")
+            _ => unreachable!("unexpected value instead of literal"),
         }
     } else if input.peek(syn::Lifetime) {
         let lifetime: syn::Lifetime = input.parse()?;
-        format!("'{}", lifetime.ident.to_string())
+        format!("'{}", lifetime.ident)
     } else {
         return Err(input.error("unsupported id macro value"));
     })
@@ -71,44 +96,858 @@ fn stringify_stream(input: &syn::parse::ParseStream) -> syn::Result<String> {
 
 impl Parse for IdInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let key = if input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+            let ident: syn::Ident = input.parse()?;
+            if ident != "key" {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "unknown id option, expected `key`",
+                ));
+            }
+            input.parse::<syn::Token![=]>()?;
+            let key: syn::LitInt = input.parse()?;
+            input.parse::<syn::Token![;]>()?;
+            Some(key.base10_parse::<u64>()?)
+        } else {
+            None
+        };
         let mut name = stringify_stream(&input)?;
         while !input.is_empty() {
             name.push(' ');
             name.push_str(stringify_stream(&input)?.as_str());
         }
 
-        Ok(IdInput {
-            name
-        })
+        Ok(IdInput { key, name })
     }
 }
 
 /// Macro that produces a constant [`NameId`] value at compile time.
-/// 
+///
 /// It's equivalent to calling `name_id::NameId::from_raw(hash, name)` where
-/// hash is the appropriate hash value for `name`.
-/// 
+/// hash is the appropriate hash value for `name`. If `debug_name` feature is
+/// disabled, `name` argument is omitted.
+///
 /// When used with `name-id` crate, this macro will inherit and use the same
 /// hashing algorithm as specified with crate features (`ahash` being the
 /// default).
-/// 
+///
+/// With `inventory` feature, each invocation without a key also records the
+/// name and id in a list returned by `declared_ids`.
+///
+/// A `key = <integer>;` prefix produces the same id as `NameId::new_keyed`
+/// with that key:
+///
+/// ```ignore
+/// const SWORD: NameId = id!(key = 0xDEADBEEF; "item.sword");
+/// assert_eq!(SWORD, NameId::new_keyed(0xDEADBEEF, "item.sword"));
+/// ```
+///
+/// With `dynamic_hasher` feature, hash values are only known at runtime, so
+/// this and all other macros computing them expand into a compile error.
+///
 #[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
 #[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
 #[proc_macro]
 pub fn id(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("id!") {
+        return err;
+    }
     let input = parse_macro_input!(tokens as IdInput);
-    let ident = input.name;
+    let span = proc_macro2::Span::call_site();
+    match input.key {
+        Some(key) => expand_hashed(&input.name, hash_keyed(key, &input.name), span, false).into(),
+        None => expand_id(&input.name, span).into(),
+    }
+}
+
+/// Macro that produces the `u64` hash value of a name at compile time.
+///
+/// Accepts the same input as [`id!`] and expands into an integer literal equal
+/// to `id!(..).value()`, so it can be used where a [`NameId`] expression can't,
+/// such as `match` patterns and `#[repr(u64)]` enum discriminants:
+///
+/// ```ignore
+/// match id.value() {
+///     id_value!(player_spawn) => spawn_player(),
+///     _ => {}
+/// }
+/// ```
+///
+/// The literal carries no name, so it bypasses `debug_name` labels, the
+/// collision registry and the `inventory` list entirely.
+///
+#[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
+#[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
+#[proc_macro]
+pub fn id_value(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("id_value!") {
+        return err;
+    }
+    let input = parse_macro_input!(tokens as IdInput);
+    let hash = match input.key {
+        Some(key) => hash_keyed(key, &input.name),
+        None => hash_name(&input.name),
+    };
+    quote! { #hash }.into()
+}
+
+/// Macro that produces a constant [`NameId`] from the hash of a file's
+/// contents at compile time.
+///
+/// The path is relative to the directory of the manifest (`Cargo.toml`) of
+/// the crate using the macro:
+///
+/// ```ignore
+/// const SHADER_ID: NameId = content_id!("shaders/blit.wgsl");
+/// assert_eq!(SHADER_ID, NameId::from_bytes_content(&std::fs::read("shaders/blit.wgsl")?));
+/// ```
+///
+/// The file is read as raw bytes and hashed the same way as
+/// `NameId::from_bytes_content` hashes them. The macro depends on the file
+/// the same way `include_bytes!` does, so changes to it recompile the crate.
+/// With `debug_name` feature, the path is used as the debug label.
+///
+/// Unlike [`id!`], the path isn't checked against the workspace registry nor
+/// recorded by `inventory` feature, as the id isn't a hash of the path.
+///
+#[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
+#[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
+#[proc_macro]
+pub fn content_id(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("content_id!") {
+        return err;
+    }
+    let path = parse_macro_input!(tokens as syn::LitStr);
+    let relative = path.value();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = std::path::Path::new(&manifest_dir).join(&relative);
+    let contents = match std::fs::read(&full_path) {
+        Ok(it) => it,
+        Err(err) => {
+            let message = format!("can't read `{relative}` in crate manifest directory: {err}");
+            return syn::Error::new(path.span(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+    let Some(full_path) = full_path.to_str() else {
+        return syn::Error::new(path.span(), "path must be valid utf-8")
+            .to_compile_error()
+            .into();
+    };
+
+    // must match `NameId::from_bytes_content` in `name-id` crate
     let mut hasher = Hasher::default();
-    ident.hash(&mut hasher);
+    contents[..].hash(&mut hasher);
     let hash = hasher.finish();
-    let entry = if cfg!(debug_assertions) {
+
+    let entry = if cfg!(feature = "debug_name") {
+        quote! { name_id::NameId::from_raw(#hash, #relative) }
+    } else {
+        quote! { name_id::NameId::from_raw(#hash) }
+    };
+    quote! {
+        {
+            // tracks the file so that changes to it trigger a rebuild
+            const _: &[u8] = include_bytes!(#full_path);
+            #entry
+        }
+    }
+    .into()
+}
+
+/// Macro that checks names against a list of keys in a file at compile time.
+///
+/// Takes a path to the key file, followed by `;` and a comma-separated list
+/// of names. Each name that isn't listed in the file is reported as a
+/// compile error, which suggests the most similar key if there's one:
+///
+/// ```ignore
+/// verify_ids_in!("locale/keys.txt"; "menu.title", "menu.subtitle");
+/// ```
+///
+/// Like with `include_str!`, the path is relative to the source file the
+/// macro is used in. Each non-empty line of the file is a key, with
+/// surrounding whitespace removed, and lines starting with `#` are comments.
+/// The macro depends on the file the same way `include_bytes!` does, so
+/// changes to it recompile the crate.
+///
+/// The macro expands into an item, so it can be used both in modules and in
+/// function bodies. Groups of [`ids!`] can be checked with
+/// `#[name_id(verify = "...")]` attribute instead.
+#[proc_macro]
+pub fn verify_ids_in(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as keys::VerifyInput);
+    keys::expand_verify(&input).into()
+}
+
+/// Returns a compile error for macros computing hashes at compile time if
+/// `name-id` hashes names with a hasher installed at runtime.
+fn reject_dynamic_hasher(macro_name: &str) -> Option<TokenStream> {
+    if !cfg!(feature = "dynamic_hasher") {
+        return None;
+    }
+    let message = format!(
+        "`{macro_name}` can't be used with \"dynamic_hasher\" feature of name-id because hash values depend on the hasher installed at runtime, construct ids with `NameId::new` instead"
+    );
+    Some(
+        syn::Error::new(proc_macro2::Span::call_site(), message)
+            .to_compile_error()
+            .into(),
+    )
+}
+
+fn hash_name(name: &str) -> u64 {
+    let mut hasher = Hasher::default();
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Must match `NameId::new_keyed` in `name-id` crate.
+fn hash_keyed(key: u64, name: &str) -> u64 {
+    let mut hasher = Hasher::default();
+    hasher.write_u64(key);
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Expands into a `NameId` expression of `name` declared at `span`, same as
+/// [`id!`].
+///
+/// Expands into a compile error instead if `name` collides with a name in the
+/// workspace registry file.
+fn expand_id(name: &str, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    expand_hashed(name, hash_name(name), span, true)
+}
+
+/// Same as [`expand_id`], with `hash` of `name` computed by the caller.
+///
+/// The id is only recorded for `inventory` if `declare` is set, as values
+/// that aren't the plain hash of `name` (e.g. keyed ids) would be reported by
+/// `verify_declared`.
+fn expand_hashed(
+    name: &str,
+    hash: u64,
+    span: proc_macro2::Span,
+    declare: bool,
+) -> proc_macro2::TokenStream {
+    if let Err(message) = registry::check(name, hash, span) {
+        return syn::Error::new(span, message).to_compile_error();
+    }
+    let entry = if cfg!(feature = "debug_name") {
         quote! {
-            name_id::NameId::from_raw(#hash, #ident)
+            name_id::NameId::from_raw(#hash, #name)
         }
     } else {
         quote! {
             name_id::NameId::from_raw(#hash)
         }
     };
-    entry.into()
+    if cfg!(feature = "inventory") && declare {
+        return quote! {
+            {
+                #[name_id::__linkme::distributed_slice(name_id::__DECLARED_IDS)]
+                #[linkme(crate = name_id::__linkme)]
+                static DECLARED: (&'static str, name_id::NameId) = (#name, #entry);
+                #entry
+            }
+        };
+    }
+    entry
+}
+
+/// Macro that declares a set of [`NameId`] constants, optionally organized
+/// into nested modules.
+///
+/// Each entry is either a constant (`NAME;` or `NAME = "name";`) or a group
+/// (`name { ... }`) which generates a module of the same name. The hashed
+/// string of a constant is its name joined with the names of all enclosing
+/// groups using `.`. Constants without an explicit name use their identifier
+/// in lower case:
+///
+/// ```ignore
+/// ids! {
+///     pub ui {
+///         button {
+///             SAVE = "save"; // ui::button::SAVE == id!("ui.button.save")
+///             CANCEL;        // ui::button::CANCEL == id!("ui.button.cancel")
+///         }
+///     }
+///     #[name_id(separator = "/")]
+///     pub assets {
+///         textures {
+///             GRASS;         // assets::textures::GRASS == id!("assets/textures/grass")
+///         }
+///     }
+/// }
+/// ```
+///
+/// Attributes and visibility are forwarded to the generated items. Top-level
+/// items use the visibility they're declared with, while groups and
+/// constants nested in a group default to `pub`.
+///
+/// `#[name_id(separator = "...")]` on a group changes the separator used to
+/// join names of its members, and is inherited by nested groups.
+///
+/// `#[name_id(from_name)]` on a group generates functions that look up
+/// constants declared directly in the group:
+/// - `fn from_name(name: &str) -> Option<NameId>` returns the constant whose
+///   name (without the group prefix) is exactly `name`. It compares strings
+///   and doesn't hash `name`, so names that aren't declared in the group are
+///   never matched, even if their hash collides with a declared one,
+/// - `const fn from_id(id: NameId) -> Option<&'static str>` returns the name
+///   (without the group prefix) of the constant equal to `id`, which compares
+///   hash values only.
+///
+/// `#[name_id(ordinal)]` on a group assigns each constant declared directly
+/// in it an ordinal equal to its position, for compact serialization:
+/// - `const COUNT: u16` is the number of such constants,
+/// - `const fn ordinal(id: NameId) -> Option<u16>` returns the ordinal of the
+///   constant equal to `id`, comparing hash values only,
+/// - `const fn from_ordinal(ordinal: u16) -> Option<NameId>` returns the
+///   constant with `ordinal`, or `None` if it isn't less than `COUNT`.
+///
+/// Ordinals depend on declaration order, so reordering or removing constants
+/// changes ordinals of others and breaks compatibility with stored or
+/// transmitted ordinals. Only append new constants to the end of such a
+/// group. `GROUP_FINGERPRINT` doesn't detect reordering.
+///
+/// `#[name_id(verify = "path")]` on a group checks the joined names of all
+/// constants declared in the group and its nested groups against keys listed
+/// in a file, like [`verify_ids_in!`] does.
+///
+/// Every group also gets `GROUP_FINGERPRINT: u64` and `GROUP_LEN: usize`
+/// constants, covering all constants declared in the group and its nested
+/// groups. The fingerprint is computed from the sorted hashes of their joined
+/// names, so it stays the same when declarations are reordered and changes
+/// when any name is renamed, added or removed. Pinning it in a test catches
+/// renames that would break compatibility with stored or transmitted ids:
+///
+/// ```ignore
+/// assert_eq!(protocol::GROUP_FINGERPRINT, 0x6f7c_2b4e_1a9d_0c35);
+/// ```
+///
+/// Like hash values, the fingerprint depends on the hasher selected with
+/// features of `name-id`.
+///
+/// Constants with equal joined names, or names with equal hashes, are
+/// reported as errors.
+///
+#[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
+#[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
+#[proc_macro]
+pub fn ids(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("ids!") {
+        return err;
+    }
+    let input = parse_macro_input!(tokens as ids::IdsInput);
+    ids::expand_ids(&input).into()
+}
+
+/// Derive macro that generates a [`NameId`] for each field of a struct.
+///
+/// The type gets the following associated items:
+/// - `const FIELD_IDS: &[(NameId, &'static str)]` with the id and name of
+///   each field, in declaration order,
+/// - `fn field_id(name: &str) -> Option<NameId>` returning the id of a field
+///   with the given name,
+/// - `const fn field_count() -> usize` returning the length of `FIELD_IDS`.
+///
+/// Fields of tuple structs are named by their index (`"0"`, `"1"`, ...).
+/// `#[field_id(rename = "...")]` changes the name of a field and
+/// `#[field_id(skip)]` leaves it out:
+///
+/// ```ignore
+/// #[derive(FieldIds)]
+/// struct Light {
+///     color: [f32; 3],
+///     #[field_id(rename = "power")]
+///     intensity: f32,
+///     #[field_id(skip)]
+///     dirty: bool,
+/// }
+///
+/// assert_eq!(Light::field_id("power"), Some(id!(power)));
+/// ```
+///
+#[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
+#[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
+#[proc_macro_derive(FieldIds, attributes(field_id))]
+pub fn derive_field_ids(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("FieldIds") {
+        return err;
+    }
+    let input = parse_macro_input!(tokens as syn::DeriveInput);
+    match field_ids(&input) {
+        Ok(it) => it.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn field_ids(input: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FieldIds can only be derived for structs",
+        ));
+    };
+
+    let mut names = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let mut name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => index.to_string(),
+        };
+        if !rename_or_skip(&field.attrs, "field_id", &mut name)? {
+            names.push((name, syn::spanned::Spanned::span(field)));
+        }
+    }
+
+    let ids = names
+        .iter()
+        .map(|(name, span)| expand_id(name, *span))
+        .collect::<Vec<_>>();
+    let names = names.into_iter().map(|it| it.0).collect::<Vec<_>>();
+    let count = names.len();
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Ids and names of fields of this type, in declaration order.
+            pub const FIELD_IDS: &'static [(name_id::NameId, &'static str)] = &[
+                #((#ids, #names)),*
+            ];
+
+            /// Returns the id of field called `name`.
+            pub fn field_id(name: &str) -> Option<name_id::NameId> {
+                match name {
+                    #(#names => Some(#ids),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the number of fields with an id.
+            pub const fn field_count() -> usize {
+                #count
+            }
+        }
+    })
+}
+
+/// Applies `rename = "..."` and `skip` options from `#[<attr>(...)]`
+/// attributes to `name`, returning whether the item is skipped.
+fn rename_or_skip(attrs: &[syn::Attribute], attr: &str, name: &mut String) -> syn::Result<bool> {
+    let mut skip = false;
+    for it in attrs.iter().filter(|it| it.path().is_ident(attr)) {
+        it.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                *name = meta.value()?.parse::<syn::LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error(format!(
+                    "unknown {attr} option, expected `rename` or `skip`"
+                )))
+            }
+        })?;
+    }
+    Ok(skip)
+}
+
+/// Attribute macro that generates a [`NameId`] for each public method of an
+/// `impl` block.
+///
+/// The type gets the following associated items, while the methods are left
+/// as they are:
+/// - `const METHOD_IDS: &[(NameId, &'static str)]` with the id and name of
+///   each `pub fn`, in declaration order,
+/// - `fn method_id(name: &str) -> Option<NameId>` returning the id of a
+///   method with the given name.
+///
+/// Methods with restricted visibility (e.g. `pub(crate)`) are left out.
+/// `#[method_ids(rename = "...")]` changes the name of a method and
+/// `#[method_ids(skip)]` leaves it out:
+///
+/// ```ignore
+/// #[method_ids]
+/// impl Door {
+///     pub fn open(&mut self) {}
+///     #[method_ids(rename = "close")]
+///     pub fn shut(&mut self) {}
+///     #[method_ids(skip)]
+///     pub fn reset(&mut self) {}
+/// }
+///
+/// assert_eq!(Door::method_id("close"), Some(id!(close)));
+/// ```
+///
+/// Generic and trait `impl` blocks aren't supported.
+///
+#[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
+#[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
+#[proc_macro_attribute]
+pub fn method_ids(args: TokenStream, tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("method_ids") {
+        return err;
+    }
+    if let Some(arg) = proc_macro2::TokenStream::from(args).into_iter().next() {
+        return syn::Error::new(
+            arg.span(),
+            "method_ids doesn't take arguments on impl blocks",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let mut input = parse_macro_input!(tokens as syn::ItemImpl);
+    match method_id_table(&mut input) {
+        Ok(table) => quote! { #input #table }.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn method_id_table(input: &mut syn::ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(syn::Error::new_spanned(
+            path,
+            "method_ids doesn't support trait impl blocks",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "method_ids doesn't support generic impl blocks",
+        ));
+    }
+
+    let mut names = Vec::new();
+    for item in &mut input.items {
+        let syn::ImplItem::Fn(method) = item else {
+            continue;
+        };
+        let mut name = method.sig.ident.to_string();
+        let skip = rename_or_skip(&method.attrs, "method_ids", &mut name)?;
+        method.attrs.retain(|it| !it.path().is_ident("method_ids"));
+        if !skip && matches!(method.vis, syn::Visibility::Public(_)) {
+            names.push((name, method.sig.ident.span()));
+        }
+    }
+
+    let ids = names
+        .iter()
+        .map(|(name, span)| expand_id(name, *span))
+        .collect::<Vec<_>>();
+    let names = names.into_iter().map(|it| it.0).collect::<Vec<_>>();
+    let self_ty = &input.self_ty;
+    Ok(quote! {
+        impl #self_ty {
+            /// Ids and names of public methods of this type, in declaration order.
+            pub const METHOD_IDS: &'static [(name_id::NameId, &'static str)] = &[
+                #((#ids, #names)),*
+            ];
+
+            /// Returns the id of method called `name`.
+            pub fn method_id(name: &str) -> Option<name_id::NameId> {
+                match name {
+                    #(#names => Some(#ids),)*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
+
+struct SeqInput {
+    prefix: String,
+    start: u64,
+    end: u64,
+    width: usize,
+    suffix: String,
+}
+
+fn parse_index(expr: Option<&syn::Expr>, range: &syn::ExprRange) -> syn::Result<u64> {
+    match expr {
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(int), ..
+        })) => int.base10_parse(),
+        Some(other) => Err(syn::Error::new_spanned(
+            other,
+            "id_seq range bounds must be integer literals",
+        )),
+        None => Err(syn::Error::new_spanned(
+            range,
+            "id_seq range must have both bounds",
+        )),
+    }
+}
+
+impl Parse for SeqInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let prefix: syn::LitStr = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let range: syn::ExprRange = input.parse()?;
+        let start = parse_index(range.start.as_deref(), &range)?;
+        let end = parse_index(range.end.as_deref(), &range)?;
+        let end = match range.limits {
+            syn::RangeLimits::HalfOpen(_) => end,
+            syn::RangeLimits::Closed(_) => end.checked_add(1).ok_or_else(|| {
+                syn::Error::new_spanned(&range.end, "id_seq range end is too large")
+            })?,
+        };
+        if start >= end {
+            return Err(syn::Error::new_spanned(&range, "id_seq range is empty"));
+        }
+
+        let mut width = 0;
+        let mut suffix = String::new();
+        while !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: syn::Ident = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            if key == "width" {
+                width = input.parse::<syn::LitInt>()?.base10_parse()?;
+            } else if key == "suffix" {
+                suffix = input.parse::<syn::LitStr>()?.value();
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "unknown id_seq option, expected `width` or `suffix`",
+                ));
+            }
+        }
+
+        Ok(SeqInput {
+            prefix: prefix.value(),
+            start,
+            end,
+            width,
+            suffix,
+        })
+    }
+}
+
+/// Macro that produces an array of [`NameId`]s of numbered names at compile
+/// time.
+///
+/// `id_seq!("bone_", 0..64)` expands into a `[NameId; 64]`, where element `i`
+/// is `id!` of `"bone_{i}"`. Both exclusive and inclusive (`0..=63`) ranges of
+/// integer literals are supported, and must not be empty.
+///
+/// Optional `width` pads indices with leading zeroes (like `{:03}` format
+/// specifier), and `suffix` is appended after the index:
+///
+/// ```ignore
+/// // "lod_001_high", "lod_002_high", "lod_003_high"
+/// const LODS: [NameId; 3] = id_seq!("lod_", 1..=3, width = 3, suffix = "_high");
+/// ```
+#[cfg_attr(not(feature = "_nested_doc"), doc = "[`NameId`]: #")]
+#[cfg_attr(feature = "_nested_doc", doc = "[`NameId`]: ./struct.NameId.html")]
+#[proc_macro]
+pub fn id_seq(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("id_seq!") {
+        return err;
+    }
+    let input = parse_macro_input!(tokens as SeqInput);
+    let ids = (input.start..input.end).map(|i| {
+        let name = format!(
+            "{}{:0width$}{}",
+            input.prefix,
+            i,
+            input.suffix,
+            width = input.width
+        );
+        expand_id(&name, proc_macro2::Span::call_site())
+    });
+    quote! { [#(#ids),*] }.into()
+}
+
+/// Mixing function of perfect hash tables, must match `name_id::phf::mix`.
+const fn phf_mix(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58476d1ce4e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+/// Slot of `value` with `displacement`, must match `name_id::phf::slot`.
+const fn phf_slot(value: u64, salt: u64, displacement: u32, len: usize) -> usize {
+    let seed = (displacement as u64).wrapping_mul(0x9e3779b97f4a7c15);
+    (phf_mix(value ^ salt ^ seed) % len as u64) as usize
+}
+
+/// Average number of keys per displacement bucket.
+const PHF_BUCKET_SIZE: usize = 4;
+/// Number of salts tried before giving up.
+const PHF_SALTS: u64 = 16;
+/// Number of displacements tried for each bucket before trying another salt.
+const PHF_DISPLACEMENTS: u32 = 1 << 16;
+
+struct PhfEntry {
+    key: String,
+    span: proc_macro2::Span,
+    value: syn::Expr,
+}
+
+struct PhfInput {
+    entries: Vec<PhfEntry>,
+}
+
+impl Parse for PhfInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut entries = Vec::new();
+        while !input.is_empty() {
+            let span = input.span();
+            let key = stringify_stream(&input)?;
+            input.parse::<syn::Token![=>]>()?;
+            let value = input.parse()?;
+            entries.push(PhfEntry { key, span, value });
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<syn::Token![,]>()?;
+        }
+        Ok(PhfInput { entries })
+    }
+}
+
+/// Displacements and slot of each key of a perfect hash table.
+struct PhfLayout {
+    salt: u64,
+    displacements: Vec<u32>,
+    slots: Vec<usize>,
+}
+
+fn phf_layout(values: &[u64]) -> Option<PhfLayout> {
+    let len = values.len();
+    let bucket_count = len.div_ceil(PHF_BUCKET_SIZE).max(1);
+    'salts: for salt_index in 0..PHF_SALTS {
+        let salt = phf_mix(salt_index.wrapping_add(0x6e616d652d6964));
+        let mut buckets = vec![Vec::new(); bucket_count];
+        for (i, value) in values.iter().enumerate() {
+            buckets[(phf_mix(value ^ salt) % bucket_count as u64) as usize].push(i);
+        }
+        let mut order: Vec<usize> = (0..bucket_count).collect();
+        order.sort_by_key(|bucket| std::cmp::Reverse(buckets[*bucket].len()));
+
+        let mut taken = vec![false; len];
+        let mut displacements = vec![0; bucket_count];
+        let mut slots = vec![0; len];
+        let mut candidate = Vec::new();
+        for bucket in order {
+            let keys = &buckets[bucket];
+            if keys.is_empty() {
+                continue;
+            }
+            let found = (0..PHF_DISPLACEMENTS).find(|displacement| {
+                candidate.clear();
+                keys.iter().all(|key| {
+                    let slot = phf_slot(values[*key], salt, *displacement, len);
+                    let free = !taken[slot] && !candidate.contains(&slot);
+                    candidate.push(slot);
+                    free
+                })
+            });
+            let Some(displacement) = found else {
+                continue 'salts;
+            };
+            displacements[bucket] = displacement;
+            for (key, slot) in keys.iter().zip(&candidate) {
+                taken[*slot] = true;
+                slots[*key] = *slot;
+            }
+        }
+        return Some(PhfLayout {
+            salt,
+            displacements,
+            slots,
+        });
+    }
+    None
+}
+
+/// Macro that produces a `NameIdPhf` perfect hash table at compile time.
+///
+/// Keys accept the same single-token forms as [`id!`], and are followed by
+/// `=>` and a constant value expression. Lookups in the resulting table take
+/// constant time. The table is meant to be stored in a `static` or `const`
+/// item:
+///
+/// ```ignore
+/// static HANDLERS: NameIdPhf<fn()> = id_phf! {
+///     jump => on_jump,
+///     "open door" => on_open_door,
+/// };
+/// ```
+///
+/// Duplicate keys, and distinct keys with equal hash values, are reported as
+/// errors.
+#[proc_macro]
+pub fn id_phf(tokens: TokenStream) -> TokenStream {
+    if let Some(err) = reject_dynamic_hasher("id_phf!") {
+        return err;
+    }
+    let input = parse_macro_input!(tokens as PhfInput);
+    let hashes: Vec<u64> = input
+        .entries
+        .iter()
+        .map(|entry| hash_name(&entry.key))
+        .collect();
+
+    for (i, entry) in input.entries.iter().enumerate() {
+        if let Some(previous) = input.entries[..i]
+            .iter()
+            .zip(&hashes)
+            .find(|(_, hash)| **hash == hashes[i])
+        {
+            let message = if previous.0.key == entry.key {
+                format!("duplicate id_phf key: {}", entry.key)
+            } else {
+                format!(
+                    "id_phf key {} has the same hash value as {}",
+                    entry.key, previous.0.key
+                )
+            };
+            return syn::Error::new(entry.span, message)
+                .to_compile_error()
+                .into();
+        }
+    }
+
+    let Some(layout) = phf_layout(&hashes) else {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "id_phf can't find a perfect hash function for given keys",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let mut ordered: Vec<Option<proc_macro2::TokenStream>> = vec![None; hashes.len()];
+    for ((entry, hash), slot) in input.entries.iter().zip(&hashes).zip(&layout.slots) {
+        let key = &entry.key;
+        let value = &entry.value;
+        let id = if cfg!(feature = "debug_name") {
+            quote! { name_id::NameId::from_raw(#hash, #key) }
+        } else {
+            quote! { name_id::NameId::from_raw(#hash) }
+        };
+        ordered[*slot] = Some(quote! { (#id, #value) });
+    }
+    let entries = ordered.into_iter().map(Option::unwrap);
+    let salt = layout.salt;
+    let displacements = layout.displacements;
+
+    quote! {
+        name_id::NameIdPhf {
+            salt: #salt,
+            displacements: &[#(#displacements),*],
+            entries: &[#(#entries),*],
+        }
+    }
+    .into()
 }
@@ -0,0 +1,133 @@
+//! Target-independent implementation of `ahash` hashing algorithm.
+//!
+//! `ahash` selects its implementation per target: it uses AES instructions
+//! where they're enabled at compile time, and a different multiplication on
+//! targets without 128-bit multiply support (e.g. 32-bit targets and wasm32).
+//! [`PortableHasher`] always follows the fallback algorithm with 128-bit
+//! folded multiply and little-endian reads, which is what `ahash` computes on
+//! x86_64 and aarch64 without AES target features, so values are the same as
+//! those of `ahash` in default x86_64 builds.
+//!
+//! This must match `PortableHasher` in `name-id` crate.
+
+/// Multiplier used by `ahash` fallback algorithm.
+const MULTIPLE: u64 = 6364136223846793005;
+/// Rotation applied after each block.
+const ROT: u32 = 23;
+/// Fixed keys of `ahash::AHasher::default()` (digits of pi).
+const KEYS: [u64; 4] = [
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+];
+
+const fn folded_multiply(s: u64, by: u64) -> u64 {
+    let result = (s as u128).wrapping_mul(by as u128);
+    (result as u64) ^ ((result >> 64) as u64)
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buffer)
+}
+
+fn read_u32(bytes: &[u8]) -> u64 {
+    let mut buffer = [0; 4];
+    buffer.copy_from_slice(&bytes[..4]);
+    u32::from_le_bytes(buffer) as u64
+}
+
+fn read_u16(bytes: &[u8]) -> u64 {
+    u16::from_le_bytes([bytes[0], bytes[1]]) as u64
+}
+
+/// `ahash` fallback hasher with fixed keys, producing the same values on all
+/// targets.
+#[derive(Debug, Clone)]
+pub(crate) struct PortableHasher {
+    buffer: u64,
+    pad: u64,
+    extra_keys: [u64; 2],
+}
+
+impl Default for PortableHasher {
+    fn default() -> Self {
+        PortableHasher {
+            buffer: KEYS[1],
+            pad: KEYS[0],
+            extra_keys: [KEYS[2], KEYS[3]],
+        }
+    }
+}
+
+impl PortableHasher {
+    fn update(&mut self, value: u64) {
+        self.buffer = folded_multiply(value ^ self.buffer, MULTIPLE);
+    }
+
+    fn large_update(&mut self, block: [u64; 2]) {
+        let combined =
+            folded_multiply(block[0] ^ self.extra_keys[0], block[1] ^ self.extra_keys[1]);
+        self.buffer = (self.buffer.wrapping_add(self.pad) ^ combined).rotate_left(ROT);
+    }
+}
+
+impl core::hash::Hasher for PortableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut data = bytes;
+        self.buffer = self
+            .buffer
+            .wrapping_add(data.len() as u64)
+            .wrapping_mul(MULTIPLE);
+        if data.len() > 16 {
+            let tail = &data[data.len() - 16..];
+            self.large_update([read_u64(tail), read_u64(&tail[8..])]);
+            while data.len() > 16 {
+                self.large_update([read_u64(data), read_u64(&data[8..])]);
+                data = &data[16..];
+            }
+        } else if data.len() > 8 {
+            self.large_update([read_u64(data), read_u64(&data[data.len() - 8..])]);
+        } else if data.len() >= 4 {
+            self.large_update([read_u32(data), read_u32(&data[data.len() - 4..])]);
+        } else if data.len() >= 2 {
+            self.large_update([read_u16(data), data[data.len() - 1] as u64]);
+        } else if let [byte] = data {
+            self.large_update([*byte as u64, *byte as u64]);
+        } else {
+            self.large_update([0, 0]);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.update(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.update(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.update(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.update(i);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.large_update([i as u64, (i >> 64) as u64]);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        // same as on 64-bit targets
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        let rot = (self.buffer & 63) as u32;
+        folded_multiply(self.buffer, self.pad).rotate_left(rot)
+    }
+}
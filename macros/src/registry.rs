@@ -0,0 +1,321 @@
+//! Collision detection across crates through a registry file shared by all
+//! expansions in a workspace.
+//!
+//! Enabled by pointing `NAME_ID_REGISTRY` environment variable at a file. Each
+//! line of the file is a JSON object describing one declared id:
+//!
+//! ```text
+//! {"hash":"c4089912f36545a0","name":"some name","crate":"alpha","file":"alpha/src/lib.rs","line":3}
+//! ```
+//!
+//! The first expansion of each crate removes entries left by previous builds
+//! of the crate, so renamed ids don't cause false positives. This is tracked
+//! per crate, as long-lived proc-macro servers (e.g. rust-analyzer) expand
+//! many crates in the same process.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use proc_macro2::Span;
+
+/// Environment variable containing path of the registry file.
+pub(crate) const REGISTRY_VAR: &str = "NAME_ID_REGISTRY";
+
+#[derive(Clone, PartialEq, Eq)]
+struct Entry {
+    hash: u64,
+    name: String,
+    krate: String,
+    file: String,
+    line: usize,
+}
+
+/// Registry contents as seen by the current compiler process.
+struct State {
+    path: PathBuf,
+    /// Length of the file after the last read or write of this process. If it
+    /// differs, other crates were compiled in the meantime.
+    known_len: u64,
+    entries: HashMap<u64, Vec<Entry>>,
+    /// Crates whose entries of previous builds were already removed.
+    pruned: HashSet<String>,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+/// Records `name` declared at `span` in the registry file, returning an error
+/// message if it collides with a different name recorded previously.
+///
+/// Does nothing if `NAME_ID_REGISTRY` isn't set.
+pub(crate) fn check(name: &str, hash: u64, span: Span) -> Result<(), String> {
+    let Some(path) = std::env::var_os(REGISTRY_VAR) else {
+        return Ok(());
+    };
+    let path = PathBuf::from(path);
+    let krate = std::env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| "unknown".to_string());
+    let location = span.unwrap();
+    let entry = Entry {
+        hash,
+        name: name.to_string(),
+        krate,
+        file: location.file(),
+        line: location.line(),
+    };
+
+    let mut state = STATE.lock().unwrap_or_else(|it| it.into_inner());
+    record(&mut state, &path, entry).map_err(|err| match err {
+        RecordError::Io(err) => format!(
+            "can't update name-id registry '{}': {err}",
+            path.display()
+        ),
+        RecordError::Collision(other) => format!(
+            "id collision: `{}` has the same hash as `{}` declared in crate `{}` at {}:{} ({:#018x}, found in name-id registry '{}')",
+            name,
+            other.name,
+            other.krate,
+            other.file,
+            other.line,
+            hash,
+            path.display()
+        ),
+    })
+}
+
+enum RecordError {
+    Io(std::io::Error),
+    /// Contains the previously recorded entry.
+    Collision(Entry),
+}
+
+impl From<std::io::Error> for RecordError {
+    fn from(err: std::io::Error) -> Self {
+        RecordError::Io(err)
+    }
+}
+
+fn record(state: &mut Option<State>, path: &Path, entry: Entry) -> Result<(), RecordError> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    // other crates are compiled by concurrent compiler processes
+    file.lock()?;
+
+    let len = file.metadata()?.len();
+    let state = match state {
+        Some(state) if state.path == path => {
+            if state.known_len != len {
+                state.entries = read_entries(&mut file)?;
+                state.known_len = len;
+            }
+            state
+        }
+        _ => state.insert(State {
+            path: path.to_path_buf(),
+            known_len: len,
+            entries: read_entries(&mut file)?,
+            pruned: HashSet::new(),
+        }),
+    };
+    if !state.pruned.contains(&entry.krate) {
+        // first expansion in this crate, remove entries of its previous builds
+        for list in state.entries.values_mut() {
+            list.retain(|it| it.krate != entry.krate);
+        }
+        state.entries.retain(|_, list| !list.is_empty());
+        let mut kept: Vec<&Entry> = state.entries.values().flatten().collect();
+        kept.sort_by(|a, b| (&a.krate, &a.file, a.line).cmp(&(&b.krate, &b.file, b.line)));
+        let mut contents = String::new();
+        for it in kept {
+            write_entry(&mut contents, it);
+        }
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(contents.as_bytes())?;
+        state.known_len = contents.len() as u64;
+        state.pruned.insert(entry.krate.clone());
+    }
+
+    let same_hash = state.entries.entry(entry.hash).or_default();
+    if let Some(other) = same_hash.iter().find(|it| it.name != entry.name) {
+        return Err(RecordError::Collision(other.clone()));
+    }
+    if same_hash.contains(&entry) {
+        return Ok(());
+    }
+
+    let mut line = String::new();
+    write_entry(&mut line, &entry);
+    file.seek(SeekFrom::End(0))?;
+    file.write_all(line.as_bytes())?;
+    state.known_len = file.stream_position()?;
+    same_hash.push(entry);
+    Ok(())
+}
+
+fn read_entries(file: &mut File) -> std::io::Result<HashMap<u64, Vec<Entry>>> {
+    let mut contents = String::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_string(&mut contents)?;
+    let mut entries: HashMap<u64, Vec<Entry>> = HashMap::new();
+    // lines that can't be parsed (e.g. written by a different version) are
+    // dropped
+    for entry in contents.lines().filter_map(parse_entry) {
+        entries.entry(entry.hash).or_default().push(entry);
+    }
+    Ok(entries)
+}
+
+fn write_entry(out: &mut String, entry: &Entry) {
+    out.push_str(&format!("{{\"hash\":\"{:016x}\",\"name\":", entry.hash));
+    write_json_str(out, &entry.name);
+    out.push_str(",\"crate\":");
+    write_json_str(out, &entry.krate);
+    out.push_str(",\"file\":");
+    write_json_str(out, &entry.file);
+    out.push_str(&format!(",\"line\":{}}}\n", entry.line));
+}
+
+fn write_json_str(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Parses a line written by [`write_entry`].
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut rest = line.trim().strip_prefix('{')?;
+    let mut hash = None;
+    let mut name = None;
+    let mut krate = None;
+    let mut file = None;
+    let mut line = None;
+    loop {
+        let (key, after) = parse_json_str(rest.trim_start())?;
+        rest = after.trim_start().strip_prefix(':')?.trim_start();
+        if rest.starts_with('"') {
+            let (value, after) = parse_json_str(rest)?;
+            rest = after;
+            match key.as_str() {
+                "hash" => hash = Some(u64::from_str_radix(&value, 16).ok()?),
+                "name" => name = Some(value),
+                "crate" => krate = Some(value),
+                "file" => file = Some(value),
+                _ => {}
+            }
+        } else {
+            let end = rest.find(|c: char| !c.is_ascii_digit())?;
+            if key == "line" {
+                line = Some(rest[..end].parse().ok()?);
+            }
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after;
+        } else {
+            rest.strip_prefix('}')?;
+            break;
+        }
+    }
+    Some(Entry {
+        hash: hash?,
+        name: name?,
+        krate: krate?,
+        file: file?,
+        line: line?,
+    })
+}
+
+/// Parses a JSON string at the start of `input`, returning it and the rest of
+/// `input`.
+fn parse_json_str(input: &str) -> Option<(String, &str)> {
+    let mut chars = input.strip_prefix('"')?.char_indices();
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &input[i + 2..])),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let code: String = (0..4).filter_map(|_| chars.next()).map(|it| it.1).collect();
+                    value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                other => value.push(other),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hash: u64, name: &str, krate: &str) -> Entry {
+        Entry {
+            hash,
+            name: name.to_string(),
+            krate: krate.to_string(),
+            file: format!("{krate}/src/lib.rs"),
+            line: 1,
+        }
+    }
+
+    #[test]
+    fn prunes_each_crate_once() {
+        let path = std::env::temp_dir().join(format!(
+            "name-id-registry-test-{}.jsonl",
+            std::process::id()
+        ));
+        // left by previous builds of both crates
+        let mut contents = String::new();
+        write_entry(&mut contents, &entry(1, "alpha.old", "alpha"));
+        write_entry(&mut contents, &entry(2, "beta.old", "beta"));
+        std::fs::write(&path, contents).unwrap();
+
+        // a single process expanding both crates, like a proc-macro server
+        let mut state = None;
+        let recorded = [
+            entry(1, "alpha.new", "alpha"),
+            entry(2, "beta.new", "beta"),
+            entry(3, "alpha.other", "alpha"),
+        ];
+        for it in &recorded {
+            assert!(record(&mut state, &path, it.clone()).is_ok());
+        }
+        assert!(matches!(
+            record(&mut state, &path, entry(3, "beta.other", "beta")),
+            Err(RecordError::Collision(other)) if other.name == "alpha.other"
+        ));
+
+        let mut file = File::open(&path).unwrap();
+        let entries = read_entries(&mut file).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let mut names: Vec<&str> = entries
+            .values()
+            .flatten()
+            .map(|it| it.name.as_str())
+            .collect();
+        names.sort_unstable();
+        assert_eq!(names, ["alpha.new", "alpha.other", "beta.new"]);
+    }
+}
@@ -0,0 +1,77 @@
+//! `ahash` hasher with keys that don't depend on features of `ahash`.
+//!
+//! `AHasher::default()` only uses fixed keys if neither `runtime-rng` (a
+//! default feature) nor `compile-time-rng` feature of `ahash` is enabled.
+//! Any crate in the dependency graph can enable them, which makes keys
+//! random for each process or build, and `runtime-rng` requires a
+//! `getrandom` backend, which `wasm32-unknown-unknown` doesn't have by
+//! default. [`SeededHasher`] passes the same fixed keys explicitly instead.
+//!
+//! This must match `SeededHasher` in `name-id` crate.
+
+use core::hash::{BuildHasher, Hasher};
+
+/// Keys of `AHasher::default()` without random seeding (digits of pi).
+const KEYS: [u64; 4] = [
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+];
+/// Constants `RandomState::with_seeds` combines seeds with to get keys.
+const SEED_MASK: [u64; 4] = [
+    0x4528_21e6_38d0_1377,
+    0xbe54_66cf_34e9_0c6c,
+    0xc0ac_29b7_c97c_50dd,
+    0x3f84_d5b5_b547_0917,
+];
+
+/// `ahash::AHasher` with fixed keys, regardless of enabled `ahash` features.
+#[derive(Debug, Clone)]
+pub(crate) struct SeededHasher(ahash::AHasher);
+
+impl Default for SeededHasher {
+    fn default() -> Self {
+        let state = ahash::RandomState::with_seeds(
+            KEYS[0] ^ SEED_MASK[0],
+            KEYS[1] ^ SEED_MASK[1],
+            KEYS[2] ^ SEED_MASK[2],
+            KEYS[3] ^ SEED_MASK[3],
+        );
+        SeededHasher(state.build_hasher())
+    }
+}
+
+impl Hasher for SeededHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes)
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.0.write_u8(i)
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.0.write_u16(i)
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.0.write_u32(i)
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0.write_u64(i)
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.0.write_u128(i)
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.0.write_usize(i)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
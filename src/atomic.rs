@@ -0,0 +1,120 @@
+//! Atomic storage of [`NameId`]s.
+
+use core::fmt;
+use core::sync::atomic::Ordering;
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicU64;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicU64;
+
+use crate::NameId;
+
+/// A [`NameId`] which can be safely shared between threads.
+///
+/// Only the hash value is stored, so ids loaded from it don't carry a debug
+/// label and display their registered name (if any) instead.
+///
+/// Uses `core::sync::atomic::AtomicU64`, which isn't available on targets
+/// without native 64-bit atomics (e.g. `thumbv6m-none-eabi` or 32-bit
+/// RISC-V). With `portable-atomic` feature, `portable_atomic::AtomicU64` is
+/// used instead, which works on all targets (together with `critical-section`
+/// feature on targets without any atomic compare-and-swap). The API is the
+/// same with either of them.
+///
+/// ```
+/// # use name_id::{id, AtomicNameId};
+/// use core::sync::atomic::Ordering;
+///
+/// static FOCUSED: AtomicNameId = AtomicNameId::new(id!(main_menu));
+/// FOCUSED.store(id!(settings), Ordering::Relaxed);
+/// assert_eq!(FOCUSED.load(Ordering::Relaxed), id!(settings));
+/// ```
+#[repr(transparent)]
+pub struct AtomicNameId(AtomicU64);
+
+impl AtomicNameId {
+    /// Creates a new atomic id.
+    pub const fn new(id: NameId) -> Self {
+        Self(AtomicU64::new(id.value()))
+    }
+
+    /// Consumes the atomic and returns the contained id.
+    pub fn into_inner(self) -> NameId {
+        NameId::from_value(self.0.into_inner())
+    }
+
+    /// Loads the id, same as `AtomicU64::load`.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> NameId {
+        NameId::from_value(self.0.load(order))
+    }
+
+    /// Stores `id`, same as `AtomicU64::store`.
+    #[inline]
+    pub fn store(&self, id: NameId, order: Ordering) {
+        self.0.store(id.value(), order)
+    }
+
+    /// Stores `id` and returns the previous id, same as `AtomicU64::swap`.
+    #[inline]
+    pub fn swap(&self, id: NameId, order: Ordering) -> NameId {
+        NameId::from_value(self.0.swap(id.value(), order))
+    }
+
+    /// Stores `new` if the current id is equal to `current`, same as
+    /// `AtomicU64::compare_exchange`.
+    ///
+    /// Ids are compared by their hash values. Returns the previous id, which
+    /// is equal to `current` on success.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: NameId,
+        new: NameId,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<NameId, NameId> {
+        self.0
+            .compare_exchange(current.value(), new.value(), success, failure)
+            .map(NameId::from_value)
+            .map_err(NameId::from_value)
+    }
+
+    /// Same as [`compare_exchange`](AtomicNameId::compare_exchange), but may
+    /// spuriously fail even when the ids are equal, same as
+    /// `AtomicU64::compare_exchange_weak`.
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: NameId,
+        new: NameId,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<NameId, NameId> {
+        self.0
+            .compare_exchange_weak(current.value(), new.value(), success, failure)
+            .map(NameId::from_value)
+            .map_err(NameId::from_value)
+    }
+}
+
+impl Default for AtomicNameId {
+    /// Creates an atomic id with hash value `0`.
+    fn default() -> Self {
+        Self::new(NameId::from_value(0))
+    }
+}
+
+impl From<NameId> for AtomicNameId {
+    fn from(id: NameId) -> Self {
+        Self::new(id)
+    }
+}
+
+/// Formats the currently stored id, loaded with `Relaxed` ordering.
+impl fmt::Debug for AtomicNameId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
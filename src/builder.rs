@@ -0,0 +1,249 @@
+//! Construction of ids from names assembled from several pieces.
+
+use core::hash::Hasher as _;
+
+use crate::{BufferTooSmall, NameId};
+
+/// Builds a [`NameId`] from a name pushed in pieces, without allocating.
+///
+/// The result is equal to [`NameId::new`] of the concatenation of all pushed
+/// pieces:
+///
+/// ```
+/// # use name_id::{NameId, NameIdBuilder};
+/// use core::fmt::Write;
+///
+/// let mut builder = NameIdBuilder::<64>::new();
+/// builder.push_str("textures")?.push_char('/')?;
+/// write!(builder, "{}.{}", "grass", "png").unwrap();
+/// assert_eq!(builder.finish(), NameId::new("textures/grass.png"));
+/// # Ok::<(), name_id::BufferTooSmall>(())
+/// ```
+///
+/// The hasher depends on the length and last bytes of the whole name before
+/// it processes the rest, so pieces are collected into an inline buffer of
+/// `N` bytes and hashed by [`finish`](NameIdBuilder::finish). Pushing more
+/// than `N` bytes in total fails with [`BufferTooSmall`].
+///
+/// Pieces pushed with [`push_bytes`](NameIdBuilder::push_bytes) don't have to
+/// be valid UTF-8 on their own, so a character can be split between them.
+/// The name isn't stored as a debug label nor recorded by the registry.
+#[derive(Clone)]
+pub struct NameIdBuilder<const N: usize = 256> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> NameIdBuilder<N> {
+    /// Creates an empty builder.
+    pub const fn new() -> Self {
+        NameIdBuilder {
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of pushed bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was pushed.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends `bytes` to the name.
+    ///
+    /// Nothing is appended if `bytes` don't fit.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<&mut Self, BufferTooSmall> {
+        let end = self.len + bytes.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(BufferTooSmall)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(self)
+    }
+
+    /// Appends `name` to the name.
+    pub fn push_str(&mut self, name: &str) -> Result<&mut Self, BufferTooSmall> {
+        self.push_bytes(name.as_bytes())
+    }
+
+    /// Appends UTF-8 encoding of `c` to the name.
+    pub fn push_char(&mut self, c: char) -> Result<&mut Self, BufferTooSmall> {
+        self.push_bytes(c.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+
+    /// Returns the id of the pushed name.
+    ///
+    /// If the pushed bytes aren't valid UTF-8, they're hashed the same way
+    /// as a `str` would be.
+    pub fn finish(self) -> NameId {
+        NameId::from_value(hash_name_bytes(&self.buffer[..self.len]))
+    }
+}
+
+/// Hashes `bytes` the same way a `str` with the same bytes is hashed, even if
+/// they aren't valid UTF-8.
+fn hash_name_bytes(bytes: &[u8]) -> u64 {
+    match core::str::from_utf8(bytes) {
+        Ok(name) => crate::hash_str(name),
+        Err(_) => {
+            // `Hash` implementation of `str`
+            let mut hasher = crate::Hasher::default();
+            hasher.write(bytes);
+            hasher.write_u8(0xff);
+            hasher.finish()
+        }
+    }
+}
+
+impl<const N: usize> Default for NameIdBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for NameIdBuilder<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bytes = &self.buffer[..self.len];
+        let mut debug = f.debug_struct("NameIdBuilder");
+        match core::str::from_utf8(bytes) {
+            Ok(name) => debug.field("name", &name),
+            Err(_) => debug.field("bytes", &bytes),
+        };
+        debug.finish()
+    }
+}
+
+/// Fails with [`core::fmt::Error`] if the written text doesn't fit.
+impl<const N: usize> core::fmt::Write for NameIdBuilder<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s).map(|_| ()).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// Length of joined names hashed without allocating by
+/// [`NameId::from_joined`], and the longest one supported by
+/// [`NameId::try_from_joined`].
+const INLINE_JOINED_LEN: usize = 256;
+
+impl NameId {
+    /// Creates a `NameId` from the concatenation of `parts`.
+    ///
+    /// The result is equal to [`NameId::new`] of the concatenated string.
+    /// No parts produce the id of an empty name:
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let key = "grass";
+    /// let id = NameId::from_segments(["textures/", key, ".png"]);
+    /// assert_eq!(id, NameId::new("textures/grass.png"));
+    /// assert_eq!(NameId::from_segments([]), NameId::new(""));
+    /// ```
+    ///
+    /// See [`from_joined`](NameId::from_joined) for details.
+    #[cfg(feature = "alloc")]
+    pub fn from_segments<'a>(parts: impl IntoIterator<Item = &'a str>) -> Self {
+        Self::from_joined(parts, "")
+    }
+
+    /// Creates a `NameId` from `parts` joined with `separator` in between.
+    ///
+    /// The result is equal to [`NameId::new`] of the joined string, like the
+    /// one `[&str]::join` would produce:
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let id = NameId::from_joined(["ui", "button", "save"], ".");
+    /// assert_eq!(id, NameId::new("ui.button.save"));
+    /// assert_eq!(NameId::from_joined(["", ""], "::"), NameId::new("::"));
+    /// ```
+    ///
+    /// Like with [`NameIdBuilder`], the hasher needs the whole name before
+    /// it processes it, so parts are copied into an inline buffer. Joined
+    /// names up to 256 bytes long are hashed without allocating, longer ones
+    /// are collected into a heap allocated buffer. The name isn't stored as a
+    /// debug label nor recorded by the registry.
+    ///
+    /// Use [`try_from_joined`](NameId::try_from_joined) where allocation
+    /// isn't possible.
+    #[cfg(feature = "alloc")]
+    pub fn from_joined<'a>(parts: impl IntoIterator<Item = &'a str>, separator: &str) -> Self {
+        let mut joined = Joined::Inline(NameIdBuilder::new());
+        for (i, part) in parts.into_iter().enumerate() {
+            if i > 0 {
+                joined.push(separator);
+            }
+            joined.push(part);
+        }
+        joined.finish()
+    }
+
+    /// Same as [`from_segments`](NameId::from_segments), but never
+    /// allocates, and fails if the concatenated name is longer than 256
+    /// bytes.
+    pub fn try_from_segments<'a>(
+        parts: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, BufferTooSmall> {
+        Self::try_from_joined(parts, "")
+    }
+
+    /// Same as [`from_joined`](NameId::from_joined), but never allocates,
+    /// and fails if the joined name is longer than 256 bytes.
+    ///
+    /// ```
+    /// # use name_id::{BufferTooSmall, NameId};
+    /// let id = NameId::try_from_joined(["ui", "button", "save"], ".");
+    /// assert_eq!(id, Ok(NameId::new("ui.button.save")));
+    /// let long = "x".repeat(200);
+    /// assert_eq!(NameId::try_from_joined([&*long, &*long], "."), Err(BufferTooSmall));
+    /// ```
+    pub fn try_from_joined<'a>(
+        parts: impl IntoIterator<Item = &'a str>,
+        separator: &str,
+    ) -> Result<Self, BufferTooSmall> {
+        let mut builder = NameIdBuilder::<INLINE_JOINED_LEN>::new();
+        for (i, part) in parts.into_iter().enumerate() {
+            if i > 0 {
+                builder.push_str(separator)?;
+            }
+            builder.push_str(part)?;
+        }
+        Ok(builder.finish())
+    }
+}
+
+/// Name collected by [`NameId::from_joined`].
+// kept inline to avoid allocating short names
+#[cfg(feature = "alloc")]
+#[allow(clippy::large_enum_variant)]
+enum Joined {
+    Inline(NameIdBuilder<INLINE_JOINED_LEN>),
+    Heap(alloc::vec::Vec<u8>),
+}
+
+#[cfg(feature = "alloc")]
+impl Joined {
+    fn push(&mut self, piece: &str) {
+        match self {
+            Joined::Inline(builder) => {
+                if builder.push_str(piece).is_err() {
+                    let mut heap = builder.buffer[..builder.len].to_vec();
+                    heap.extend_from_slice(piece.as_bytes());
+                    *self = Joined::Heap(heap);
+                }
+            }
+            Joined::Heap(heap) => heap.extend_from_slice(piece.as_bytes()),
+        }
+    }
+
+    fn finish(self) -> NameId {
+        match self {
+            Joined::Inline(builder) => builder.finish(),
+            Joined::Heap(heap) => NameId::from_value(hash_name_bytes(&heap)),
+        }
+    }
+}
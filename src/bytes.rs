@@ -0,0 +1,108 @@
+//! Canonical byte representation of [`NameId`].
+
+use crate::NameId;
+
+/// Error returned by [`NameId::from_bytes`] when bytes following the hash
+/// value aren't zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonZeroPadding;
+
+impl core::fmt::Display for NonZeroPadding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("NameId padding bytes aren't zero")
+    }
+}
+
+impl core::error::Error for NonZeroPadding {}
+
+impl NameId {
+    /// Returns the canonical byte representation of this id: the hash value
+    /// in little-endian order, followed by zeroes up to
+    /// [`SIZE`](NameId::SIZE) bytes.
+    ///
+    /// Unlike the in-memory representation, it doesn't depend on target
+    /// endianness, and it doesn't include the debug label, so it can be copied
+    /// into shared memory or files as is.
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let bytes = NameId::from_value(0x0102).as_bytes();
+    /// assert_eq!(bytes[..8], [2, 1, 0, 0, 0, 0, 0, 0]);
+    /// assert!(bytes[8..].iter().all(|it| *it == 0));
+    /// ```
+    pub const fn as_bytes(&self) -> [u8; NameId::SIZE] {
+        let mut result = [0; NameId::SIZE];
+        let value = self.value().to_le_bytes();
+        let mut i = 0;
+        while i < value.len() {
+            result[i] = value[i];
+            i += 1;
+        }
+        result
+    }
+
+    /// Constructs a `NameId` without a debug label from bytes written by
+    /// [`as_bytes`](NameId::as_bytes).
+    ///
+    /// Returns an error if any of the bytes following the hash value aren't
+    /// zero.
+    pub const fn from_bytes(bytes: [u8; NameId::SIZE]) -> Result<Self, NonZeroPadding> {
+        let mut value = [0; 8];
+        let mut i = 0;
+        while i < value.len() {
+            value[i] = bytes[i];
+            i += 1;
+        }
+        while i < bytes.len() {
+            if bytes[i] != 0 {
+                return Err(NonZeroPadding);
+            }
+            i += 1;
+        }
+        Ok(NameId::from_value(u64::from_le_bytes(value)))
+    }
+
+    /// Returns the hash value in big-endian order, for use as a key in
+    /// ordered key-value stores (e.g. `sled` or `redb`).
+    ///
+    /// Lexicographic comparison of the returned bytes gives the same order as
+    /// comparing the ids (see [`const_cmp`](NameId::const_cmp)), which isn't
+    /// the case for the little-endian [`as_bytes`](NameId::as_bytes), so range
+    /// scans over stored ids visit them in order.
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let (low, high) = (NameId::from_value(0x01ff), NameId::from_value(0x0200));
+    /// assert!(low < high);
+    /// assert!(low.to_ordered_bytes() < high.to_ordered_bytes());
+    /// assert!(low.as_bytes() > high.as_bytes());
+    /// ```
+    pub const fn to_ordered_bytes(&self) -> [u8; 8] {
+        self.value().to_be_bytes()
+    }
+
+    /// Constructs a `NameId` without a debug label from bytes written by
+    /// [`to_ordered_bytes`](NameId::to_ordered_bytes).
+    pub const fn from_ordered_bytes(bytes: [u8; 8]) -> Self {
+        NameId::from_value(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns [`to_ordered_bytes`](NameId::to_ordered_bytes) preceded by
+    /// `prefix`, for keeping keys of different kinds apart in a single tree.
+    ///
+    /// Keys with the same prefix are ordered the same way as the ids, and
+    /// all keys with a prefix form a contiguous range (`[prefix, 0, ..]` to
+    /// `[prefix, 0xff, ..]`). Use
+    /// [`from_ordered_bytes`](NameId::from_ordered_bytes) on the last 8 bytes
+    /// to get the id back.
+    pub const fn ordered_key_prefixed(&self, prefix: u8) -> [u8; 9] {
+        let mut result = [prefix; 9];
+        let value = self.to_ordered_bytes();
+        let mut i = 0;
+        while i < value.len() {
+            result[i + 1] = value[i];
+            i += 1;
+        }
+        result
+    }
+}
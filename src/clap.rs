@@ -0,0 +1,168 @@
+//! [`clap`](::clap) value parser for [`NameId`] arguments.
+//!
+//! Arguments accept names and hash values in the forms described in
+//! [`NameId` `FromStr` documentation](NameId#impl-FromStr-for-NameId), so both
+//! `--watch-id player_spawn` and `--watch-id 0x1a2b` work:
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use name_id::clap::NameIdValueParser;
+//! use name_id::NameId;
+//!
+//! let command = Command::new("debug").arg(
+//!     Arg::new("watch-id")
+//!         .long("watch-id")
+//!         .value_parser(NameIdValueParser::new()),
+//! );
+//! let matches = command.get_matches_from(["debug", "--watch-id", "player_spawn"]);
+//! let id = matches.get_one::<NameId>("watch-id");
+//! assert_eq!(id, Some(&NameId::new("player_spawn")));
+//! ```
+//!
+//! `NameId` also implements [`ValueParserFactory`], so `value_parser!(NameId)`
+//! uses [`NameIdValueParser::new`].
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use std::ffi::OsStr;
+
+use ::clap::builder::{PossibleValue, TypedValueParser, ValueParserFactory};
+use ::clap::error::{Error, ErrorKind};
+use ::clap::{Arg, Command};
+
+use crate::parse::{parse_name, parse_value};
+use crate::suggest::similar_names;
+use crate::NameId;
+
+/// Maximum number of names suggested for an unknown name.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// [`TypedValueParser`] producing [`NameId`]s.
+///
+/// Parsers created with [`new`](NameIdValueParser::new) accept any name.
+/// [`strict`](NameIdValueParser::strict) parsers only accept known names and
+/// list the most similar known names when rejecting one. Hash values are
+/// accepted by both.
+///
+/// Known names are the ones passed to
+/// [`with_names`](NameIdValueParser::with_names) (e.g. an `ALL` table of
+/// names), or registered names if no names were passed. Known names are also
+/// listed as possible values for shell completions and help output, which
+/// includes registered names only for strict parsers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NameIdValueParser {
+    names: Option<&'static [&'static str]>,
+    strict: bool,
+}
+
+impl NameIdValueParser {
+    /// Creates a parser that accepts any name.
+    pub const fn new() -> Self {
+        Self {
+            names: None,
+            strict: false,
+        }
+    }
+
+    /// Creates a parser that rejects names which aren't known.
+    ///
+    /// Without a registry, only names passed to
+    /// [`with_names`](NameIdValueParser::with_names) are known, so all names
+    /// are rejected if none were passed.
+    pub const fn strict() -> Self {
+        Self {
+            names: None,
+            strict: true,
+        }
+    }
+
+    /// Uses `names` as the known names instead of the registry.
+    pub const fn with_names(self, names: &'static [&'static str]) -> Self {
+        Self {
+            names: Some(names),
+            ..self
+        }
+    }
+
+    /// Returns whether `name` is known.
+    fn is_known(&self, name: &str) -> bool {
+        if let Some(names) = self.names {
+            return names.contains(&name);
+        }
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        return crate::registry::resolve(crate::hash_str(name)) == Some(name);
+        #[allow(unreachable_code)]
+        false
+    }
+
+    /// Returns the known names most similar to `name`.
+    fn suggest(&self, name: &str) -> Vec<&'static str> {
+        if let Some(names) = self.names {
+            return similar_names(name, names.iter().copied(), MAX_SUGGESTIONS).collect();
+        }
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        return crate::registry_suggest(name, MAX_SUGGESTIONS).collect();
+        #[allow(unreachable_code)]
+        Vec::new()
+    }
+}
+
+impl TypedValueParser for NameIdValueParser {
+    type Value = NameId;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<NameId, Error> {
+        let Some(value) = value.to_str() else {
+            return Err(Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+        if let Some(parsed) = parse_value(value) {
+            return parsed.map_err(|err| invalid_value(cmd, arg, value, err));
+        }
+        let name = parse_name(value);
+        if self.strict && !self.is_known(name) {
+            let mut message = String::from("unknown name");
+            for (i, suggestion) in self.suggest(name).into_iter().enumerate() {
+                let separator = if i == 0 { ", did you mean" } else { " or" };
+                let _ = write!(message, "{separator} `{suggestion}`");
+            }
+            return Err(invalid_value(cmd, arg, value, message));
+        }
+        Ok(crate::from_borrowed_name(name))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        if let Some(names) = self.names {
+            return Some(Box::new(names.iter().map(|name| PossibleValue::new(*name))));
+        }
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        if self.strict {
+            return Some(Box::new(
+                crate::registry_iter().map(|(_, name)| PossibleValue::new(name)),
+            ));
+        }
+        None
+    }
+}
+
+impl ValueParserFactory for NameId {
+    type Parser = NameIdValueParser;
+
+    fn value_parser() -> NameIdValueParser {
+        NameIdValueParser::new()
+    }
+}
+
+fn invalid_value(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &str,
+    reason: impl core::fmt::Display,
+) -> Error {
+    let message = match arg {
+        Some(arg) => format!("invalid value '{value}' for '{arg}': {reason}"),
+        None => format!("invalid value '{value}': {reason}"),
+    };
+    cmd.clone().error(ErrorKind::InvalidValue, message)
+}
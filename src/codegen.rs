@@ -0,0 +1,162 @@
+//! Generation of C++ headers computing the same ids as this crate.
+//!
+//! Only available with `portable_hash` feature, as that's the only hasher
+//! whose values don't depend on the target, so C++ code compiled for any
+//! platform can reproduce them.
+
+use core::fmt::{self, Write};
+
+use crate::portable::{KEYS, MULTIPLE, ROT};
+
+/// Writes a C++ header with a `constexpr` implementation of the hashing
+/// algorithm, and precomputed values of `names`.
+///
+/// The header defines
+/// `constexpr uint64_t name_id_hash(const char* name, size_t len)`, which
+/// returns the same value as `NameId::new` for a UTF-8 name of `len` bytes,
+/// both at compile time and at runtime. It requires C++14 and doesn't rely on
+/// 128-bit integer extensions.
+///
+/// Each of `names` gets a `NAME_ID_<NAME>` macro with its hash value, where
+/// `<NAME>` is the name in upper case with all characters other than ASCII
+/// letters and digits replaced by `_`. Names that map to the same macro name
+/// cause redefinition errors when the header is compiled. Every value is
+/// also checked against `name_id_hash` with a `static_assert`.
+///
+/// ```
+/// let mut header = String::new();
+/// name_id::codegen::write_cpp_header(&mut header, &["player_spawn"]).unwrap();
+/// assert!(header.contains("#define NAME_ID_PLAYER_SPAWN UINT64_C(0x"));
+/// ```
+pub fn write_cpp_header(out: &mut impl Write, names: &[&str]) -> fmt::Result {
+    write!(
+        out,
+        r#"// Generated by name-id {version}, do not edit.
+//
+// Hashes names the same way as Rust `name-id` crate with `portable_hash`
+// feature: `ahash` fallback algorithm with fixed keys, fed UTF-8 bytes of the
+// name followed by a 0xff terminator.
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define NAME_ID_ALGORITHM_FINGERPRINT UINT64_C({fingerprint:#018x})
+
+namespace name_id_detail {{
+
+constexpr uint64_t MULTIPLE = UINT64_C({multiple:#018x});
+constexpr uint64_t KEY0 = UINT64_C({key0:#018x});
+constexpr uint64_t KEY1 = UINT64_C({key1:#018x});
+constexpr uint64_t KEY2 = UINT64_C({key2:#018x});
+constexpr uint64_t KEY3 = UINT64_C({key3:#018x});
+constexpr unsigned ROT = {rot};
+
+constexpr uint64_t rotate_left(uint64_t x, unsigned r) {{
+    return (x << (r & 63)) | (x >> ((64 - r) & 63));
+}}
+
+// low and high halves of the 128-bit product xor-ed together
+constexpr uint64_t folded_multiply(uint64_t s, uint64_t by) {{
+    uint64_t lo_lo = (s & 0xffffffff) * (by & 0xffffffff);
+    uint64_t hi_lo = (s >> 32) * (by & 0xffffffff);
+    uint64_t lo_hi = (s & 0xffffffff) * (by >> 32);
+    uint64_t hi_hi = (s >> 32) * (by >> 32);
+    uint64_t cross = (lo_lo >> 32) + (hi_lo & 0xffffffff) + lo_hi;
+    uint64_t high = hi_hi + (hi_lo >> 32) + (cross >> 32);
+    uint64_t low = (cross << 32) | (lo_lo & 0xffffffff);
+    return low ^ high;
+}}
+
+constexpr uint64_t read_le(const char* p, size_t n) {{
+    uint64_t value = 0;
+    for (size_t i = 0; i < n; ++i) {{
+        value |= (uint64_t)(unsigned char)p[i] << (8 * i);
+    }}
+    return value;
+}}
+
+constexpr uint64_t large_update(uint64_t buffer, uint64_t a, uint64_t b) {{
+    uint64_t combined = folded_multiply(a ^ KEY2, b ^ KEY3);
+    return rotate_left((buffer + KEY0) ^ combined, ROT);
+}}
+
+}} // namespace name_id_detail
+
+constexpr uint64_t name_id_hash(const char* name, size_t len) {{
+    using namespace name_id_detail;
+    uint64_t buffer = (KEY1 + (uint64_t)len) * MULTIPLE;
+    if (len > 16) {{
+        const char* tail = name + len - 16;
+        buffer = large_update(buffer, read_le(tail, 8), read_le(tail + 8, 8));
+        while (len > 16) {{
+            buffer = large_update(buffer, read_le(name, 8), read_le(name + 8, 8));
+            name += 16;
+            len -= 16;
+        }}
+    }} else if (len > 8) {{
+        buffer = large_update(buffer, read_le(name, 8), read_le(name + len - 8, 8));
+    }} else if (len >= 4) {{
+        buffer = large_update(buffer, read_le(name, 4), read_le(name + len - 4, 4));
+    }} else if (len >= 2) {{
+        buffer = large_update(buffer, read_le(name, 2), read_le(name + len - 1, 1));
+    }} else if (len == 1) {{
+        buffer = large_update(buffer, read_le(name, 1), read_le(name, 1));
+    }} else {{
+        buffer = large_update(buffer, 0, 0);
+    }}
+    buffer = folded_multiply(buffer ^ 0xff, MULTIPLE);
+    return rotate_left(folded_multiply(buffer, KEY0), (unsigned)(buffer & 63));
+}}
+"#,
+        version = env!("CARGO_PKG_VERSION"),
+        fingerprint = crate::ALGORITHM_FINGERPRINT,
+        multiple = MULTIPLE,
+        key0 = KEYS[0],
+        key1 = KEYS[1],
+        key2 = KEYS[2],
+        key3 = KEYS[3],
+        rot = ROT,
+    )?;
+
+    for name in names {
+        out.write_str("\n#define ")?;
+        write_macro_name(out, name)?;
+        writeln!(out, " UINT64_C({:#018x})", crate::hash_str(name))?;
+        out.write_str("static_assert(name_id_hash(\"")?;
+        write_escaped(out, name)?;
+        write!(out, "\", {}) == ", name.len())?;
+        write_macro_name(out, name)?;
+        out.write_str(", \"name-id hash mismatch\");\n")?;
+    }
+    Ok(())
+}
+
+/// Writes `NAME_ID_` followed by `name` in upper case, with characters other
+/// than ASCII letters and digits replaced by `_`.
+fn write_macro_name(out: &mut impl Write, name: &str) -> fmt::Result {
+    out.write_str("NAME_ID_")?;
+    for c in name.chars() {
+        out.write_char(if c.is_ascii_alphanumeric() {
+            c.to_ascii_uppercase()
+        } else {
+            '_'
+        })?;
+    }
+    Ok(())
+}
+
+/// Writes `name` as the contents of a C++ string literal.
+///
+/// Bytes other than printable ASCII are written as 3-digit octal escapes,
+/// which unlike hex escapes can't absorb the characters following them.
+fn write_escaped(out: &mut impl Write, name: &str) -> fmt::Result {
+    for byte in name.bytes() {
+        match byte {
+            b'"' | b'\\' | b'?' => write!(out, "\\{}", byte as char)?,
+            0x20..=0x7e => out.write_char(byte as char)?,
+            _ => write!(out, "\\{:03o}", byte)?,
+        }
+    }
+    Ok(())
+}
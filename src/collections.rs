@@ -0,0 +1,268 @@
+//! `hashbrown` collections keyed by [`NameId`] which don't rehash keys.
+//!
+//! [`IdMap`] and [`IdSet`] dereference to `hashbrown` collections using
+//! [`IdentityBuildHasher`], so their whole API (including the entry API) is
+//! available. Unlike plain type aliases, they can also be collected from
+//! names, which are hashed the same way as by [`NameId::new`]:
+//!
+//! ```
+//! # use name_id::{id, IdMap, IdSet};
+//! let visited: IdSet = ["start", "cellar"].into_iter().collect();
+//! assert!(visited.contains(&id!(cellar)));
+//!
+//! let mut counts: IdMap<u32> = IdMap::with_capacity(4);
+//! *counts.entry(id!(start)).or_default() += 1;
+//! assert_eq!(counts[&id!(start)], 1);
+//! ```
+//!
+//! Names collected this way aren't recorded as labels or registered, as they
+//! aren't required to be `'static`.
+
+use core::borrow::Borrow;
+use core::ops::{Deref, DerefMut, Index};
+
+use crate::{IdentityBuildHasher, NameId};
+
+/// Map type [`IdMap`] dereferences to.
+pub type RawIdMap<V> = hashbrown::HashMap<NameId, V, IdentityBuildHasher>;
+/// Set type [`IdSet`] dereferences to.
+pub type RawIdSet = hashbrown::HashSet<NameId, IdentityBuildHasher>;
+/// Entry in an [`IdMap`], returned by [`RawIdMap::entry`].
+pub type Entry<'a, V> = hashbrown::hash_map::Entry<'a, NameId, V, IdentityBuildHasher>;
+/// Occupied entry in an [`IdMap`].
+pub type OccupiedEntry<'a, V> =
+    hashbrown::hash_map::OccupiedEntry<'a, NameId, V, IdentityBuildHasher>;
+/// Vacant entry in an [`IdMap`].
+pub type VacantEntry<'a, V> = hashbrown::hash_map::VacantEntry<'a, NameId, V, IdentityBuildHasher>;
+
+/// Hash map keyed by [`NameId`] which doesn't rehash keys.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IdMap<V>(RawIdMap<V>);
+
+impl<V> IdMap<V> {
+    /// Creates an empty map.
+    #[inline]
+    pub const fn new() -> Self {
+        IdMap(hashbrown::HashMap::with_hasher(IdentityBuildHasher))
+    }
+
+    /// Creates an empty map with space for at least `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        IdMap(hashbrown::HashMap::with_capacity_and_hasher(
+            capacity,
+            IdentityBuildHasher,
+        ))
+    }
+
+    /// Returns the underlying `hashbrown` map.
+    #[inline]
+    pub fn into_inner(self) -> RawIdMap<V> {
+        self.0
+    }
+}
+
+impl<V> Default for IdMap<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: core::fmt::Debug> core::fmt::Debug for IdMap<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<V> Deref for IdMap<V> {
+    type Target = RawIdMap<V>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<V> DerefMut for IdMap<V> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<V> From<RawIdMap<V>> for IdMap<V> {
+    #[inline]
+    fn from(map: RawIdMap<V>) -> Self {
+        IdMap(map)
+    }
+}
+
+impl<V, Q: Borrow<NameId>> Index<Q> for IdMap<V> {
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if the key isn't present in the map.
+    #[inline]
+    fn index(&self, key: Q) -> &V {
+        &self.0[key.borrow()]
+    }
+}
+
+impl<V> FromIterator<(NameId, V)> for IdMap<V> {
+    fn from_iter<I: IntoIterator<Item = (NameId, V)>>(iter: I) -> Self {
+        IdMap(iter.into_iter().collect())
+    }
+}
+
+impl<'a, V> FromIterator<(&'a str, V)> for IdMap<V> {
+    fn from_iter<I: IntoIterator<Item = (&'a str, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .map(|(name, value)| (hash_name(name), value))
+            .collect()
+    }
+}
+
+impl<V> Extend<(NameId, V)> for IdMap<V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (NameId, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<V> IntoIterator for IdMap<V> {
+    type Item = (NameId, V);
+    type IntoIter = hashbrown::hash_map::IntoIter<NameId, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a IdMap<V> {
+    type Item = (&'a NameId, &'a V);
+    type IntoIter = hashbrown::hash_map::Iter<'a, NameId, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut IdMap<V> {
+    type Item = (&'a NameId, &'a mut V);
+    type IntoIter = hashbrown::hash_map::IterMut<'a, NameId, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+/// Hash set of [`NameId`]s which doesn't rehash them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IdSet(RawIdSet);
+
+impl IdSet {
+    /// Creates an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        IdSet(hashbrown::HashSet::with_hasher(IdentityBuildHasher))
+    }
+
+    /// Creates an empty set with space for at least `capacity` ids.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        IdSet(hashbrown::HashSet::with_capacity_and_hasher(
+            capacity,
+            IdentityBuildHasher,
+        ))
+    }
+
+    /// Returns the underlying `hashbrown` set.
+    #[inline]
+    pub fn into_inner(self) -> RawIdSet {
+        self.0
+    }
+}
+
+impl Default for IdSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for IdSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Deref for IdSet {
+    type Target = RawIdSet;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for IdSet {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<RawIdSet> for IdSet {
+    #[inline]
+    fn from(set: RawIdSet) -> Self {
+        IdSet(set)
+    }
+}
+
+impl FromIterator<NameId> for IdSet {
+    fn from_iter<I: IntoIterator<Item = NameId>>(iter: I) -> Self {
+        IdSet(iter.into_iter().collect())
+    }
+}
+
+impl<'a> FromIterator<&'a str> for IdSet {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().map(hash_name).collect()
+    }
+}
+
+impl Extend<NameId> for IdSet {
+    #[inline]
+    fn extend<I: IntoIterator<Item = NameId>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for IdSet {
+    type Item = NameId;
+    type IntoIter = hashbrown::hash_set::IntoIter<NameId>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a IdSet {
+    type Item = &'a NameId;
+    type IntoIter = hashbrown::hash_set::Iter<'a, NameId>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+fn hash_name(name: &str) -> NameId {
+    NameId::from_value(crate::hash_str(name))
+}
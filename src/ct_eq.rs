@@ -0,0 +1,52 @@
+//! Constant-time comparison of [`NameId`]s.
+
+use crate::NameId;
+
+impl NameId {
+    /// Compares hash values of two ids in constant time.
+    ///
+    /// Unlike `==`, the time this takes doesn't depend on how many leading
+    /// bytes of the values are equal, so it can be used to compare an id
+    /// derived from a secret (e.g. an access token) with an untrusted one
+    /// without leaking how close they are through timing. Debug labels are
+    /// ignored, same as with `==`.
+    ///
+    /// With `subtle` feature, this uses [`subtle::ConstantTimeEq`] (which is
+    /// also implemented for `NameId`). Otherwise, the bytes of both values are
+    /// XORed, ORed together and reduced to a single bit without branching on
+    /// the data, with the result passed through
+    /// [`black_box`](core::hint::black_box) so the compiler is less likely to
+    /// add an early exit. Neither is a guarantee about the generated machine
+    /// code.
+    ///
+    /// This only protects the comparison itself. Hashers supported by this
+    /// crate (`ahash` and `portable_hash`) aren't cryptographic: given an id,
+    /// finding some name that hashes to it is feasible, so ids derived from
+    /// secrets are only as safe as the hasher. Use `dynamic_hasher` with a
+    /// cryptographic (and keyed) hash function installed if ids must not
+    /// reveal the secrets they were derived from.
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let token = NameId::new("secret token");
+    /// assert!(token.ct_eq(&NameId::new("secret token")));
+    /// assert!(!token.ct_eq(&NameId::new("guess")));
+    /// ```
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "subtle")]
+        return subtle::ConstantTimeEq::ct_eq(&self.value(), &other.value()).into();
+
+        #[cfg(not(feature = "subtle"))]
+        {
+            let bytes = (self.value() ^ other.value()).to_le_bytes();
+            let mut diff = 0u8;
+            for byte in bytes {
+                diff |= byte;
+            }
+            // high bit of `diff | -diff` is set iff `diff` isn't zero
+            let unequal = (diff | diff.wrapping_neg()) >> 7;
+            core::hint::black_box(unequal) == 0
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! Assignment of dense `u32` indices to [`NameId`]s.
+
+use crate::NameId;
+
+/// Error returned when an allocator has no index left for a new id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexesExhausted {
+    /// Id that didn't get an index.
+    pub id: NameId,
+}
+
+impl core::fmt::Display for IndexesExhausted {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "no index left for {}", self.id)
+    }
+}
+
+impl core::error::Error for IndexesExhausted {}
+
+/// Assigns consecutive `u32` indices to ids in the order they're first seen.
+///
+/// Indices start at `0` and can be used to address per-id arrays (e.g.
+/// component columns or counters), growing them whenever a new id gets an
+/// index:
+///
+/// ```
+/// # use name_id::{id, DenseIndexAllocator};
+/// let mut indexes = DenseIndexAllocator::new();
+/// let mut counters = Vec::new();
+/// for id in [id!(jump), id!(run), id!(jump)] {
+///     let index = indexes.index_of(id)? as usize;
+///     if index == counters.len() {
+///         counters.push(0);
+///     }
+///     counters[index] += 1;
+/// }
+/// assert_eq!(counters, [2, 1]);
+/// assert_eq!(indexes.id_at(1), Some(id!(run)));
+/// # Ok::<(), name_id::IndexesExhausted>(())
+/// ```
+///
+/// Indices are never reassigned nor removed, so an index stays valid for as
+/// long as the allocator exists. At most `u32::MAX` ids get an index.
+///
+/// Requires `alloc` feature, and `std` or `hashbrown` feature for the map of
+/// indices. Use [`FixedDenseIndexAllocator`] without them.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+#[derive(Clone, Default)]
+pub struct DenseIndexAllocator {
+    indexes: crate::IdHashMap<u32>,
+    ids: alloc::vec::Vec<NameId>,
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+impl DenseIndexAllocator {
+    /// Creates an allocator which hasn't assigned any index.
+    pub const fn new() -> Self {
+        DenseIndexAllocator {
+            indexes: crate::IdHashMap::with_hasher(crate::IdentityBuildHasher),
+            ids: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Creates an allocator with space for at least `capacity` ids.
+    pub fn with_capacity(capacity: usize) -> Self {
+        DenseIndexAllocator {
+            indexes: crate::IdHashMap::with_capacity_and_hasher(
+                capacity,
+                crate::IdentityBuildHasher,
+            ),
+            ids: alloc::vec::Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the index of `id`, assigning it the next unused index if it
+    /// doesn't have one yet.
+    ///
+    /// Fails without changing anything if all `u32::MAX` indices are used.
+    pub fn index_of(&mut self, id: NameId) -> Result<u32, IndexesExhausted> {
+        if let Some(&index) = self.indexes.get(&id) {
+            return Ok(index);
+        }
+        let index = u32::try_from(self.ids.len())
+            .ok()
+            .filter(|it| *it < u32::MAX)
+            .ok_or(IndexesExhausted { id })?;
+        self.indexes.insert(id, index);
+        self.ids.push(id);
+        Ok(index)
+    }
+
+    /// Returns the index of `id`, if it has one.
+    pub fn get(&self, id: NameId) -> Option<u32> {
+        self.indexes.get(&id).copied()
+    }
+
+    /// Returns the id with `index`.
+    pub fn id_at(&self, index: u32) -> Option<NameId> {
+        self.ids.get(index as usize).copied()
+    }
+
+    /// Returns the number of ids with an index, which is also the index the
+    /// next new id gets.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if no id has an index.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns ids in index order, so the id with index `i` is at `i`.
+    pub fn ids(&self) -> &[NameId] {
+        &self.ids
+    }
+
+    /// Returns an iterator over indices and their ids in index order.
+    pub fn iter(&self) -> DenseIndexes<'_> {
+        DenseIndexes::new(&self.ids)
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+impl core::fmt::Debug for DenseIndexAllocator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+impl<'a> IntoIterator for &'a DenseIndexAllocator {
+    type Item = (u32, NameId);
+    type IntoIter = DenseIndexes<'a>;
+
+    fn into_iter(self) -> DenseIndexes<'a> {
+        self.iter()
+    }
+}
+
+/// Fixed-capacity version of [`DenseIndexAllocator`], which assigns at most
+/// `N` indices and doesn't allocate.
+///
+/// ```
+/// # use name_id::{id, FixedDenseIndexAllocator, IndexesExhausted};
+/// let mut indexes = FixedDenseIndexAllocator::<2>::new();
+/// assert_eq!(indexes.index_of(id!(jump)), Ok(0));
+/// assert_eq!(indexes.index_of(id!(run)), Ok(1));
+/// assert_eq!(indexes.index_of(id!(jump)), Ok(0));
+/// assert_eq!(
+///     indexes.index_of(id!(crawl)),
+///     Err(IndexesExhausted { id: id!(crawl) })
+/// );
+/// ```
+///
+/// Indices are found in an open-addressing table of `N` entries, so lookups
+/// get slower as the allocator gets close to full. `N` must not be larger
+/// than `u32::MAX`.
+#[derive(Clone)]
+pub struct FixedDenseIndexAllocator<const N: usize> {
+    ids: [NameId; N],
+    /// Table of `index + 1` of ids placed by their hash value, `0` for empty
+    /// entries.
+    table: [u32; N],
+    len: usize,
+}
+
+/// Entry of [`FixedDenseIndexAllocator`] table an id was looked up in.
+enum Probe {
+    Found(u32),
+    Vacant(usize),
+    Full,
+}
+
+impl<const N: usize> FixedDenseIndexAllocator<N> {
+    /// Creates an allocator which hasn't assigned any index.
+    pub const fn new() -> Self {
+        const { assert!(N <= u32::MAX as usize, "capacity doesn't fit into u32") };
+        FixedDenseIndexAllocator {
+            ids: [NameId::from_value(0); N],
+            table: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the index of `id`, assigning it the next unused index if it
+    /// doesn't have one yet.
+    ///
+    /// Fails without changing anything if all `N` indices are used.
+    pub fn index_of(&mut self, id: NameId) -> Result<u32, IndexesExhausted> {
+        match self.probe(id) {
+            Probe::Found(index) => Ok(index),
+            Probe::Vacant(entry) => {
+                let index = self.len as u32;
+                self.ids[self.len] = id;
+                self.table[entry] = index + 1;
+                self.len += 1;
+                Ok(index)
+            }
+            Probe::Full => Err(IndexesExhausted { id }),
+        }
+    }
+
+    /// Returns the index of `id`, if it has one.
+    pub fn get(&self, id: NameId) -> Option<u32> {
+        match self.probe(id) {
+            Probe::Found(index) => Some(index),
+            Probe::Vacant(_) | Probe::Full => None,
+        }
+    }
+
+    /// Returns the id with `index`.
+    pub fn id_at(&self, index: u32) -> Option<NameId> {
+        self.ids().get(index as usize).copied()
+    }
+
+    /// Returns the number of ids with an index, which is also the index the
+    /// next new id gets.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no id has an index.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of ids that can get an index.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns ids in index order, so the id with index `i` is at `i`.
+    pub fn ids(&self) -> &[NameId] {
+        &self.ids[..self.len]
+    }
+
+    /// Returns an iterator over indices and their ids in index order.
+    pub fn iter(&self) -> DenseIndexes<'_> {
+        DenseIndexes::new(self.ids())
+    }
+
+    /// Finds the table entry of `id` with linear probing, starting from the
+    /// entry its hash value is placed at.
+    fn probe(&self, id: NameId) -> Probe {
+        if N == 0 {
+            return Probe::Full;
+        }
+        let start = (id.value() % N as u64) as usize;
+        for offset in 0..N {
+            let entry = (start + offset) % N;
+            match self.table[entry] {
+                0 => return Probe::Vacant(entry),
+                slot if self.ids[slot as usize - 1] == id => return Probe::Found(slot - 1),
+                _ => {}
+            }
+        }
+        Probe::Full
+    }
+}
+
+impl<const N: usize> Default for FixedDenseIndexAllocator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedDenseIndexAllocator<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a FixedDenseIndexAllocator<N> {
+    type Item = (u32, NameId);
+    type IntoIter = DenseIndexes<'a>;
+
+    fn into_iter(self) -> DenseIndexes<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over indices and ids of a [`DenseIndexAllocator`] or
+/// [`FixedDenseIndexAllocator`] in index order, returned by their `iter`
+/// methods.
+#[derive(Debug, Clone)]
+pub struct DenseIndexes<'a> {
+    inner: core::iter::Zip<core::ops::RangeFrom<u32>, core::slice::Iter<'a, NameId>>,
+}
+
+impl<'a> DenseIndexes<'a> {
+    fn new(ids: &'a [NameId]) -> Self {
+        DenseIndexes {
+            inner: (0..).zip(ids),
+        }
+    }
+}
+
+impl Iterator for DenseIndexes<'_> {
+    type Item = (u32, NameId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(index, id)| (index, *id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for DenseIndexes<'_> {}
@@ -0,0 +1,154 @@
+//! Translation of hash values produced by a different hasher.
+//!
+//! Dictionaries can be stored in a binary format: a sequence of entries, each
+//! consisting of the legacy hash value and the current hash value as `u64` LE.
+//! Entries are sorted by their legacy value, without duplicates.
+
+use alloc::vec::Vec;
+
+use crate::NameId;
+
+/// Error returned when parsing a malformed dictionary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryError {
+    /// Input length isn't a multiple of the entry size.
+    Truncated,
+    /// Entry starting at `offset` bytes isn't sorted after the previous
+    /// entry, or has the same legacy value.
+    Unsorted { offset: usize },
+}
+
+impl core::fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DictionaryError::Truncated => f.write_str("dictionary is truncated"),
+            DictionaryError::Unsorted { offset } => {
+                write!(f, "dictionary entry at offset {} is out of order", offset)
+            }
+        }
+    }
+}
+
+impl core::error::Error for DictionaryError {}
+
+/// Maps hash values of known names produced by a legacy hash function to
+/// [`NameId`]s of the same names produced by the current crate hasher.
+///
+/// Useful for migrating persisted raw values after switching hashers:
+///
+/// ```
+/// # use name_id::{IdDictionary, NameId};
+/// fn legacy_hash(name: &str) -> u64 {
+///     name.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+///         (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+///     })
+/// }
+///
+/// let dictionary = IdDictionary::new(["player_spawn", "level_exit"], legacy_hash);
+/// let stored = legacy_hash("player_spawn");
+/// assert_eq!(dictionary.translate(stored), Some(NameId::new("player_spawn")));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdDictionary {
+    /// `(legacy, current)` pairs sorted by legacy value.
+    entries: Vec<(u64, u64)>,
+}
+
+impl IdDictionary {
+    /// Creates a dictionary of `names`, hashing them with both `legacy` and
+    /// the current crate hasher.
+    ///
+    /// If different names produce the same legacy value, that value is
+    /// ambiguous and it's left out of the dictionary.
+    pub fn new<'a, I>(names: I, legacy: fn(&str) -> u64) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut entries: Vec<_> = names
+            .into_iter()
+            .map(|name| (legacy(name), crate::hash_str(name)))
+            .collect();
+        entries.sort_unstable();
+        entries.dedup();
+
+        let mut result = Vec::with_capacity(entries.len());
+        let mut i = 0;
+        while i < entries.len() {
+            let legacy = entries[i].0;
+            let run = entries[i..]
+                .iter()
+                .take_while(|(value, _)| *value == legacy)
+                .count();
+            if run == 1 {
+                result.push(entries[i]);
+            }
+            i += run;
+        }
+        IdDictionary { entries: result }
+    }
+
+    /// Returns the current id of a name with `old` legacy hash value, or
+    /// `None` if the name is unknown.
+    pub fn translate(&self, old: u64) -> Option<NameId> {
+        self.entries
+            .binary_search_by_key(&old, |(legacy, _)| *legacy)
+            .ok()
+            .map(|i| NameId::from_value(self.entries[i].1))
+    }
+
+    /// Returns the current id of a name with `old` legacy hash value, or
+    /// `old` as is if the name is unknown.
+    pub fn translate_or_keep(&self, old: u64) -> NameId {
+        self.translate(old)
+            .unwrap_or_else(|| NameId::from_value(old))
+    }
+
+    /// Returns the number of translated names.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the dictionary translates no names.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over `(legacy, current)` pairs, sorted by legacy
+    /// value.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (u64, NameId)> + '_ {
+        self.entries
+            .iter()
+            .map(|(legacy, current)| (*legacy, NameId::from_value(*current)))
+    }
+
+    /// Returns the dictionary encoded in the binary format described in
+    /// [module documentation](self).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.entries.len() * 16);
+        for (legacy, current) in &self.entries {
+            result.extend_from_slice(&legacy.to_le_bytes());
+            result.extend_from_slice(&current.to_le_bytes());
+        }
+        result
+    }
+
+    /// Parses a dictionary in the binary format described in
+    /// [module documentation](self).
+    pub fn from_bytes(data: &[u8]) -> Result<Self, DictionaryError> {
+        if !data.len().is_multiple_of(16) {
+            return Err(DictionaryError::Truncated);
+        }
+        let mut entries = Vec::with_capacity(data.len() / 16);
+        for (i, entry) in data.chunks_exact(16).enumerate() {
+            let legacy = u64::from_le_bytes(entry[..8].try_into().unwrap());
+            let current = u64::from_le_bytes(entry[8..].try_into().unwrap());
+            if let Some((previous, _)) = entries.last() {
+                if *previous >= legacy {
+                    return Err(DictionaryError::Unsorted { offset: i * 16 });
+                }
+            }
+            entries.push((legacy, current));
+        }
+        Ok(IdDictionary { entries })
+    }
+}
@@ -0,0 +1,125 @@
+//! Formatting of [`NameId`] into caller-provided buffers, for contexts where
+//! neither allocation nor `core::fmt` machinery is available.
+
+use crate::NameId;
+
+/// Error returned when a buffer can't hold the formatted id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("buffer is too small for formatted id")
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+/// Replaces the tail of names that don't fit into the buffer.
+const TRUNCATION_MARKER: &[u8] = b"...";
+
+impl NameId {
+    /// Length of the longest text [`write_display`](NameId::write_display)
+    /// writes for an id without a name (`NameId(18446744073709551615)`).
+    ///
+    /// Buffers of this size always fit the [`write_display`](NameId::write_display)
+    /// (with names truncated) and [`write_hex`](NameId::write_hex) output.
+    pub const MAX_DISPLAY_LEN: usize = "NameId(18446744073709551615)".len();
+
+    /// Writes the same text as [`Display`](core::fmt::Display) into `buf` and
+    /// returns the written part of it.
+    ///
+    /// Names that don't fit are cut at a character boundary and end with
+    /// `...}` instead, e.g. `#{player_sp...}`. Ids without a name are never
+    /// truncated.
+    pub fn write_display<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        let len = self.with_display_name(|name| match name {
+            Some(name) => write_name(name, buf),
+            None => {
+                let mut digits = [0; 20];
+                let digits = write_decimal(self.value(), &mut digits);
+                Ok(Writer::new(buf)
+                    .push(b"NameId(")?
+                    .push(digits)?
+                    .push(b")")?
+                    .len)
+            }
+        })?;
+        Ok(as_str(&buf[..len]))
+    }
+
+    /// Writes the hash value as lowercase hexadecimal digits without leading
+    /// zeros (same as `{:x}`) into `buf` and returns the written part of it.
+    pub fn write_hex<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let value = self.value();
+        let len = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+        let out = buf.get_mut(..len).ok_or(BufferTooSmall)?;
+        for (i, digit) in out.iter_mut().rev().enumerate() {
+            *digit = DIGITS[(value >> (4 * i)) as usize & 0xf];
+        }
+        Ok(as_str(&buf[..len]))
+    }
+}
+
+fn write_name(name: &str, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    if let Ok(writer) = Writer::new(buf).push(b"#{") {
+        if let Ok(writer) = writer.push(name.as_bytes()) {
+            if let Ok(writer) = writer.push(b"}") {
+                return Ok(writer.len);
+            }
+        }
+    }
+    let available = buf
+        .len()
+        .checked_sub(b"#{".len() + TRUNCATION_MARKER.len() + b"}".len())
+        .ok_or(BufferTooSmall)?;
+    let mut cut = available;
+    while !name.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    Ok(Writer::new(buf)
+        .push(b"#{")?
+        .push(&name.as_bytes()[..cut])?
+        .push(TRUNCATION_MARKER)?
+        .push(b"}")?
+        .len)
+}
+
+/// Writes `value` into the end of `buf`, and returns the written digits.
+fn write_decimal(mut value: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            return &buf[start..];
+        }
+    }
+}
+
+fn as_str(bytes: &[u8]) -> &str {
+    // SAFETY: only ASCII and whole characters of `str`s are written
+    unsafe { core::str::from_utf8_unchecked(bytes) }
+}
+
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Writer<'a> {
+    fn new(buf: &'a mut [u8]) -> Self {
+        Writer { buf, len: 0 }
+    }
+
+    fn push(self, bytes: &[u8]) -> Result<Self, BufferTooSmall> {
+        let end = self.len + bytes.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(BufferTooSmall)?
+            .copy_from_slice(bytes);
+        Ok(Writer { len: end, ..self })
+    }
+}
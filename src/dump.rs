@@ -0,0 +1,110 @@
+//! Human-readable listing of all known names for diagnostics.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// Names longer than this many characters are truncated by [`dump_registry`].
+const MAX_NAME_WIDTH: usize = 64;
+
+/// Order of entries written by [`dump_registry`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DumpOrder {
+    /// Ascending hash values.
+    #[default]
+    ByHash,
+    /// Names in lexicographic order.
+    ByName,
+}
+
+/// Writes a table of all known ids and their names into `out`.
+///
+/// Entries are collected from all sources available with enabled features:
+/// - the registry (`registry` or `registry_static` feature),
+/// - the global [`NameTable`](crate::NameTable), if one is installed,
+/// - ids declared with `id!` macro (`inventory` feature).
+///
+/// Names found in several sources are listed once. Different names with the
+/// same hash value are all listed, one per line.
+///
+/// Each line contains the hash value as `0x`-prefixed hex, and the name.
+/// Control characters in names are escaped, and names longer than 64
+/// characters are cut and end with `...`, so each entry is a single line of
+/// bounded length. The table ends with the number of entries:
+///
+/// ```text
+/// HASH                NAME
+/// 0x00000000000004d2  player_spawn
+/// 0x000000000000162e  player_death
+/// 2 ids
+/// ```
+pub fn dump_registry(out: &mut impl Write, order: DumpOrder) -> fmt::Result {
+    crate::name_table::with_global(|table| dump_entries(out, order, table))
+}
+
+fn dump_entries(
+    out: &mut impl Write,
+    order: DumpOrder,
+    table: Option<&crate::NameTable>,
+) -> fmt::Result {
+    #[allow(unused_mut)]
+    let mut entries: Vec<(u64, &str)> = Vec::new();
+    // pushed one by one, as `extend` would require all names to be `'static`
+    #[cfg(any(feature = "registry", feature = "registry_static"))]
+    for entry in crate::registry_iter() {
+        entries.push(entry);
+    }
+    if let Some(table) = table {
+        entries.extend(table.iter().map(|(id, name)| (id.value(), name)));
+    }
+    #[cfg(feature = "inventory")]
+    for (name, id) in crate::declared_ids() {
+        entries.push((id.value(), name));
+    }
+
+    match order {
+        DumpOrder::ByHash => entries.sort_unstable(),
+        DumpOrder::ByName => entries.sort_unstable_by(|a, b| (a.1, a.0).cmp(&(b.1, b.0))),
+    }
+    entries.dedup();
+
+    out.write_str("HASH                NAME\n")?;
+    for (value, name) in &entries {
+        write!(out, "{value:#018x}  ")?;
+        write_name(out, name)?;
+        out.write_char('\n')?;
+    }
+    match entries.len() {
+        1 => out.write_str("1 id\n"),
+        count => writeln!(out, "{count} ids"),
+    }
+}
+
+/// Returns the table written by [`dump_registry`] as a `String`.
+pub fn dump_registry_string(order: DumpOrder) -> String {
+    let mut result = String::new();
+    dump_registry(&mut result, order).expect("writing to a String can't fail");
+    result
+}
+
+/// Writes `name` with control characters escaped, truncated to
+/// [`MAX_NAME_WIDTH`] characters.
+fn write_name(out: &mut impl Write, name: &str) -> fmt::Result {
+    let truncated = name.chars().count() > MAX_NAME_WIDTH;
+    let shown = if truncated {
+        MAX_NAME_WIDTH - "...".len()
+    } else {
+        MAX_NAME_WIDTH
+    };
+    for c in name.chars().take(shown) {
+        if c.is_control() {
+            write!(out, "{}", c.escape_default())?;
+        } else {
+            out.write_char(c)?;
+        }
+    }
+    if truncated {
+        out.write_str("...")?;
+    }
+    Ok(())
+}
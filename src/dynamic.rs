@@ -0,0 +1,190 @@
+//! Hashing through a function selected at runtime, with `dynamic_hasher`
+//! feature.
+//!
+//! Hosts and dynamically loaded plugins are built separately, possibly with
+//! different features, so the hasher can't be decided by features of either.
+//! Instead, all of them install the same function (e.g. one of
+//! [`BUILTIN_HASHERS`] looked up by name) with [`install_hasher`] before any
+//! id is constructed.
+
+use core::hash::Hasher;
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use alloc::vec::Vec;
+
+/// Function hashing a whole input into a hash value.
+pub type HashFn = fn(&[u8]) -> u64;
+
+/// Hashers available in all builds, by name.
+///
+/// These hash the whole input at once, so their values differ from values
+/// produced by the same hasher with static hasher features.
+pub const BUILTIN_HASHERS: &[(&str, HashFn)] = &[
+    ("portable", hash_portable),
+    ("fnv1a", hash_fnv1a),
+    #[cfg(feature = "ahash")]
+    ("ahash", hash_ahash),
+];
+
+/// Hasher used if no hasher was installed before the first id was hashed.
+pub const DEFAULT_HASHER: &str = "portable";
+
+/// Returns the built-in hasher with the given `name`.
+///
+/// ```
+/// let hasher = name_id::builtin_hasher("fnv1a").unwrap();
+/// name_id::install_hasher(hasher).unwrap();
+/// ```
+pub fn builtin_hasher(name: &str) -> Option<HashFn> {
+    BUILTIN_HASHERS
+        .iter()
+        .find(|(it, _)| *it == name)
+        .map(|(_, hasher)| *hasher)
+}
+
+/// `ahash` fallback algorithm with fixed keys, same on all targets.
+fn hash_portable(bytes: &[u8]) -> u64 {
+    let mut hasher = crate::portable::PortableHasher::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a.
+fn hash_fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// `ahash` with fixed keys. Values depend on the target, like those of
+/// `ahash` feature.
+#[cfg(feature = "ahash")]
+fn hash_ahash(bytes: &[u8]) -> u64 {
+    let mut hasher = crate::seeded::SeededHasher::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Installed [`HashFn`], null until a hasher is installed or an id is hashed.
+static HASHER: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+/// Set once an id is hashed, to report why installation failed.
+static USED: AtomicBool = AtomicBool::new(false);
+
+/// Error returned by [`install_hasher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallHasherError {
+    /// A hasher was already installed.
+    AlreadyInstalled,
+    /// An id was already hashed with the default hasher or a previously
+    /// installed one.
+    AlreadyUsed,
+}
+
+impl core::fmt::Display for InstallHasherError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InstallHasherError::AlreadyInstalled => {
+                write!(f, "a name-id hasher was already installed")
+            }
+            InstallHasherError::AlreadyUsed => write!(
+                f,
+                "name-id hasher can't be installed after ids were hashed, install it before constructing any ids"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InstallHasherError {}
+
+/// Sets the function used to hash all names for the rest of the process.
+///
+/// Must be called once, before any id is constructed from a name. Hashing an
+/// id before that installs [`DEFAULT_HASHER`] instead, after which this
+/// function returns [`InstallHasherError::AlreadyUsed`].
+///
+/// The function is passed the bytes the `Hash` implementation of hashed value
+/// writes, with integers in little-endian order: UTF-8 bytes followed by a
+/// `0xff` byte for strings, and the length as a `u64` followed by the bytes
+/// for byte slices and C strings (including the nul terminator).
+pub fn install_hasher(hasher: HashFn) -> Result<(), InstallHasherError> {
+    HASHER
+        .compare_exchange(
+            core::ptr::null_mut(),
+            hasher as *mut (),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        )
+        .map(|_| ())
+        .map_err(|_| {
+            if USED.load(Ordering::Acquire) {
+                InstallHasherError::AlreadyUsed
+            } else {
+                InstallHasherError::AlreadyInstalled
+            }
+        })
+}
+
+/// Returns the installed hasher, installing the default one if there's none.
+fn current() -> HashFn {
+    if !USED.load(Ordering::Relaxed) {
+        USED.store(true, Ordering::Release);
+    }
+    let mut ptr = HASHER.load(Ordering::Acquire);
+    if ptr.is_null() {
+        let default =
+            builtin_hasher(DEFAULT_HASHER).expect("default hasher is built-in") as *mut ();
+        ptr = match HASHER.compare_exchange(
+            core::ptr::null_mut(),
+            default,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => default,
+            Err(installed) => installed,
+        };
+    }
+    // SAFETY: only `HashFn` pointers are stored in `HASHER`.
+    unsafe { core::mem::transmute::<*mut (), HashFn>(ptr) }
+}
+
+/// [`Hasher`] which collects written bytes and passes them to the installed
+/// [`HashFn`] when finished.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DynamicHasher {
+    bytes: Vec<u8>,
+}
+
+impl Hasher for DynamicHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.bytes.push(i);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        // same as on 64-bit targets
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        current()(&self.bytes)
+    }
+}
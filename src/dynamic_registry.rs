@@ -0,0 +1,215 @@
+//! Registry of names whose entries are removed once they're no longer used.
+//!
+//! The global registry leaks every name it stores, which is fine for names
+//! known at build time, but not for names chosen by users of long-running
+//! processes. [`DynamicRegistry`] owns its names and reference counts them
+//! through [`RegisteredId`] guards instead.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use crate::sync::Mutex;
+use crate::{InternCollision, NameId};
+
+struct Entry {
+    name: Box<str>,
+    /// Number of live [`RegisteredId`] guards.
+    count: usize,
+}
+
+/// Thread-safe registry of names, which are stored only while a
+/// [`RegisteredId`] guard returned for them is alive.
+///
+/// ```
+/// # use name_id::{DynamicRegistry, NameId};
+/// static PLAYERS: DynamicRegistry = DynamicRegistry::new();
+///
+/// let player = PLAYERS.register("xX_slayer_Xx").unwrap();
+/// let id = player.id();
+/// assert_eq!(PLAYERS.resolve(id).as_deref(), Some("xX_slayer_Xx"));
+/// drop(player);
+/// assert_eq!(PLAYERS.resolve(id), None);
+/// ```
+///
+/// Names are hashed the same way [`NameId::new`] hashes them, but aren't
+/// recorded by the global registry. With `registry` feature, lookups also
+/// check the global registry, so a `DynamicRegistry` can resolve all ids of a
+/// process. Names registered in one are never added to the other.
+pub struct DynamicRegistry {
+    entries: Mutex<BTreeMap<u64, Entry>>,
+}
+
+impl DynamicRegistry {
+    /// Creates an empty registry. Usable in `static` items.
+    pub const fn new() -> Self {
+        DynamicRegistry {
+            entries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Stores `name` (if it isn't stored yet) and returns a guard which keeps
+    /// it stored until the guard and all its clones are dropped.
+    ///
+    /// Returns an error if a different name with the same hash value is
+    /// stored by this registry, or by the global registry with `registry`
+    /// feature.
+    pub fn register(&self, name: &str) -> Result<RegisteredId<'_>, InternCollision> {
+        let id = NameId::from_value(crate::hash_str(name));
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        if crate::registry::resolve(id.value()).is_some_and(|it| it != name) {
+            return Err(InternCollision { id });
+        }
+        self.entries.with(|entries| {
+            match entries.get_mut(&id.value()) {
+                Some(entry) if &*entry.name != name => return Err(InternCollision { id }),
+                Some(entry) => entry.count += 1,
+                None => {
+                    entries.insert(
+                        id.value(),
+                        Entry {
+                            name: name.into(),
+                            count: 1,
+                        },
+                    );
+                }
+            }
+            Ok(RegisteredId { registry: self, id })
+        })
+    }
+
+    /// Returns a copy of the name of `id`.
+    pub fn resolve(&self, id: NameId) -> Option<String> {
+        self.resolve_with(id, |name| String::from(name))
+    }
+
+    /// Calls `f` with the name of `id` and returns its result, without
+    /// copying the name.
+    ///
+    /// The registry is locked while `f` runs, so `f` must not register or
+    /// drop ids of this registry.
+    pub fn resolve_with<R>(&self, id: NameId, f: impl FnOnce(&str) -> R) -> Option<R> {
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        if let Some(name) = crate::registry::resolve(id.value()) {
+            return Some(f(name));
+        }
+        self.entries
+            .with(|entries| entries.get(&id.value()).map(|entry| f(&entry.name)))
+    }
+
+    /// Returns `true` if a name is stored for `id` by this registry (or the
+    /// global registry with `registry` feature).
+    pub fn contains(&self, id: NameId) -> bool {
+        self.resolve_with(id, |_| ()).is_some()
+    }
+
+    /// Returns the number of live guards of `id`.
+    pub fn ref_count(&self, id: NameId) -> usize {
+        self.entries
+            .with(|entries| entries.get(&id.value()).map_or(0, |entry| entry.count))
+    }
+
+    /// Returns the number of names stored by this registry.
+    pub fn len(&self) -> usize {
+        self.entries.with(|entries| entries.len())
+    }
+
+    /// Returns `true` if this registry doesn't store any names.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn retain(&self, id: NameId) {
+        self.entries.with(|entries| {
+            if let Some(entry) = entries.get_mut(&id.value()) {
+                entry.count += 1;
+            }
+        })
+    }
+
+    fn release(&self, id: NameId) {
+        // the name is dropped after the lock is released
+        let _removed = self.entries.with(|entries| {
+            let entry = entries.get_mut(&id.value())?;
+            entry.count -= 1;
+            if entry.count == 0 {
+                entries.remove(&id.value())
+            } else {
+                None
+            }
+        });
+    }
+}
+
+impl Default for DynamicRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for DynamicRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.entries.with(|entries| {
+            f.debug_map()
+                .entries(entries.iter().map(|(value, entry)| (value, &entry.name)))
+                .finish()
+        })
+    }
+}
+
+/// Id of a name stored in a [`DynamicRegistry`], which keeps the name stored
+/// while it (or any of its clones) is alive.
+///
+/// Cloning the guard increments the reference count of the name, and dropping
+/// it decrements it. The name is removed when the count reaches zero.
+pub struct RegisteredId<'a> {
+    registry: &'a DynamicRegistry,
+    id: NameId,
+}
+
+impl RegisteredId<'_> {
+    /// Returns the id of the registered name.
+    ///
+    /// The id can outlive the guard, but the name can't be resolved through
+    /// the registry once all guards are dropped.
+    pub fn id(&self) -> NameId {
+        self.id
+    }
+
+    /// Returns a copy of the registered name.
+    pub fn name(&self) -> String {
+        self.registry
+            .resolve(self.id)
+            .expect("guarded names stay registered")
+    }
+}
+
+impl Clone for RegisteredId<'_> {
+    fn clone(&self) -> Self {
+        self.registry.retain(self.id);
+        RegisteredId {
+            registry: self.registry,
+            id: self.id,
+        }
+    }
+}
+
+impl Drop for RegisteredId<'_> {
+    fn drop(&mut self) {
+        self.registry.release(self.id);
+    }
+}
+
+impl From<&RegisteredId<'_>> for NameId {
+    fn from(guard: &RegisteredId<'_>) -> Self {
+        guard.id
+    }
+}
+
+impl core::fmt::Debug for RegisteredId<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RegisteredId")
+            .field(&self.id.value())
+            .finish()
+    }
+}
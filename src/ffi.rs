@@ -0,0 +1,77 @@
+//! C ABI functions for using `NameId`s across FFI boundaries.
+//!
+//! With `ffi` feature enabled `NameId` is guaranteed to be ABI-identical to
+//! `uint64_t` (it's `repr(transparent)` over its `u64` hash value), so it can
+//! be passed to and from C as a plain integer. Signatures only use primitive
+//! types so headers can be generated with `cbindgen`.
+//!
+//! Names hashed through these functions aren't registered.
+
+use crate::NameId;
+
+const _: () = assert!(NameId::IS_TRANSPARENT_U64);
+
+/// Returns the hash of the `len` bytes long UTF-8 string at `ptr`, which is
+/// equal to the value of `NameId` constructed from it in Rust.
+///
+/// Returns `0` if the string isn't valid UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes, it can be null only if `len`
+/// is `0`.
+#[no_mangle]
+pub unsafe extern "C" fn name_id_from_str(ptr: *const u8, len: usize) -> u64 {
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        // SAFETY: caller guarantees ptr is valid for len bytes
+        unsafe { core::slice::from_raw_parts(ptr, len) }
+    };
+    match core::str::from_utf8(bytes) {
+        Ok(name) => crate::hash_str(name),
+        Err(_) => 0,
+    }
+}
+
+/// Returns the hash value of `id`.
+#[no_mangle]
+pub extern "C" fn name_id_value(id: NameId) -> u64 {
+    id.value()
+}
+
+/// Returns `true` if `a` and `b` are equal.
+#[no_mangle]
+pub extern "C" fn name_id_eq(a: NameId, b: NameId) -> bool {
+    a == b
+}
+
+/// Looks up the name registered for `hash`.
+///
+/// If found, writes a pointer to its UTF-8 bytes into `out_ptr` and its length
+/// into `out_len`, and returns `true`. The name isn't nul-terminated and stays
+/// valid for the rest of the program. If not found, returns `false` without
+/// writing to outputs.
+///
+/// # Safety
+///
+/// `out_ptr` and `out_len` must be valid for writes.
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+#[no_mangle]
+pub unsafe extern "C" fn name_id_resolve(
+    hash: u64,
+    out_ptr: *mut *const u8,
+    out_len: *mut usize,
+) -> bool {
+    match crate::registry::resolve(hash) {
+        Some(name) => {
+            // SAFETY: caller guarantees outputs are valid for writes
+            unsafe {
+                out_ptr.write(name.as_ptr());
+                out_len.write(name.len());
+            }
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,174 @@
+//! Bloom filter over [`NameId`]s.
+
+use crate::NameId;
+
+/// Bloom filter of [`NameId`]s with `WORDS * 64` bits, probing `K` bits per
+/// id.
+///
+/// Ids inserted into the filter are always reported as (maybe) contained,
+/// while other ids are reported as contained with a probability that depends
+/// on the number of inserted ids `n`, approximately
+/// `(1 - e^(-K * n / BITS))^K`. It's useful for quickly rejecting ids before
+/// looking them up in a slower structure.
+///
+/// The number of bits is given in 64-bit words, as array lengths can't be
+/// computed from generic parameters.
+///
+/// # Probe positions
+///
+/// Hash values of ids are already well distributed, so probe positions are
+/// derived from their bits directly instead of hashing them again. The lower
+/// and upper 32 bits of the value are used as two independent hashes `h1` and
+/// `h2`, and `i`-th probe is at `(h1 + i * h2) % BITS` (double hashing).
+/// `h2` is made odd so that probes don't repeat for power of two sizes.
+///
+/// ```
+/// # use name_id::{id, IdFilter};
+/// let mut filter = IdFilter::<16>::new();
+/// filter.insert(id!(player_spawn));
+/// assert!(filter.maybe_contains(id!(player_spawn)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdFilter<const WORDS: usize, const K: usize = 4> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize, const K: usize> IdFilter<WORDS, K> {
+    /// Number of bits in the filter.
+    pub const BITS: usize = WORDS * 64;
+
+    /// Creates an empty filter.
+    pub const fn new() -> Self {
+        assert!(WORDS > 0, "IdFilter must have at least one word");
+        assert!(K > 0, "IdFilter must probe at least one bit");
+        IdFilter { words: [0; WORDS] }
+    }
+
+    #[inline]
+    const fn probe(id: NameId, i: usize) -> (usize, u64) {
+        let value = id.value();
+        let h1 = value as u32 as u64;
+        let h2 = (value >> 32) | 1;
+        let bit = (h1.wrapping_add((i as u64).wrapping_mul(h2)) % Self::BITS as u64) as usize;
+        (bit / 64, 1 << (bit % 64))
+    }
+
+    /// Adds `id` to the filter.
+    #[inline]
+    pub const fn insert(&mut self, id: NameId) {
+        let mut i = 0;
+        while i < K {
+            let (word, mask) = Self::probe(id, i);
+            self.words[word] |= mask;
+            i += 1;
+        }
+    }
+
+    /// Returns `false` if `id` definitely wasn't added to the filter, and
+    /// `true` if it might have been.
+    #[inline]
+    pub const fn maybe_contains(&self, id: NameId) -> bool {
+        let mut i = 0;
+        while i < K {
+            let (word, mask) = Self::probe(id, i);
+            if self.words[word] & mask == 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Removes all ids from the filter.
+    pub const fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Returns `true` if no ids were added to the filter.
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns the number of set bits.
+    pub const fn count_ones(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < WORDS {
+            count += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns a filter which maybe contains ids maybe contained by either
+    /// filter.
+    pub const fn union(&self, other: &Self) -> Self {
+        let mut result = *self;
+        result.union_with(other);
+        result
+    }
+
+    /// Adds all ids of `other` to this filter.
+    pub const fn union_with(&mut self, other: &Self) {
+        let mut i = 0;
+        while i < WORDS {
+            self.words[i] |= other.words[i];
+            i += 1;
+        }
+    }
+
+    /// Returns a filter which maybe contains ids maybe contained by both
+    /// filters.
+    ///
+    /// Intersection has the same bits set as a filter with only the common
+    /// ids in it, plus bits shared by different ids of both filters, so it
+    /// may have a higher false positive rate.
+    pub const fn intersection(&self, other: &Self) -> Self {
+        let mut result = *self;
+        result.intersect_with(other);
+        result
+    }
+
+    /// Keeps only bits set in both filters.
+    pub const fn intersect_with(&mut self, other: &Self) {
+        let mut i = 0;
+        while i < WORDS {
+            self.words[i] &= other.words[i];
+            i += 1;
+        }
+    }
+
+    /// Returns bits of the filter.
+    pub const fn words(&self) -> &[u64; WORDS] {
+        &self.words
+    }
+}
+
+impl<const WORDS: usize, const K: usize> Default for IdFilter<WORDS, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize, const K: usize> Extend<NameId> for IdFilter<WORDS, K> {
+    fn extend<I: IntoIterator<Item = NameId>>(&mut self, iter: I) {
+        for id in iter {
+            self.insert(id);
+        }
+    }
+}
+
+impl<const WORDS: usize, const K: usize> FromIterator<NameId> for IdFilter<WORDS, K> {
+    fn from_iter<I: IntoIterator<Item = NameId>>(iter: I) -> Self {
+        let mut filter = Self::new();
+        filter.extend(iter);
+        filter
+    }
+}
@@ -0,0 +1,108 @@
+//! Fingerprint of the hashing algorithm, for validating persisted hash values.
+
+/// Description of the algorithm used to hash names into [`NameId`](crate::NameId)s.
+///
+/// Raw hash values are only meaningful to binaries using the same algorithm,
+/// so persisted values should be accompanied by
+/// [`ALGORITHM_FINGERPRINT`], and checked with [`check_fingerprint`] when
+/// they're loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlgorithmInfo {
+    /// Name and major version of the hasher selected by crate features.
+    pub hasher: &'static str,
+    /// Version of conventions used to feed names into the hasher (e.g. the
+    /// terminator written after string bytes). Incremented whenever they
+    /// change.
+    pub version: u32,
+    /// Hash of a fixed probe string, which covers hasher keys and any seed or
+    /// salt applied to them.
+    pub seed_digest: u64,
+}
+
+/// Algorithm used by this build of the crate.
+///
+/// With `dynamic_hasher` feature, hashes depend on the hasher installed at
+/// runtime, which this doesn't describe, so persist the name of the installed
+/// hasher alongside values as well.
+pub const ALGORITHM: AlgorithmInfo = AlgorithmInfo {
+    #[cfg(all(
+        feature = "ahash",
+        not(any(feature = "portable_hash", feature = "dynamic_hasher"))
+    ))]
+    hasher: "ahash 0.8",
+    // same values as `ahash` on x86_64, but not on other targets
+    #[cfg(all(feature = "portable_hash", not(feature = "dynamic_hasher")))]
+    hasher: "portable ahash 0.8",
+    // installed hasher isn't known at compile time
+    #[cfg(feature = "dynamic_hasher")]
+    hasher: "dynamic",
+    version: 1,
+    #[cfg(not(feature = "dynamic_hasher"))]
+    seed_digest: PROBE.value(),
+    #[cfg(feature = "dynamic_hasher")]
+    seed_digest: 0,
+};
+
+#[cfg(not(feature = "dynamic_hasher"))]
+const PROBE: crate::NameId = crate::id!("name-id algorithm fingerprint probe");
+
+/// Hash of all [`ALGORITHM`] fields.
+///
+/// It changes whenever selected hasher, its keys, or hashing conventions
+/// change, and stays the same between builds otherwise.
+pub const ALGORITHM_FINGERPRINT: u64 = {
+    // FNV-1a, as the selected hasher can't be used in constant context
+    const fn fnv(mut hash: u64, bytes: &[u8]) -> u64 {
+        let mut i = 0;
+        while i < bytes.len() {
+            hash = (hash ^ bytes[i] as u64).wrapping_mul(0x0100_0000_01b3);
+            i += 1;
+        }
+        hash
+    }
+    let hash = fnv(0xcbf2_9ce4_8422_2325, ALGORITHM.hasher.as_bytes());
+    let hash = fnv(hash, &[0xff]);
+    let hash = fnv(hash, &ALGORITHM.version.to_le_bytes());
+    fnv(hash, &ALGORITHM.seed_digest.to_le_bytes())
+};
+
+/// Error returned by [`check_fingerprint`] when persisted values were hashed
+/// with a different algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FingerprintMismatch {
+    /// Fingerprint stored alongside persisted values.
+    pub stored: u64,
+    /// Fingerprint of the algorithm used by this build.
+    pub expected: u64,
+}
+
+impl core::fmt::Display for FingerprintMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "persisted ids were hashed with algorithm fingerprint {:#018x}, but this build uses {:#018x} ({}, version {}); stored hash values don't match names hashed by this build",
+            self.stored, self.expected, ALGORITHM.hasher, ALGORITHM.version
+        )
+    }
+}
+
+impl core::error::Error for FingerprintMismatch {}
+
+/// Checks whether `stored` fingerprint matches [`ALGORITHM_FINGERPRINT`].
+///
+/// ```
+/// // when saving
+/// let header = name_id::ALGORITHM_FINGERPRINT;
+/// // when loading
+/// name_id::check_fingerprint(header).expect("incompatible save file");
+/// ```
+pub const fn check_fingerprint(stored: u64) -> Result<(), FingerprintMismatch> {
+    if stored == ALGORITHM_FINGERPRINT {
+        Ok(())
+    } else {
+        Err(FingerprintMismatch {
+            stored,
+            expected: ALGORITHM_FINGERPRINT,
+        })
+    }
+}
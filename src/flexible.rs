@@ -0,0 +1,75 @@
+//! Serde with-module accepting both raw id values and names for a single
+//! [`NameId`] field.
+//!
+//! Useful for configuration files that mix legacy numeric ids with names.
+//! Values are serialized as `u64`, while deserialization accepts:
+//! - unsigned integers, used as hash values directly,
+//! - non-negative signed integers, used as hash values directly (negative
+//!   values are rejected),
+//! - strings prefixed with `0x`, parsed as hexadecimal hash values,
+//! - strings prefixed with `raw:`, parsed as decimal hash values,
+//! - any other string, treated as a name and hashed. This includes strings
+//!   consisting only of digits, so `"123"` is the id of name `123` and not the
+//!   hash value `123`.
+//!
+//! ```
+//! # use name_id::NameId;
+//! #[derive(serde::Deserialize)]
+//! struct Trigger {
+//!     #[serde(with = "name_id::flexible")]
+//!     event: NameId,
+//! }
+//! ```
+
+use core::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use crate::NameId;
+
+/// Serializes `id` as its `u64` hash value.
+pub fn serialize<S: Serializer>(id: &NameId, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(id.value())
+}
+
+/// Deserializes an id from any of the forms described in
+/// [module documentation](self).
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NameId, D::Error> {
+    deserializer.deserialize_any(FlexibleVisitor)
+}
+
+struct FlexibleVisitor;
+
+impl Visitor<'_> for FlexibleVisitor {
+    type Value = NameId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(
+            "a non-negative integer id value, a \"0x\" (hex) or \"raw:\" (decimal) prefixed id value string, or a name string",
+        )
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<NameId, E> {
+        Ok(NameId::from_value(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<NameId, E> {
+        u64::try_from(value)
+            .map(NameId::from_value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<NameId, E> {
+        let parsed = if let Some(hex) = value.strip_prefix("0x") {
+            u64::from_str_radix(hex, 16)
+        } else if let Some(decimal) = value.strip_prefix("raw:") {
+            decimal.parse()
+        } else {
+            return Ok(NameId::from_value(crate::hash_str(value)));
+        };
+        parsed
+            .map(NameId::from_value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
@@ -0,0 +1,229 @@
+//! Hashing of `NameId`s used as keys of hash maps.
+//!
+//! [`Hash`](core::hash::Hash) implementation of [`NameId`] writes its (already
+//! well distributed) hash value with a single `write_u64` call, so there's no
+//! need to hash it again.
+
+use core::hash::{BuildHasher, Hasher};
+
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use crate::NameId;
+
+/// [`Hasher`] which returns the value passed to `write_u64` as is.
+///
+/// It's only meant to hash [`NameId`](crate::NameId)s, writing anything other
+/// than a single `u64` panics.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentityHasher(u64);
+
+impl Hasher for IdentityHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, _: &[u8]) {
+        panic!("IdentityHasher only supports hashing NameId values");
+    }
+
+    #[inline]
+    fn write_u64(&mut self, value: u64) {
+        self.0 = value;
+    }
+}
+
+/// [`BuildHasher`] of [`IdentityHasher`]s.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentityBuildHasher;
+
+impl BuildHasher for IdentityBuildHasher {
+    type Hasher = IdentityHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> IdentityHasher {
+        IdentityHasher::default()
+    }
+}
+
+/// [`Hasher`] which passes `NameId` values through and hashes names the same
+/// way `NameId` constructors do.
+///
+/// A single `write_u64` call (as made by the [`Hash`](core::hash::Hash)
+/// implementation of [`NameId`]) finishes with the written value, while any
+/// other input is hashed with the crate hasher. This makes a name and its
+/// `NameId` hash to the same value, which `hashbrown` lookups through
+/// [`Equivalent`](hashbrown::Equivalent) rely on.
+#[cfg(feature = "hashbrown")]
+#[derive(Debug, Clone, Default)]
+pub struct NameHasher(NameHasherState);
+
+#[cfg(feature = "hashbrown")]
+#[derive(Debug, Clone, Default)]
+enum NameHasherState {
+    #[default]
+    Empty,
+    Value(u64),
+    Hashing(crate::Hasher),
+}
+
+#[cfg(feature = "hashbrown")]
+impl NameHasher {
+    #[inline]
+    fn hashing(&mut self) -> &mut crate::Hasher {
+        let value = match self.0 {
+            NameHasherState::Hashing(ref mut hasher) => return hasher,
+            NameHasherState::Empty => None,
+            NameHasherState::Value(value) => Some(value),
+        };
+        let mut hasher = crate::Hasher::default();
+        if let Some(value) = value {
+            hasher.write_u64(value);
+        }
+        self.0 = NameHasherState::Hashing(hasher);
+        match self.0 {
+            NameHasherState::Hashing(ref mut hasher) => hasher,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl Hasher for NameHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match &self.0 {
+            NameHasherState::Empty => crate::Hasher::default().finish(),
+            NameHasherState::Value(value) => *value,
+            NameHasherState::Hashing(hasher) => hasher.finish(),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hashing().write(bytes)
+    }
+
+    #[inline]
+    fn write_u8(&mut self, value: u8) {
+        self.hashing().write_u8(value)
+    }
+
+    #[inline]
+    fn write_u16(&mut self, value: u16) {
+        self.hashing().write_u16(value)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, value: u32) {
+        self.hashing().write_u32(value)
+    }
+
+    #[inline]
+    fn write_u64(&mut self, value: u64) {
+        match self.0 {
+            NameHasherState::Empty => self.0 = NameHasherState::Value(value),
+            _ => self.hashing().write_u64(value),
+        }
+    }
+
+    #[inline]
+    fn write_u128(&mut self, value: u128) {
+        self.hashing().write_u128(value)
+    }
+
+    #[inline]
+    fn write_usize(&mut self, value: usize) {
+        self.hashing().write_usize(value)
+    }
+}
+
+/// [`BuildHasher`] of [`NameHasher`]s.
+///
+/// Maps keyed by [`NameId`] using it can be queried with names (`&str` and
+/// `&String`) directly:
+///
+/// ```
+/// # use name_id::{id, NameBuildHasher};
+/// let mut map = hashbrown::HashMap::with_hasher(NameBuildHasher);
+/// map.insert(id!(player_spawn), 1);
+/// assert_eq!(map.get("player_spawn"), Some(&1));
+/// ```
+///
+/// Lookups by name in maps using other hashers (e.g. [`IdentityBuildHasher`])
+/// don't find entries, or panic.
+#[cfg(feature = "hashbrown")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NameBuildHasher;
+
+#[cfg(feature = "hashbrown")]
+impl BuildHasher for NameBuildHasher {
+    type Hasher = NameHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> NameHasher {
+        NameHasher::default()
+    }
+}
+
+/// Hash map keyed by [`NameId`] which doesn't rehash keys.
+///
+/// Uses `hashbrown` with `hashbrown` feature and `std` otherwise.
+#[cfg(feature = "hashbrown")]
+pub type IdHashMap<V> = hashbrown::HashMap<NameId, V, IdentityBuildHasher>;
+/// Hash map keyed by [`NameId`] which doesn't rehash keys.
+///
+/// Uses `hashbrown` with `hashbrown` feature and `std` otherwise.
+#[cfg(all(feature = "std", not(feature = "hashbrown")))]
+pub type IdHashMap<V> = std::collections::HashMap<NameId, V, IdentityBuildHasher>;
+
+/// Hash set of [`NameId`]s which doesn't rehash them.
+///
+/// Uses `hashbrown` with `hashbrown` feature and `std` otherwise.
+#[cfg(feature = "hashbrown")]
+pub type IdHashSet = hashbrown::HashSet<NameId, IdentityBuildHasher>;
+/// Hash set of [`NameId`]s which doesn't rehash them.
+///
+/// Uses `hashbrown` with `hashbrown` feature and `std` otherwise.
+#[cfg(all(feature = "std", not(feature = "hashbrown")))]
+pub type IdHashSet = std::collections::HashSet<NameId, IdentityBuildHasher>;
+
+/// Fixed-capacity `heapless` map keyed by [`NameId`] which doesn't rehash
+/// keys.
+///
+/// Constructed using [`Default::default`].
+#[cfg(feature = "heapless")]
+pub type IdIndexMap<V, const N: usize> = heapless::IndexMap<NameId, V, IdentityBuildHasher, N>;
+
+/// Returns `value` folded into 32 bits, as written by `hash32::Hash`
+/// implementation of `NameId`.
+#[cfg(feature = "hash32")]
+pub(crate) const fn fold32(value: u64) -> u32 {
+    (value ^ (value >> 32)) as u32
+}
+
+/// Keeps the 32-bit value written by `hash32::Hash` implementation of
+/// `NameId`, which is already folded.
+#[cfg(feature = "hash32")]
+impl hash32::Hasher for IdentityHasher {
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.0 as u32
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let bytes: [u8; 4] = bytes
+            .try_into()
+            .expect("IdentityHasher only supports hashing NameId values");
+        self.0 = u32::from_le_bytes(bytes) as u64;
+    }
+}
+
+#[cfg(feature = "hash32")]
+impl hash32::BuildHasher for IdentityBuildHasher {
+    type Hasher = IdentityHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> IdentityHasher {
+        IdentityHasher::default()
+    }
+}
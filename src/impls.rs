@@ -0,0 +1,54 @@
+//! Trait implementations for third-party crates, each enabled by a feature of
+//! the same name.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "bevy_reflect")]
+mod bevy_reflect;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(all(
+    feature = "bytemuck",
+    not(all(debug_assertions, feature = "debug_name"))
+))]
+mod bytemuck;
+#[cfg(feature = "compact_str")]
+mod compact_str;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "godot")]
+mod godot;
+#[cfg(feature = "hash32")]
+mod hash32;
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "log-kv")]
+pub(crate) mod log;
+#[cfg(feature = "minicbor")]
+mod minicbor;
+#[cfg(feature = "miniserde")]
+mod miniserde;
+#[cfg(feature = "nohash")]
+mod nohash;
+#[cfg(feature = "scale")]
+mod scale;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "smol_str")]
+mod smol_str;
+#[cfg(feature = "speedy")]
+mod speedy;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+#[cfg(feature = "wasm")]
+mod wasm;
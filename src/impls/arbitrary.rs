@@ -0,0 +1,41 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::NameId;
+
+/// Characters used by names generated by [`NameId::arbitrary_named`].
+const NAME_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_";
+/// Maximum length of names generated by [`NameId::arbitrary_named`].
+const MAX_NAME_LEN: usize = 16;
+
+/// Consumes 8 bytes to construct a `NameId` from a raw hash value.
+impl<'a> Arbitrary<'a> for NameId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u64::arbitrary(u).map(NameId::from_value)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+impl NameId {
+    /// Generates a short ASCII name (1 to 16 lowercase letters, digits or
+    /// underscores) and returns its hash, like runtime constructors would.
+    ///
+    /// Unlike [`Arbitrary`] implementation, which produces raw values that
+    /// almost never correspond to a name, this allows fuzzers to produce ids
+    /// which equal names used by the code under test.
+    ///
+    /// Generated names aren't registered and returned ids don't carry a debug
+    /// label.
+    pub fn arbitrary_named(u: &mut Unstructured<'_>) -> Result<Self> {
+        let mut buffer = [0u8; MAX_NAME_LEN];
+        let len = u.int_in_range(1..=MAX_NAME_LEN)?;
+        for byte in &mut buffer[..len] {
+            *byte = *u.choose(NAME_CHARS)?;
+        }
+        let name = core::str::from_utf8(&buffer[..len]).expect("generated names are ASCII");
+        Ok(NameId::from_value(crate::hash_str(name)))
+    }
+}
@@ -0,0 +1,15 @@
+//! `ArrayString` is hashed the same way as a `&str` with the same content,
+//! without allocating.
+//!
+//! Contents aren't `'static`, so ids constructed this way don't store a debug
+//! label and aren't registered (so they aren't checked for collisions).
+
+use arrayvec::ArrayString;
+
+use crate::NameId;
+
+impl<const CAP: usize> From<&ArrayString<CAP>> for NameId {
+    fn from(name: &ArrayString<CAP>) -> Self {
+        NameId::from_value(crate::hash_str(name.as_str()))
+    }
+}
@@ -0,0 +1,14 @@
+//! `NameId` is reflected as an opaque value, so it's serialized in scenes
+//! through its serde implementation (as the `u64` hash value).
+
+use ::bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+use crate::NameId;
+
+::bevy_reflect::impl_reflect_opaque!((in name_id) NameId(
+    Debug,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize
+));
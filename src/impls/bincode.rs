@@ -0,0 +1,22 @@
+//! `NameId` is encoded as its `u64` hash value, using integer encoding of the
+//! active configuration. Debug label isn't encoded.
+
+use ::bincode::de::{Decode, Decoder};
+use ::bincode::enc::{Encode, Encoder};
+use ::bincode::error::{DecodeError, EncodeError};
+
+use crate::NameId;
+
+impl Encode for NameId {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.value().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for NameId {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        u64::decode(decoder).map(NameId::from_value)
+    }
+}
+
+::bincode::impl_borrow_decode!(NameId);
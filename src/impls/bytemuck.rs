@@ -0,0 +1,23 @@
+//! Only compiled when `NameId` doesn't store a debug label, in which case it
+//! consists of a `u64` value optionally followed by `fixed_size` padding
+//! bytes.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::NameId;
+
+// Pod requires that there are no padding bytes introduced by the compiler.
+const _: () = assert!(
+    NameId::SIZE == core::mem::size_of::<u64>()
+        || (cfg!(feature = "fixed_size")
+            && NameId::SIZE == core::mem::size_of::<u64>() + core::mem::size_of::<&'static str>())
+);
+
+// SAFETY: all fields are integers or integer arrays, for which all-zero bytes
+// are a valid value.
+unsafe impl Zeroable for NameId {}
+
+// SAFETY: NameId is `repr(transparent)` or `repr(C)` without compiler inserted
+// padding (asserted above), it's `Copy` and all bit patterns of its fields
+// are valid. `fixed_size` padding isn't read by any method.
+unsafe impl Pod for NameId {}
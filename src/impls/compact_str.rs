@@ -0,0 +1,24 @@
+//! `CompactString` is hashed the same way as a `&str` with the same content.
+//!
+//! Its contents aren't `'static`, so a name has to be copied to be stored as a
+//! label or registry entry. Conversions reuse the registered copy of a name,
+//! so only the first conversion of each name leaks it, and no label is stored
+//! without a registry.
+
+use compact_str::CompactString;
+
+use crate::NameId;
+
+impl From<&CompactString> for NameId {
+    #[track_caller]
+    fn from(name: &CompactString) -> Self {
+        crate::from_borrowed_name(name.as_str())
+    }
+}
+
+impl From<CompactString> for NameId {
+    #[track_caller]
+    fn from(name: CompactString) -> Self {
+        crate::from_borrowed_name(name.as_str())
+    }
+}
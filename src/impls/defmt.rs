@@ -0,0 +1,16 @@
+use defmt::{Format, Formatter};
+
+use crate::NameId;
+
+/// Mirrors [`Debug`](core::fmt::Debug) output, except that hash values are
+/// printed in hex. Labels are `&'static str` so they're sent as plain strings
+/// instead of being interned.
+impl Format for NameId {
+    fn format(&self, f: Formatter<'_>) {
+        #[cfg(all(debug_assertions, feature = "debug_name"))]
+        if !self.name.is_empty() {
+            return defmt::write!(f, "NameId({=str})", self.name);
+        }
+        defmt::write!(f, "NameId({=u64:#x})", self.value)
+    }
+}
@@ -0,0 +1,59 @@
+//! Godot strings store UTF-32 characters, so they're encoded as UTF-8 before
+//! hashing. Short names are encoded on the stack, so conversions don't
+//! allocate unless a name has to be stored for a debug label or the registry.
+
+use godot::builtin::{GString, StringName};
+
+use crate::NameId;
+
+impl NameId {
+    /// Constructs a `NameId` from a string given as characters, such as
+    /// [`StringName::chars`] and [`GString::chars`].
+    ///
+    /// The result is the same as for a `&str` of the same characters.
+    #[track_caller]
+    pub fn from_chars(chars: &[char]) -> Self {
+        #[cfg(not(any(
+            feature = "registry",
+            feature = "registry_static",
+            all(debug_assertions, feature = "debug_name")
+        )))]
+        {
+            /// Longest name (in UTF-8 bytes) that's encoded without allocating.
+            const STACK_NAME_LEN: usize = 256;
+
+            let len: usize = chars.iter().map(|c| c.len_utf8()).sum();
+            if len <= STACK_NAME_LEN {
+                let mut buffer = [0; STACK_NAME_LEN];
+                let mut end = 0;
+                for c in chars {
+                    end += c.encode_utf8(&mut buffer[end..]).len();
+                }
+                // SAFETY: buffer only contains UTF-8 encoded chars up to end.
+                let name = unsafe { core::str::from_utf8_unchecked(&buffer[..end]) };
+                return NameId::from_value(crate::hash_str(name));
+            }
+        }
+        NameId::from(chars.iter().collect::<alloc::string::String>())
+    }
+
+    /// Returns the debug label or registered name of this id as a
+    /// [`StringName`], if there is one.
+    pub fn to_string_name(&self) -> Option<StringName> {
+        self.label().map(StringName::from)
+    }
+}
+
+impl From<&StringName> for NameId {
+    #[track_caller]
+    fn from(name: &StringName) -> Self {
+        NameId::from_chars(name.chars())
+    }
+}
+
+impl From<&GString> for NameId {
+    #[track_caller]
+    fn from(name: &GString) -> Self {
+        NameId::from_chars(name.chars())
+    }
+}
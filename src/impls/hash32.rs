@@ -0,0 +1,12 @@
+use crate::hash::fold32;
+use crate::NameId;
+
+/// Writes the hash value folded into 32 bits (upper half XOR lower half) as
+/// 4 little-endian bytes.
+///
+/// [`IdentityHasher`](crate::IdentityHasher) returns the folded value as is.
+impl hash32::Hash for NameId {
+    fn hash<H: hash32::Hasher>(&self, state: &mut H) {
+        state.write(&fold32(self.value()).to_le_bytes());
+    }
+}
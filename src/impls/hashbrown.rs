@@ -0,0 +1,22 @@
+//! Names are equivalent to the `NameId` they hash into. Maps must use
+//! [`NameBuildHasher`](crate::NameBuildHasher) for lookups by name to hash
+//! names the same way as ids.
+
+use hashbrown::Equivalent;
+
+use crate::NameId;
+
+impl Equivalent<NameId> for str {
+    #[inline]
+    fn equivalent(&self, key: &NameId) -> bool {
+        key.value() == crate::hash_str(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Equivalent<NameId> for alloc::string::String {
+    #[inline]
+    fn equivalent(&self, key: &NameId) -> bool {
+        key.value() == crate::hash_str(self)
+    }
+}
@@ -0,0 +1,13 @@
+//! `heapless::String` is hashed the same way as a `&str` with the same
+//! content, without allocating.
+//!
+//! Contents aren't `'static`, so ids constructed this way don't store a debug
+//! label and aren't registered (so they aren't checked for collisions).
+
+use crate::NameId;
+
+impl<const N: usize> From<&heapless::String<N>> for NameId {
+    fn from(name: &heapless::String<N>) -> Self {
+        NameId::from_value(crate::hash_str(name.as_str()))
+    }
+}
@@ -0,0 +1,46 @@
+//! Ids are logged as `u64` values so log collectors can filter them
+//! numerically.
+
+use ::log::kv::{Error, Key, Source, ToKey, ToValue, Value, VisitSource};
+
+use crate::NameId;
+
+/// Key used for ids whose name isn't known.
+const UNNAMED_KEY: &str = "<unnamed NameId>";
+
+impl ToValue for NameId {
+    fn to_value(&self) -> Value<'_> {
+        Value::from(self.value())
+    }
+}
+
+/// Uses the debug label or registered name of the id as the key, or
+/// `"<unnamed NameId>"` if neither is available.
+impl ToKey for NameId {
+    fn to_key(&self) -> Key<'_> {
+        Key::from_str(self.label().unwrap_or(UNNAMED_KEY))
+    }
+}
+
+/// Key-value pairs describing a [`NameId`], returned by
+/// [`NameId::kv_pair`].
+#[derive(Debug, Clone, Copy)]
+pub struct LogKv(NameId);
+
+impl Source for LogKv {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), Error> {
+        visitor.visit_pair(Key::from_str("id"), self.0.to_value())?;
+        if let Some(name) = self.0.label() {
+            visitor.visit_pair(Key::from_str("id.name"), Value::from(name))?;
+        }
+        Ok(())
+    }
+}
+
+impl NameId {
+    /// Returns key-value pairs containing hash value of this id as `"id"`,
+    /// and its debug label or registered name (if known) as `"id.name"`.
+    pub fn kv_pair(&self) -> LogKv {
+        LogKv(*self)
+    }
+}
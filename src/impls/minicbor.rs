@@ -0,0 +1,39 @@
+//! `NameId` is CBOR encoded as an unsigned integer holding its hash value.
+//!
+//! With `minicbor_names` feature, decoding also accepts (definite length)
+//! text strings which are hashed the same way [`NameId::new`] would, without
+//! registering them or storing a debug label.
+
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{CborLen, Decode, Encode};
+
+use crate::NameId;
+
+impl<C> Encode<C> for NameId {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.u64(self.value())?.ok()
+    }
+}
+
+impl<C> CborLen<C> for NameId {
+    fn cbor_len(&self, ctx: &mut C) -> usize {
+        self.value().cbor_len(ctx)
+    }
+}
+
+impl<'b, C> Decode<'b, C> for NameId {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        #[cfg(feature = "minicbor_names")]
+        if d.datatype()? == minicbor::data::Type::String {
+            return d
+                .str()
+                .map(|name| NameId::from_value(crate::hash_str(name)));
+        }
+        d.u64().map(NameId::from_value)
+    }
+}
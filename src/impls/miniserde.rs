@@ -0,0 +1,56 @@
+//! `NameId` is serialized as a JSON number when its value is at most `2^53`,
+//! and as a string of decimal digits otherwise. JavaScript (and other JSON
+//! readers which store numbers as `f64`) can't represent larger integers
+//! exactly, so bigger values would silently change when read by them.
+//!
+//! Deserialization accepts both forms. Strings which don't consist only of
+//! ASCII digits are treated as names and hashed, same as with the human
+//! readable `serde` formats.
+
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+
+use miniserde::de::{Deserialize, Visitor};
+use miniserde::ser::{Fragment, Serialize};
+use miniserde::{make_place, Error, Result};
+
+use crate::NameId;
+
+/// Largest value serialized as a number.
+const MAX_NUMBER: u64 = 1 << 53;
+
+make_place!(Place);
+
+impl Serialize for NameId {
+    fn begin(&self) -> Fragment<'_> {
+        let value = self.value();
+        if value <= MAX_NUMBER {
+            Fragment::U64(value)
+        } else {
+            Fragment::Str(Cow::Owned(value.to_string()))
+        }
+    }
+}
+
+impl Deserialize for NameId {
+    fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+        Place::new(out)
+    }
+}
+
+impl Visitor for Place<NameId> {
+    fn nonnegative(&mut self, n: u64) -> Result<()> {
+        self.out = Some(NameId::from_value(n));
+        Ok(())
+    }
+
+    fn string(&mut self, s: &str) -> Result<()> {
+        let value = if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            s.parse().map_err(|_| Error)?
+        } else {
+            crate::hash_str(s)
+        };
+        self.out = Some(NameId::from_value(value));
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+use crate::NameId;
+
+/// [`Hash`](core::hash::Hash) implementation of `NameId` only writes its hash
+/// value with a single `write_u64` call.
+impl nohash_hasher::IsEnabled for NameId {}
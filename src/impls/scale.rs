@@ -0,0 +1,46 @@
+//! `NameId` is SCALE encoded as a fixed 8 byte little-endian `u64` (not
+//! compact), and described to metadata tooling as a newtype over `u64`.
+
+use parity_scale_codec::{Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+use scale_info::{build::Fields, Path, Type, TypeInfo};
+
+use crate::NameId;
+
+impl Encode for NameId {
+    fn size_hint(&self) -> usize {
+        core::mem::size_of::<u64>()
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.value().encode_to(dest)
+    }
+
+    fn encoded_size(&self) -> usize {
+        core::mem::size_of::<u64>()
+    }
+}
+
+impl EncodeLike for NameId {}
+
+impl Decode for NameId {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        u64::decode(input).map(NameId::from_value)
+    }
+}
+
+impl MaxEncodedLen for NameId {
+    fn max_encoded_len() -> usize {
+        u64::max_encoded_len()
+    }
+}
+
+impl TypeInfo for NameId {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("NameId", module_path!()))
+            .docs(&["Identifier derived from a string hash."])
+            .composite(Fields::unnamed().field(|f| f.ty::<u64>().type_name("u64")))
+    }
+}
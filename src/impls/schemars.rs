@@ -0,0 +1,36 @@
+//! Schema describes what [`NameId`] deserializer accepts in human-readable
+//! formats: an unsigned 64-bit hash value or a name string that gets hashed.
+
+use alloc::borrow::Cow;
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::NameId;
+
+impl JsonSchema for NameId {
+    fn schema_name() -> Cow<'static, str> {
+        "NameId".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "name_id::NameId".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "title": "NameId",
+            "description": "Identifier stored as a 64-bit hash of a name. Either the hash value itself, or the name string which gets hashed on deserialization.",
+            "anyOf": [
+                {
+                    "type": "integer",
+                    "format": "uint64",
+                    "minimum": 0,
+                    "maximum": u64::MAX
+                },
+                {
+                    "type": "string"
+                }
+            ]
+        })
+    }
+}
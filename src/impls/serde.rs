@@ -0,0 +1,82 @@
+//! `NameId` is serialized as its `u64` hash value in all formats.
+//!
+//! Human-readable formats additionally accept strings on deserialization, which
+//! get hashed the same way runtime constructors hash them. That allows
+//! configuration files to reference ids by name (e.g. `"player_spawn"`).
+//! Deserialized ids don't carry a debug label and aren't registered.
+//!
+//! Formats that only support signed 64-bit integers (e.g. TOML) can't store
+//! hash values above `i64::MAX`, which are about half of all ids, so
+//! serializing them fails. Store ids as strings in those formats instead,
+//! with [`serde_str`](crate::serde_str) (names, or hex values if they aren't
+//! known), or read them with [`flexible`](crate::flexible), which also
+//! accepts `0x` prefixed hex values.
+
+use core::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{MaybeNamed, NameId};
+
+impl Serialize for NameId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for NameId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NameIdVisitor)
+        } else {
+            deserializer.deserialize_u64(NameIdVisitor)
+        }
+    }
+}
+
+pub(crate) struct NameIdVisitor;
+
+impl Visitor<'_> for NameIdVisitor {
+    type Value = NameId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an unsigned 64-bit id value or a name string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<NameId, E> {
+        Ok(NameId::from_value(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<NameId, E> {
+        u64::try_from(value)
+            .map(NameId::from_value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<NameId, E> {
+        Ok(NameId::from_value(crate::hash_str(value)))
+    }
+}
+
+/// Serializes the name as a string in human-readable formats when it's known,
+/// and the `u64` hash value otherwise. Binary formats can't tell the two
+/// apart without deserializing any type, so they always get the hash value.
+impl Serialize for MaybeNamed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MaybeNamed::Named(_, name) if serializer.is_human_readable() => {
+                serializer.serialize_str(name)
+            }
+            _ => self.id().serialize(serializer),
+        }
+    }
+}
+
+/// Deserializes a `NameId` and [resolves](MaybeNamed::resolve) it, so names
+/// read from strings are only kept if they're known already.
+impl<'de> Deserialize<'de> for MaybeNamed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NameId::deserialize(deserializer).map(MaybeNamed::resolve)
+    }
+}
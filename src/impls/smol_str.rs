@@ -0,0 +1,24 @@
+//! `SmolStr` is hashed the same way as a `&str` with the same content.
+//!
+//! Its contents aren't `'static`, so a name has to be copied to be stored as a
+//! label or registry entry. Conversions reuse the registered copy of a name,
+//! so only the first conversion of each name leaks it, and no label is stored
+//! without a registry.
+
+use smol_str::SmolStr;
+
+use crate::NameId;
+
+impl From<&SmolStr> for NameId {
+    #[track_caller]
+    fn from(name: &SmolStr) -> Self {
+        crate::from_borrowed_name(name.as_str())
+    }
+}
+
+impl From<SmolStr> for NameId {
+    #[track_caller]
+    fn from(name: SmolStr) -> Self {
+        crate::from_borrowed_name(name.as_str())
+    }
+}
@@ -0,0 +1,31 @@
+//! `NameId` is written as its `u64` hash value in the endianness of the
+//! context. Debug labels and padding are never read or written, so the format
+//! doesn't depend on the build profile or enabled features.
+
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+use crate::NameId;
+
+impl<'a, C: Context> Readable<'a, C> for NameId {
+    #[inline]
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_u64().map(NameId::from_value)
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        core::mem::size_of::<u64>()
+    }
+}
+
+impl<C: Context> Writable<C> for NameId {
+    #[inline]
+    fn write_to<T: ?Sized + Writer<C>>(&self, writer: &mut T) -> Result<(), C::Error> {
+        writer.write_u64(self.value())
+    }
+
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(core::mem::size_of::<u64>())
+    }
+}
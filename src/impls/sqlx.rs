@@ -0,0 +1,49 @@
+//! `NameId` is stored as a signed 64-bit integer (`BIGINT`/`INTEGER`), as
+//! none of the supported databases have an unsigned 64-bit column type. The
+//! hash value is bit-cast (`NameId::value_i64`), so values with the top bit
+//! set are stored as negative numbers and read back without loss.
+
+use sqlx::decode::Decode;
+use sqlx::encode::{Encode, IsNull};
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Type};
+
+use crate::NameId;
+
+impl<DB: Database> Type<DB> for NameId
+where
+    i64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i64 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for NameId
+where
+    i64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.value_i64().encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.value_i64().size_hint()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for NameId
+where
+    i64: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        <i64 as Decode<DB>>::decode(value).map(NameId::from_raw_i64)
+    }
+}
@@ -0,0 +1,11 @@
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::NameId;
+
+/// Compares hash values, see [`NameId::ct_eq`].
+impl ConstantTimeEq for NameId {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.value().ct_eq(&other.value())
+    }
+}
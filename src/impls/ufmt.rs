@@ -0,0 +1,31 @@
+//! Output matches `core::fmt` implementations of [`NameId`], but is written
+//! without going through `core::fmt` machinery.
+
+use ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+use crate::NameId;
+
+impl uDisplay for NameId {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        self.with_display_name(|name| match name {
+            Some(name) => {
+                f.write_str("#{")?;
+                f.write_str(name)?;
+                f.write_str("}")
+            }
+            None => uwrite!(f, "NameId({})", self.value),
+        })
+    }
+}
+
+impl uDebug for NameId {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        #[cfg(all(debug_assertions, feature = "debug_name"))]
+        if !self.name.is_empty() {
+            f.write_str("NameId(")?;
+            f.write_str(self.name)?;
+            return f.write_str(")");
+        }
+        uwrite!(f, "NameId({})", self.value)
+    }
+}
@@ -0,0 +1,60 @@
+//! JavaScript numbers can't represent all `u64` values, so exported methods
+//! pass the hash value as two `u32` halves, a string, or a `BigInt`.
+
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::NameId;
+
+#[wasm_bindgen]
+impl NameId {
+    /// Returns the upper 32 bits of the hash value.
+    pub fn value_hi(&self) -> u32 {
+        (self.value() >> 32) as u32
+    }
+
+    /// Returns the lower 32 bits of the hash value.
+    pub fn value_lo(&self) -> u32 {
+        self.value() as u32
+    }
+
+    /// Constructs a `NameId` from upper (`hi`) and lower (`lo`) 32 bits of the
+    /// hash value.
+    pub fn from_parts(hi: u32, lo: u32) -> NameId {
+        NameId::from_value((hi as u64) << 32 | lo as u64)
+    }
+
+    /// Returns the hash value as a decimal string.
+    pub fn to_js_string(&self) -> String {
+        self.value().to_string()
+    }
+
+    /// Parses a hash value from a decimal or a `0x` prefixed hexadecimal
+    /// string, returning `undefined` if it isn't valid.
+    pub fn from_js_string(value: &str) -> Option<NameId> {
+        let value = match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => value.parse(),
+        };
+        value.ok().map(NameId::from_value)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl From<NameId> for js_sys::BigInt {
+    fn from(id: NameId) -> Self {
+        js_sys::BigInt::from(id.value())
+    }
+}
+
+/// Fails if the `BigInt` is negative or doesn't fit into 64 bits, returning
+/// it back.
+#[cfg(target_arch = "wasm32")]
+impl TryFrom<js_sys::BigInt> for NameId {
+    type Error = js_sys::BigInt;
+
+    fn try_from(value: js_sys::BigInt) -> Result<Self, Self::Error> {
+        u64::try_from(value).map(NameId::from_value)
+    }
+}
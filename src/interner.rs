@@ -0,0 +1,345 @@
+//! Interner mapping names to [`NameId`]s and back, which owns its names.
+//!
+//! Unlike the global registry, names aren't leaked, so an interner (and all
+//! names stored in it) can be dropped. This makes it suitable for names
+//! discovered at runtime in long-running processes.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::NameId;
+
+/// Error returned when a name hashes to the same value as a different name
+/// that's already stored in the interner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InternCollision {
+    /// Id shared by both names.
+    pub id: NameId,
+}
+
+impl core::fmt::Display for InternCollision {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "name collides with a different interned name with hash value {:#018x}",
+            self.id.value()
+        )
+    }
+}
+
+impl core::error::Error for InternCollision {}
+
+/// Handling of names that collide with a different stored name, set with
+/// [`NameInterner::on_collision`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollisionPolicy {
+    /// [`NameInterner::intern`] returns an [`InternCollision`] error.
+    #[default]
+    Error,
+    /// The name is rehashed with a salt until an unused id is found, or
+    /// `max_attempts` salts were tried, after which an error is returned.
+    ///
+    /// Attempt `n` (starting at 1) uses the id
+    /// [`NameId::new_keyed(n, name)`](NameId::new_keyed).
+    ///
+    /// **Rehashed ids are local to the interner.** They aren't equal to
+    /// `NameId::new(name)` or `id!(name)`, depend on the order in which names
+    /// were interned, and must not be persisted or shared with other
+    /// processes.
+    Rehash {
+        /// Number of salts tried before giving up.
+        max_attempts: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    value: u64,
+    start: usize,
+    end: usize,
+}
+
+/// Bidirectional mapping between names and [`NameId`]s.
+///
+/// All names are stored in a single buffer, and entries are kept sorted by
+/// hash value, so lookups take `O(log n)` time and interning a new name takes
+/// `O(n)` time. Interning a name that's already present doesn't allocate.
+///
+/// ```
+/// # use name_id::{id, NameInterner};
+/// let mut interner = NameInterner::new();
+/// let id = interner.intern("player_spawn").unwrap();
+/// assert_eq!(id, id!(player_spawn));
+/// assert_eq!(interner.resolve(id), Some("player_spawn"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NameInterner {
+    names: String,
+    entries: Vec<Entry>,
+    on_collision: CollisionPolicy,
+}
+
+impl NameInterner {
+    /// Creates an empty interner.
+    pub const fn new() -> Self {
+        NameInterner {
+            names: String::new(),
+            entries: Vec::new(),
+            on_collision: CollisionPolicy::Error,
+        }
+    }
+
+    /// Creates an empty interner with space for at least `names` names with
+    /// a total length of `bytes`.
+    pub fn with_capacity(names: usize, bytes: usize) -> Self {
+        NameInterner {
+            names: String::with_capacity(bytes),
+            entries: Vec::with_capacity(names),
+            on_collision: CollisionPolicy::Error,
+        }
+    }
+
+    /// Sets how names colliding with a different stored name are handled.
+    ///
+    /// ```
+    /// # use name_id::{CollisionPolicy, NameInterner};
+    /// let interner = NameInterner::new().on_collision(CollisionPolicy::Rehash { max_attempts: 4 });
+    /// ```
+    ///
+    /// With [`CollisionPolicy::Rehash`], ids returned for colliding names are
+    /// only meaningful to this interner, see its documentation.
+    pub const fn on_collision(mut self, policy: CollisionPolicy) -> Self {
+        self.on_collision = policy;
+        self
+    }
+
+    /// Returns the policy set with [`on_collision`](NameInterner::on_collision).
+    pub const fn collision_policy(&self) -> CollisionPolicy {
+        self.on_collision
+    }
+
+    /// Returns the id of `name`, storing the name if it isn't present yet.
+    ///
+    /// Returns an error if a different name with the same hash value is
+    /// already present, unless [`CollisionPolicy::Rehash`] is used and a
+    /// rehashed id of `name` is available.
+    pub fn intern(&mut self, name: &str) -> Result<NameId, InternCollision> {
+        let id = NameId::from_value(crate::hash_str(name));
+        let err = match self.insert(id, name) {
+            Ok(()) => return Ok(id),
+            Err(err) => err,
+        };
+        let CollisionPolicy::Rehash { max_attempts } = self.on_collision else {
+            return Err(err);
+        };
+        // same name always tries the same salts, so it finds its rehashed id
+        for attempt in 1..=max_attempts {
+            let salted = NameId::new_keyed(attempt as u64, name);
+            if self.insert(salted, name).is_ok() {
+                return Ok(salted);
+            }
+        }
+        Err(err)
+    }
+
+    /// Stores `name` as the name of `id`, without hashing it.
+    ///
+    /// Useful for names of ids that were hashed elsewhere. Returns an error if
+    /// a different name is already stored for `id`.
+    pub fn insert(&mut self, id: NameId, name: &str) -> Result<(), InternCollision> {
+        match self.search(id) {
+            Ok(i) if self.name(&self.entries[i]) == name => Ok(()),
+            Ok(_) => Err(InternCollision { id }),
+            Err(i) => {
+                let start = self.names.len();
+                self.names.push_str(name);
+                let entry = Entry {
+                    value: id.value(),
+                    start,
+                    end: self.names.len(),
+                };
+                self.entries.insert(i, entry);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the name stored for `id`.
+    pub fn resolve(&self, id: NameId) -> Option<&str> {
+        self.search(id).ok().map(|i| self.name(&self.entries[i]))
+    }
+
+    /// Returns `true` if a name is stored for `id`.
+    pub fn contains(&self, id: NameId) -> bool {
+        self.search(id).is_ok()
+    }
+
+    /// Returns the number of stored names.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no stored names.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over ids and their names, in order of hash values.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            names: &self.names,
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Returns the number of names and the total length of names that can be
+    /// stored without reallocating.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.entries.capacity(), self.names.capacity())
+    }
+
+    /// Reserves space for at least `names` more names with a total length of
+    /// `bytes`.
+    pub fn reserve(&mut self, names: usize, bytes: usize) {
+        self.entries.reserve(names);
+        self.names.reserve(bytes);
+    }
+
+    /// Shrinks the capacity to fit stored names.
+    pub fn shrink_to_fit(&mut self) {
+        self.entries.shrink_to_fit();
+        self.names.shrink_to_fit();
+    }
+
+    /// Removes all names, keeping allocated capacity.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.names.clear();
+    }
+
+    fn search(&self, id: NameId) -> Result<usize, usize> {
+        self.entries
+            .binary_search_by_key(&id.value(), |entry| entry.value)
+    }
+
+    fn name(&self, entry: &Entry) -> &str {
+        &self.names[entry.start..entry.end]
+    }
+}
+
+impl<'a> IntoIterator for &'a NameInterner {
+    type Item = (NameId, &'a str);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over names stored in a [`NameInterner`], returned by
+/// [`NameInterner::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    names: &'a str,
+    entries: core::slice::Iter<'a, Entry>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (NameId, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| {
+            (
+                NameId::from_value(entry.value),
+                &self.names[entry.start..entry.end],
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+/// [`NameInterner`] which can be shared between threads, guarded by the lock
+/// of the selected locking backend.
+///
+/// Names are returned as owned copies, use [`with`](SharedInterner::with) to
+/// borrow them instead.
+#[cfg(any(feature = "std", feature = "critical-section", feature = "spin"))]
+pub struct SharedInterner {
+    inner: crate::sync::Mutex<NameInterner>,
+}
+
+#[cfg(any(feature = "std", feature = "critical-section", feature = "spin"))]
+impl SharedInterner {
+    /// Creates an empty interner. Usable in `static` items.
+    pub const fn new() -> Self {
+        SharedInterner {
+            inner: crate::sync::Mutex::new(NameInterner::new()),
+        }
+    }
+
+    /// Same as [`NameInterner::intern`].
+    pub fn intern(&self, name: &str) -> Result<NameId, InternCollision> {
+        self.with(|interner| interner.intern(name))
+    }
+
+    /// Same as [`NameInterner::insert`].
+    pub fn insert(&self, id: NameId, name: &str) -> Result<(), InternCollision> {
+        self.with(|interner| interner.insert(id, name))
+    }
+
+    /// Returns a copy of the name stored for `id`.
+    pub fn resolve(&self, id: NameId) -> Option<String> {
+        self.with(|interner| interner.resolve(id).map(String::from))
+    }
+
+    /// Same as [`NameInterner::contains`].
+    pub fn contains(&self, id: NameId) -> bool {
+        self.with(|interner| interner.contains(id))
+    }
+
+    /// Same as [`NameInterner::len`].
+    pub fn len(&self) -> usize {
+        self.with(|interner| interner.len())
+    }
+
+    /// Same as [`NameInterner::is_empty`].
+    pub fn is_empty(&self) -> bool {
+        self.with(|interner| interner.is_empty())
+    }
+
+    /// Runs `f` with exclusive access to the interner.
+    ///
+    /// The lock isn't reentrant, so `f` must not access this interner through
+    /// the shared reference.
+    pub fn with<R>(&self, f: impl FnOnce(&mut NameInterner) -> R) -> R {
+        self.inner.with(f)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "critical-section", feature = "spin"))]
+impl Default for SharedInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "critical-section", feature = "spin"))]
+impl From<NameInterner> for SharedInterner {
+    fn from(interner: NameInterner) -> Self {
+        SharedInterner {
+            inner: crate::sync::Mutex::new(interner),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "critical-section", feature = "spin"))]
+impl core::fmt::Debug for SharedInterner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.with(|interner| interner.fmt(f))
+    }
+}
@@ -0,0 +1,148 @@
+//! List of ids declared with [`id!`](crate::id) macro anywhere in the binary.
+//!
+//! Entries are collected by the linker from all crates (using `linkme`), so
+//! it works across crate boundaries without any registration calls. This is
+//! only supported on platforms supported by `linkme` (Linux, macOS, Windows,
+//! and some others), notably not on WebAssembly.
+
+use crate::NameId;
+
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static __DECLARED_IDS: [(&'static str, NameId)];
+
+/// Returns an iterator over names and ids declared with [`id!`](crate::id)
+/// macro in any crate linked into the binary.
+///
+/// Each macro invocation adds an entry, so names used in several places are
+/// returned several times. Order of entries is unspecified. Use
+/// [`declared_ids_dedup`] to get each name only once.
+pub fn declared_ids() -> impl ExactSizeIterator<Item = (&'static str, NameId)> + Clone {
+    __DECLARED_IDS.iter().copied()
+}
+
+/// Returns declared names and ids sorted by hash value, with duplicate
+/// entries removed.
+///
+/// Different names with the same hash value (collisions) are all kept.
+#[cfg(feature = "alloc")]
+pub fn declared_ids_dedup() -> alloc::vec::Vec<(&'static str, NameId)> {
+    let mut ids: alloc::vec::Vec<_> = declared_ids().collect();
+    ids.sort_unstable_by(|a, b| (a.1.value(), a.0).cmp(&(b.1.value(), b.0)));
+    ids.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1);
+    ids
+}
+
+/// Returns the name `id` was declared with, if it was declared with
+/// [`id!`](crate::id) macro in any crate linked into the binary.
+///
+/// Performs a linear search over all entries.
+pub fn find_declared(id: NameId) -> Option<&'static str> {
+    declared_ids()
+        .find(|(_, declared)| *declared == id)
+        .map(|(name, _)| name)
+}
+
+/// Problem with ids declared with [`id!`](crate::id) macro, found by
+/// [`verify_declared`].
+#[cfg(feature = "paranoid")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationError {
+    /// Id declared for `name` isn't the hash runtime constructors compute
+    /// for it, e.g. because the declaring crate was compiled with a different
+    /// hasher.
+    Mismatch {
+        /// Declared name.
+        name: &'static str,
+        /// Id computed by `id!` macro.
+        declared: NameId,
+        /// Hash value computed at runtime.
+        runtime: u64,
+    },
+    /// Different names were declared with the same id.
+    Collision(crate::Collision<'static>),
+}
+
+#[cfg(feature = "paranoid")]
+impl core::fmt::Display for DeclarationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeclarationError::Mismatch {
+                name,
+                declared,
+                runtime,
+            } => write!(
+                f,
+                "{name:?} was declared with hash value {:#018x}, but hashes to {runtime:#018x} at runtime",
+                declared.value()
+            ),
+            DeclarationError::Collision(collision) => write!(f, "{collision}"),
+        }
+    }
+}
+
+#[cfg(feature = "paranoid")]
+impl core::error::Error for DeclarationError {}
+
+/// Checks all ids declared with [`id!`](crate::id) macro in any crate linked
+/// into the binary, and returns every problem found.
+///
+/// Collision detection at compile time can only see ids of crates compiled
+/// together, so it misses ids of separately compiled crates (e.g. plugins
+/// loaded as dynamic libraries). This hashes each declared name again with
+/// the runtime hasher and reports:
+/// - [`Mismatch`](DeclarationError::Mismatch) for ids computed differently
+///   by the macro and runtime constructors, and
+/// - [`Collision`](DeclarationError::Collision) for different names declared
+///   with the same id.
+///
+/// ```
+/// assert_eq!(name_id::verify_declared(), Ok(()));
+/// ```
+///
+/// Mismatches are ordered by name, and followed by collisions ordered by hash
+/// value and names. Repeated declarations of the same name and id are
+/// reported once.
+///
+/// With `paranoid_startup` feature, this runs automatically before `main` in
+/// debug builds, and panics if it finds any problem.
+#[cfg(feature = "paranoid")]
+pub fn verify_declared() -> Result<(), alloc::vec::Vec<DeclarationError>> {
+    let declared = declared_ids_dedup();
+    let mut mismatches: alloc::vec::Vec<_> = declared
+        .iter()
+        .map(|&(name, declared)| (name, declared, crate::hash_str(name)))
+        .filter(|(_, declared, runtime)| declared.value() != *runtime)
+        .collect();
+    mismatches.sort_unstable_by_key(|(name, declared, _)| (*name, declared.value()));
+    let mut errors: alloc::vec::Vec<_> = mismatches
+        .into_iter()
+        .map(|(name, declared, runtime)| DeclarationError::Mismatch {
+            name,
+            declared,
+            runtime,
+        })
+        .collect();
+    crate::scan::report_sorted(
+        &declared,
+        |(name, id)| (id.value(), *name),
+        |collision| errors.push(DeclarationError::Collision(collision)),
+    );
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(all(feature = "paranoid_startup", debug_assertions))]
+#[ctor::ctor]
+fn verify_declared_on_startup() {
+    if let Err(errors) = verify_declared() {
+        let mut message = alloc::string::String::from("declared ids are invalid:");
+        for error in errors {
+            message.push_str(&alloc::format!("\n- {error}"));
+        }
+        panic!("{message}");
+    }
+}
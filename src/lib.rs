@@ -1,16 +1,285 @@
 #![doc = include_str!("../doc/crate.md")]
 
 #![no_std]
+#![cfg_attr(feature = "adt_const_params", feature(adt_const_params))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+// allows using `id!` within this crate
+extern crate self as name_id;
+
+// Cargo features already enforce these, but cfgs can also be passed manually.
+#[cfg(all(feature = "registry", not(feature = "alloc")))]
+compile_error!(
+    "\"registry\" and \"detect_collisions\" features require \"alloc\", use \"registry_static\" and \"detect_collisions_static\" for a registry that doesn't"
+);
+
+#[cfg(all(feature = "zerocopy", debug_assertions, feature = "debug_name"))]
+compile_error!(
+    "\"zerocopy\" feature can't be used in debug builds with \"debug_name\" feature because the debug label is stored as a reference, disable \"debug_name\" to use it"
+);
+#[cfg(all(feature = "ffi", debug_assertions, feature = "debug_name"))]
+compile_error!(
+    "\"ffi\" feature requires NameId to be ABI-identical to uint64_t, which isn't the case in debug builds with \"debug_name\" feature"
+);
+#[cfg(all(feature = "adt_const_params", debug_assertions, feature = "debug_name"))]
+compile_error!(
+    "\"adt_const_params\" feature can't be used in debug builds with \"debug_name\" feature because the debug label prevents NameId from being a const parameter type, disable \"debug_name\" to use it"
+);
+#[cfg(all(feature = "bytemuck", debug_assertions, feature = "debug_name"))]
+compile_error!(
+    "\"bytemuck\" feature can't be used in debug builds with \"debug_name\" feature because the debug label is stored as a reference, disable \"debug_name\" to use it"
+);
+
 use core::hash::{Hash, Hasher as _};
 
-#[cfg(feature = "detect_collisions")]
-use alloc::collections::BTreeMap;
+pub use name_id_macros::{content_id, id, id_phf, id_value, ids, verify_ids_in, FieldIds};
+/// Empty and reversed ranges are rejected at compile time:
+///
+/// ```compile_fail
+/// let bones = name_id::id_seq!("bone_", 4..4);
+/// ```
+///
+/// ```compile_fail
+/// let bones = name_id::id_seq!("bone_", 8..=2);
+/// ```
+pub use name_id_macros::id_seq;
+/// Generic and trait `impl` blocks are rejected:
+///
+/// ```compile_fail
+/// struct Door;
+/// trait Open {
+///     fn open(&self);
+/// }
+/// #[name_id::method_ids]
+/// impl Open for Door {
+///     fn open(&self) {}
+/// }
+/// ```
+///
+/// ```compile_fail
+/// struct Door<T>(T);
+/// #[name_id::method_ids]
+/// impl<T> Door<T> {
+///     pub fn open(&self) {}
+/// }
+/// ```
+pub use name_id_macros::method_ids;
 
-pub use name_id_macros::id;
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+mod atomic;
+#[cfg(feature = "clap")]
+pub mod clap;
+// `fixed_size` ids aren't ABI-identical to uint64_t, so nothing is exported
+#[cfg(all(feature = "ffi", not(feature = "fixed_size")))]
+pub mod ffi;
+#[cfg(feature = "collections")]
+pub mod collections;
+#[cfg(feature = "alloc")]
+pub mod dictionary;
+mod builder;
+#[cfg(all(feature = "portable_hash", not(feature = "dynamic_hasher")))]
+pub mod codegen;
+mod bytes;
+mod ct_eq;
+mod dense;
+mod display;
+#[cfg(feature = "alloc")]
+mod dump;
+#[cfg(feature = "dynamic_hasher")]
+mod dynamic;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+mod dynamic_registry;
+mod filter;
+mod fingerprint;
+#[cfg(feature = "serde")]
+pub mod flexible;
+#[cfg(feature = "alloc")]
+pub mod interner;
+#[cfg(feature = "inventory")]
+mod inventory;
+mod hash;
+mod impls;
+mod mask;
+mod maybe_named;
+#[cfg(feature = "std")]
+mod os_str;
+#[cfg(feature = "alloc")]
+mod name_table;
+mod parse;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+#[cfg(feature = "serde")]
+pub mod serde_key;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+pub mod sparse;
+mod phf;
+mod resolved;
+mod scan;
+#[cfg(all(
+    feature = "ahash",
+    any(not(feature = "portable_hash"), feature = "dynamic_hasher")
+))]
+mod seeded;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "registry", feature = "registry_static", feature = "clap")
+))]
+mod suggest;
+#[cfg(any(feature = "portable_hash", feature = "dynamic_hasher"))]
+mod portable;
+mod table;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+mod type_registry;
+#[cfg(feature = "serde")]
+pub mod serde_named_keys;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+mod varint;
+mod wrapper;
+// reference values are computed by `id!` macro
+#[cfg(not(feature = "dynamic_hasher"))]
+#[doc(hidden)]
+pub mod self_test;
+#[cfg(not(feature = "dynamic_hasher"))]
+pub use self_test::{self_test, SelfTestError};
+#[cfg(feature = "dynamic_hasher")]
+pub use dynamic::{
+    builtin_hasher, install_hasher, HashFn, InstallHasherError, BUILTIN_HASHERS, DEFAULT_HASHER,
+};
+#[cfg(feature = "alloc")]
+pub use dictionary::{DictionaryError, IdDictionary};
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+pub use atomic::AtomicNameId;
+pub use builder::NameIdBuilder;
+pub use bytes::NonZeroPadding;
+pub use display::BufferTooSmall;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+pub use dense::DenseIndexAllocator;
+pub use dense::{DenseIndexes, FixedDenseIndexAllocator, IndexesExhausted};
+#[cfg(feature = "alloc")]
+pub use dump::{dump_registry, dump_registry_string, DumpOrder};
+pub use filter::IdFilter;
+pub use fingerprint::{
+    check_fingerprint, AlgorithmInfo, FingerprintMismatch, ALGORITHM, ALGORITHM_FINGERPRINT,
+};
+pub use mask::{IdMask, MaskUniverse, NotInUniverse};
+pub use maybe_named::MaybeNamed;
+pub use parse::ParseIdError;
+#[cfg(feature = "std")]
+pub use os_str::NonUtf8Name;
+#[cfg(feature = "alloc")]
+pub use name_table::NameTable;
+pub use phf::NameIdPhf;
+pub use resolved::Resolved;
+pub use scan::{scan_collisions_in, Collision};
+#[cfg(feature = "alloc")]
+pub use scan::scan_collisions;
+#[cfg(feature = "inventory")]
+pub use inventory::{declared_ids, find_declared, __DECLARED_IDS};
+#[cfg(all(feature = "inventory", feature = "alloc"))]
+pub use inventory::declared_ids_dedup;
+#[cfg(feature = "paranoid")]
+pub use inventory::{verify_declared, DeclarationError};
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub use linkme as __linkme;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use wrapper::__prefixed_id;
+pub use table::NameIdTable;
+#[cfg(feature = "alloc")]
+pub use interner::{CollisionPolicy, InternCollision, NameInterner};
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+pub use sparse::SparseIdMap;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+pub use interner::SharedInterner;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+pub use dynamic_registry::{DynamicRegistry, RegisteredId};
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+pub use type_registry::{TypeConflict, TypeRegistry};
+pub use varint::VarintError;
+pub use hash::{IdentityBuildHasher, IdentityHasher};
+#[cfg(feature = "hashbrown")]
+pub use hash::{NameBuildHasher, NameHasher};
+#[cfg(feature = "collections")]
+pub use collections::{IdMap, IdSet};
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+pub use hash::{IdHashMap, IdHashSet};
+#[cfg(feature = "heapless")]
+pub use hash::IdIndexMap;
+#[cfg(feature = "log-kv")]
+pub use impls::log::LogKv;
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+mod registry;
+#[cfg(any(
+    feature = "registry",
+    feature = "registry_static",
+    all(
+        feature = "alloc",
+        any(feature = "std", feature = "critical-section", feature = "spin")
+    )
+))]
+mod sync;
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+pub use registry::{
+    register, registry_contains, registry_find_prefix, registry_iter, registry_len, RegistryIter,
+};
+#[cfg(all(any(feature = "registry", feature = "registry_static"), feature = "alloc"))]
+pub use registry::{registry_find_prefix_vec, registry_suggest, registry_suggest_for_id};
+#[cfg(feature = "registry_static")]
+pub use registry::REGISTRY_CAPACITY;
+#[cfg(any(feature = "registry", feature = "registry_static", feature = "alloc"))]
+mod snapshot;
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+pub use snapshot::registry_snapshot;
+#[cfg(all(any(feature = "registry", feature = "registry_static"), feature = "alloc"))]
+pub use snapshot::registry_snapshot_bytes;
+#[cfg(any(feature = "registry", feature = "registry_static", feature = "alloc"))]
+pub use snapshot::SnapshotError;
+#[cfg(feature = "alloc")]
+pub use snapshot::RegistrySnapshot;
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+pub use registry::stats::{registry_stats, RegistryStats};
+#[cfg(all(any(feature = "registry", feature = "registry_static"), feature = "testing"))]
+pub use registry::stats::registry_clear;
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+pub use registry::{
+    debug_panic_on_collision, panic_on_collision, set_collision_handler, CollisionHandler,
+    CollisionInfo, CollisionKind,
+};
 
 macro_rules! assert_unique_feature {
     () => {};
@@ -24,14 +293,18 @@ macro_rules! assert_unique_feature {
 }
 assert_unique_feature!("ahash");
 
-#[cfg(feature = "ahash")]
-type Hasher = ahash::AHasher;
+#[cfg(all(feature = "ahash", not(any(feature = "portable_hash", feature = "dynamic_hasher"))))]
+type Hasher = seeded::SeededHasher;
+#[cfg(all(feature = "portable_hash", not(feature = "dynamic_hasher")))]
+type Hasher = portable::PortableHasher;
+#[cfg(feature = "dynamic_hasher")]
+type Hasher = dynamic::DynamicHasher;
 
-#[cfg(feature = "detect_collisions")]
-static mut LOOKUP: BTreeMap<u64, &'static str> = BTreeMap::new();
-#[cfg(feature = "detect_collisions")]
-fn lookup() -> &'static mut BTreeMap<u64, &'static str> {
-    unsafe { core::ptr::addr_of_mut!(LOOKUP).as_mut().unwrap_unchecked() }
+/// Hashes `name` the same way runtime constructors and `id!` macro do.
+pub(crate) fn hash_str(name: &str) -> u64 {
+    let mut hasher = Hasher::default();
+    name.hash(&mut hasher);
+    hasher.finish()
 }
 
 /// A small identifier type based on string hash values.
@@ -41,23 +314,114 @@ fn lookup() -> &'static mut BTreeMap<u64, &'static str> {
 /// hasher, and stored as a `u64`.
 /// 
 /// For convenient compile-time constuction use [`id!`][id] macro.
+///
+/// # Performance
+///
+/// In release builds without `fixed_size` feature, `NameId` has the layout
+/// of a `u64` and is passed in registers, and comparisons are always inlined.
+/// Comparing an id with an [`id!`][id] constant (with `==`,
+/// [`const_eq`](NameId::const_eq) or `match` on [`value`](NameId::value))
+/// compiles to a single integer comparison with an immediate value, so it
+/// costs the same as comparing `u64`s in hot loops. Codegen tests in
+/// `tests/codegen` check this for every release.
 #[derive(Clone, Copy)]
+// const parameter types require derived equality
+#[cfg_attr(
+    all(feature = "adt_const_params", not(all(debug_assertions, feature = "debug_name"))),
+    derive(PartialEq, Eq, core::marker::ConstParamTy)
+)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
+#[cfg_attr(
+    all(feature = "zerocopy", not(all(debug_assertions, feature = "debug_name"))),
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+// `value` is the only field unless there's a debug label or `fixed_size`
+// padding, in which case it's followed by one of them in `repr(C)` order.
+#[cfg_attr(
+    not(any(all(debug_assertions, feature = "debug_name"), feature = "fixed_size")),
+    repr(transparent)
+)]
 #[cfg_attr(
-    all(not(feature = "fixed_size"), not(all(debug_assertions, feature = "debug_name"))),
+    any(all(debug_assertions, feature = "debug_name"), feature = "fixed_size"),
     repr(C)
 )]
-#[cfg_attr(any(not(all(debug_assertions, feature = "debug_name")), feature = "fixed_size"), repr(transparent))]
 pub struct NameId {
     value: u64,
     #[cfg(all(debug_assertions, feature = "debug_name"))]
     name: &'static str,
-    #[cfg(all(not(debug_assertions), not(feature = "debug_name"), feature = "fixed_size"))]
+    #[cfg(all(feature = "fixed_size", not(all(debug_assertions, feature = "debug_name"))))]
     _padding: [u8; core::mem::size_of::<&'static str>()],
 }
 
+// Pins the layouts documented on `NameId::SIZE`, so that unintentional
+// changes fail to compile.
+const _: () = {
+    use core::mem::{align_of, size_of};
+    let labeled = cfg!(all(debug_assertions, feature = "debug_name"));
+    assert!(NameId::IS_TRANSPARENT_U64 == (!labeled && !cfg!(feature = "fixed_size")));
+    if NameId::IS_TRANSPARENT_U64 {
+        assert!(NameId::SIZE == size_of::<u64>());
+        assert!(align_of::<NameId>() == align_of::<u64>());
+    } else {
+        // with `fixed_size`, size is the same whether or not there's a label
+        assert!(NameId::SIZE == size_of::<u64>() + size_of::<&'static str>());
+        if labeled {
+            let align = if align_of::<u64>() > align_of::<&'static str>() {
+                align_of::<u64>()
+            } else {
+                align_of::<&'static str>()
+            };
+            assert!(align_of::<NameId>() == align);
+        } else {
+            assert!(align_of::<NameId>() == align_of::<u64>());
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    assert!(NameId::SIZE == if NameId::IS_TRANSPARENT_U64 { 8 } else { 24 });
+};
+
 impl NameId {
+    /// Size of `NameId` in bytes.
+    ///
+    /// Layout depends on whether `NameId` stores a debug label (`debug_name`
+    /// feature in debug builds) and on `fixed_size` feature:
+    ///
+    /// | Debug label | `fixed_size` | Layout                                              | Size (64-bit) |
+    /// | :---------: | :----------: | :-------------------------------------------------- | :-----------: |
+    /// |     no      |      no      | `repr(transparent)` `u64`                           |       8       |
+    /// |     no      |     yes      | `repr(C)` `u64`, `[u8; size_of::<&'static str>()]` |      24       |
+    /// |     yes     |    either    | `repr(C)` `u64`, `&'static str`                     |      24       |
+    ///
+    /// Padding bytes are always zero. Layouts are asserted at compile time, so
+    /// they can't change without a compile error in this crate.
     pub const SIZE: usize = core::mem::size_of::<Self>();
 
+    /// `true` if `NameId` has the same layout and ABI as `u64`, i.e. it
+    /// doesn't store a debug label and `fixed_size` feature is disabled.
+    ///
+    /// Useful for asserting layout assumptions in dependent crates, e.g. with
+    /// `const _: () = assert!(NameId::IS_TRANSPARENT_U64);`, or for choosing
+    /// between code paths:
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let ids = [NameId::from_value(1), NameId::from_value(2)];
+    /// let values: Vec<u64> = if NameId::IS_TRANSPARENT_U64 {
+    ///     // SAFETY: NameId has the same layout as u64.
+    ///     unsafe { core::slice::from_raw_parts(ids.as_ptr().cast::<u64>(), ids.len()) }.to_vec()
+    /// } else {
+    ///     ids.iter().map(NameId::value).collect()
+    /// };
+    /// assert_eq!(values, [1, 2]);
+    /// ```
+    pub const IS_TRANSPARENT_U64: bool =
+        cfg!(not(any(all(debug_assertions, feature = "debug_name"), feature = "fixed_size")));
+
     /// Creates a new `NameId` using one of supported input data types. This
     /// constructor can be extended by implementing [`Into<NameId>`] for
     /// external types.
@@ -67,36 +431,208 @@ impl NameId {
     /// 
     /// [`from_raw`]: NameId::from_raw
     #[inline(always)]
+    #[track_caller]
     pub fn new<T: Into<Self>>(name: T) -> Self {
         name.into()
     }
 
-    /// Constructs a `NameId` from hash `value`.
-    #[cfg(not(feature = "debug_name"))]
-    pub const fn from_raw(value: u64) -> Self {
+    /// Creates a `NameId` from a borrowed `name` of any lifetime, such as a
+    /// slice of a parse buffer.
+    ///
+    /// The result is equal to `NameId::new(name)`, but `name` isn't copied
+    /// to be stored as a debug label, so the id is displayed with its
+    /// registered name (if any) or its hash value. `From<&'a str>` can't be
+    /// implemented next to `From<&'static str>`, so this is a function.
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// let buffer = String::from("spawn player_spawn");
+    /// let id = NameId::from_str_ref(&buffer[6..]);
+    /// assert_eq!(id, NameId::new("player_spawn"));
+    /// ```
+    ///
+    /// With `detect_collisions` (or `detect_collisions_static`) feature, the
+    /// name is registered so collisions with it are detected. Only a name
+    /// that isn't registered yet is copied (and leaked) for that, which
+    /// requires `alloc` feature. Without collision detection, nothing is
+    /// registered and nothing is allocated.
+    #[track_caller]
+    pub fn from_str_ref(name: &str) -> Self {
+        let value = hash_str(name);
+        #[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+        match registry::resolve(value) {
+            Some(registered) if registered == name => {}
+            #[cfg(feature = "alloc")]
+            _ => registry::register_value(value, leak_name(name.into())),
+            #[cfg(not(feature = "alloc"))]
+            _ => {}
+        }
+        Self::from_value(value)
+    }
+
+    /// Creates a `NameId` from `name` hashed in the hash space of `key`.
+    ///
+    /// Ids of the same name with different keys are unrelated, so ids from
+    /// different sources (e.g. built-in content and user mods) can be kept
+    /// apart by using a different key for each, and names from one source
+    /// can't be chosen to collide with ids of another without knowing its key.
+    ///
+    /// The hasher is fed `key` with `write_u64` before `name` is hashed the
+    /// same way [`new`](NameId::new) hashes it. The result never matches
+    /// unkeyed ids of `name`, nor ids of `name` with other keys (barring hash
+    /// collisions). Use `id!(key = ...; name)` for compile-time construction:
+    ///
+    /// ```
+    /// # use name_id::{id, NameId};
+    /// const ENGINE: u64 = 0x454e_4749_4e45;
+    /// let builtin = NameId::new_keyed(ENGINE, "item.sword");
+    /// assert_eq!(builtin, id!(key = 0x454e_4749_4e45; "item.sword"));
+    /// assert_ne!(builtin, NameId::new_keyed(1, "item.sword"));
+    /// assert_ne!(builtin, NameId::new("item.sword"));
+    /// ```
+    ///
+    /// The name isn't stored as a debug label nor recorded by the registry.
+    pub fn new_keyed(key: u64, name: &str) -> Self {
+        let mut hasher = Hasher::default();
+        hasher.write_u64(key);
+        name.hash(&mut hasher);
+        Self::from_value(hasher.finish())
+    }
+
+    /// Creates a `NameId` from the hash of `bytes` content, such as contents
+    /// of a file.
+    ///
+    /// Produces the same value as [`content_id!`](content_id) for a file
+    /// containing `bytes`, so ids of assets embedded at compile time can be
+    /// compared to ids of assets loaded at runtime:
+    ///
+    /// ```
+    /// # use name_id::{content_id, NameId};
+    /// const README: NameId = content_id!("README.md");
+    /// assert_eq!(README, NameId::from_bytes_content(include_bytes!("../README.md")));
+    /// ```
+    ///
+    /// Content ids don't share the hash space of names: the result isn't
+    /// equal to the id of `bytes` interpreted as a name. Nothing is stored as
+    /// a debug label nor recorded by the registry.
+    pub fn from_bytes_content(bytes: &[u8]) -> Self {
+        let mut hasher = Hasher::default();
+        bytes.hash(&mut hasher);
+        Self::from_value(hasher.finish())
+    }
+
+    /// Constructs a `NameId` from its fields.
+    ///
+    /// All constructors go through this function, so `fixed_size` padding is
+    /// always zeroed.
+    #[inline(always)]
+    #[cfg_attr(not(all(debug_assertions, feature = "debug_name")), allow(unused_variables))]
+    const fn from_value_and_label(value: u64, label: &'static str) -> Self {
         Self {
             value,
-            #[cfg(feature = "fixed_size")]
+            #[cfg(all(debug_assertions, feature = "debug_name"))]
+            name: label,
+            #[cfg(all(feature = "fixed_size", not(all(debug_assertions, feature = "debug_name"))))]
             _padding: [0; core::mem::size_of::<&'static str>()],
         }
     }
 
+    /// Constructs a `NameId` from hash `value`.
+    #[cfg(not(feature = "debug_name"))]
+    #[inline(always)]
+    pub const fn from_raw(value: u64) -> Self {
+        Self::from_value_and_label(value, "")
+    }
+
     /// Constructs a `NameId` from hash `value` and a debug `label`.
+    ///
+    /// The `label` is only stored in debug builds.
     #[cfg(feature = "debug_name")]
+    #[inline(always)]
     pub const fn from_raw(value: u64, label: &'static str) -> Self {
-        #[cfg(debug_assertions)]
-        {Self { value, name: label }}
-        #[cfg(all(not(debug_assertions), feature = "fixed_size"))]
-        {Self { value, _padding: [0; core::mem::size_of::<&'static str>()] }}
-        #[cfg(not(any(debug_assertions, feature = "fixed_size")))]
-        {Self { value }}
+        Self::from_value_and_label(value, label)
+    }
+
+    /// Constructs a `NameId` from hash `value` without a debug label.
+    ///
+    /// Unlike [`from_raw`](NameId::from_raw), signature of this function
+    /// doesn't depend on `debug_name` feature. Ids constructed this way are
+    /// displayed using their registered name (if any) or their hash value.
+    #[inline(always)]
+    pub const fn from_value(value: u64) -> Self {
+        Self::from_value_and_label(value, "")
     }
 
     /// Returns the raw hash value.
+    #[inline(always)]
     pub const fn value(&self) -> u64 {
         self.value
     }
 
+    /// Returns the raw hash value bit-cast to `i64`, for storage in systems
+    /// that only support signed 64-bit integers (JVM, Lua, most databases).
+    ///
+    /// This isn't a numeric conversion: hash values with the top bit set
+    /// become negative, and [`from_raw_i64`](NameId::from_raw_i64) restores
+    /// them losslessly.
+    ///
+    /// ```
+    /// # use name_id::NameId;
+    /// // e.g. passed as a `jlong` through JNI, or bound to a BIGINT column
+    /// let id = NameId::from_value(u64::MAX - 1);
+    /// let stored: i64 = id.value_i64();
+    /// assert_eq!(stored, -2);
+    /// assert_eq!(NameId::from_raw_i64(stored), id);
+    /// ```
+    pub const fn value_i64(&self) -> i64 {
+        self.value as i64
+    }
+
+    /// Constructs a `NameId` from a hash value bit-cast to `i64` by
+    /// [`value_i64`](NameId::value_i64), without a debug label.
+    pub const fn from_raw_i64(value: i64) -> Self {
+        Self::from_value(value as u64)
+    }
+
+    /// Returns the name this `NameId` was constructed from, as recorded by the
+    /// global registry.
+    ///
+    /// Only ids constructed from names at runtime (or explicitly passed to
+    /// [`register`]) are recorded, so this returns `None` for ids created via
+    /// [`id!`][id] macro or [`from_raw`](NameId::from_raw). Embedded debug
+    /// label isn't consulted.
+    ///
+    /// Each call acquires the registry lock and performs a `O(log n)` lookup.
+    #[cfg(any(feature = "registry", feature = "registry_static"))]
+    pub fn resolve(&self) -> Option<&'static str> {
+        registry::resolve(self.value)
+    }
+
+    /// Returns the embedded debug label if there is one, or the registered
+    /// name otherwise.
+    pub(crate) fn label(&self) -> Option<&'static str> {
+        #[cfg(all(debug_assertions, feature = "debug_name"))]
+        if !self.name.is_empty() {
+            return Some(self.name);
+        }
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        return self.resolve();
+        #[cfg(not(any(feature = "registry", feature = "registry_static")))]
+        None
+    }
+
+    /// Calls `f` with the name shown by [`Display`](core::fmt::Display): the
+    /// label, or the name in the global [`NameTable`] otherwise.
+    pub(crate) fn with_display_name<R>(&self, f: impl FnOnce(Option<&str>) -> R) -> R {
+        if let Some(name) = self.label() {
+            return f(Some(name));
+        }
+        #[cfg(feature = "alloc")]
+        return name_table::with_global(|table| f(table.and_then(|table| table.resolve(*self))));
+        #[cfg(not(feature = "alloc"))]
+        f(None)
+    }
+
     /// Checks whether two `NameId`s are equal.
     #[inline(always)]
     pub const fn const_eq(&self, other: &Self) -> bool {
@@ -104,7 +640,7 @@ impl NameId {
     }
 
     /// Same as [`const_eq`][NameId::const_eq], but accepts a hash/id value directly.
-    #[inline]
+    #[inline(always)]
     pub const fn const_eq_value(&self, other: u64) -> bool {
         self.value == other
     }
@@ -116,6 +652,7 @@ impl NameId {
     }
     
     /// Same as [`const_cmp`][NameId::const_cmp], but accepts a hash/id value directly.
+    #[inline(always)]
     pub const fn const_cmp_value(&self, other: u64) -> core::cmp::Ordering {
         if self.value > other {
             core::cmp::Ordering::Greater
@@ -129,8 +666,9 @@ impl NameId {
 
 /// Use [`const_eq`][NameId::const_eq] to perform equality checks in const
 /// contexts.
+#[cfg(not(all(feature = "adt_const_params", not(all(debug_assertions, feature = "debug_name")))))]
 impl PartialEq for NameId {
-    #[inline]
+    #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.const_eq(other)
     }
@@ -138,21 +676,55 @@ impl PartialEq for NameId {
 
 /// Use [`const_eq`][NameId::const_eq] to perform equality checks in const
 /// contexts.
+#[cfg(not(all(feature = "adt_const_params", not(all(debug_assertions, feature = "debug_name")))))]
 impl Eq for NameId {}
-impl<S: AsRef<str>> PartialEq<S> for NameId {
-    /// Compares hash of `other` to the hash stored by this `NameId`.
-    fn eq(&self, other: &S) -> bool {
-        let mut hasher = Hasher::default();
-        other.as_ref().hash(&mut hasher);
-        let value = hasher.finish();
-        self.value.eq(&value)
-    }
+
+/// Implements comparison with string types by hashing them.
+///
+/// Implemented for concrete types rather than all `AsRef<str>` types, so that
+/// comparisons with types that hash differently (e.g. `&CStr`) can be
+/// implemented as well. Other string types can be compared through their
+/// `&str` (e.g. `id == name.as_ref()`).
+macro_rules! impl_eq_str {
+    ($a: lifetime, $T: ty) => {
+        impl<$a> PartialEq<$T> for NameId {
+            /// Compares hash of `other` to the hash stored by this `NameId`.
+            fn eq(&self, other: &$T) -> bool {
+                let other: &str = other.as_ref();
+                self.value == hash_str(other)
+            }
+        }
+    };
+    ($T: ty) => {
+        impl PartialEq<$T> for NameId {
+            /// Compares hash of `other` to the hash stored by this `NameId`.
+            fn eq(&self, other: &$T) -> bool {
+                let other: &str = other.as_ref();
+                self.value == hash_str(other)
+            }
+        }
+    };
 }
+impl_eq_str!(str);
+impl_eq_str!('a, &'a str);
+impl_eq_str!('a, &'a &'a str);
+#[cfg(feature = "alloc")]
+impl_eq_str!(alloc::string::String);
+#[cfg(feature = "alloc")]
+impl_eq_str!('a, &'a alloc::string::String);
+#[cfg(feature = "alloc")]
+impl_eq_str!('a, alloc::borrow::Cow<'a, str>);
+#[cfg(feature = "alloc")]
+impl_eq_str!(alloc::boxed::Box<str>);
+#[cfg(feature = "alloc")]
+impl_eq_str!(alloc::rc::Rc<str>);
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl_eq_str!(alloc::sync::Arc<str>);
 
 /// Use [`const_cmp`][NameId::const_cmp] to perform comparison in const
 /// contexts.
 impl PartialOrd for NameId {
-    #[inline]
+    #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
@@ -161,12 +733,13 @@ impl PartialOrd for NameId {
 /// Use [`const_cmp`][NameId::const_cmp] to perform comparison in const
 /// contexts.
 impl Ord for NameId {
-    #[inline]
+    #[inline(always)]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.const_cmp(other)
     }
 }
 impl core::hash::Hash for NameId {
+    #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         state.write_u64(self.value)
     }
@@ -176,6 +749,7 @@ macro_rules! specialize_signature {
     ((&'static str) => |$name: ident| $it: block) => {
         #[allow(unreachable_code)]
         impl From<&'static str> for NameId {
+            #[track_caller]
             fn from($name: &'static str) -> Self {
                 $it
             }
@@ -184,6 +758,7 @@ macro_rules! specialize_signature {
     ((&'a $($T: tt)*) => |$name: ident| $it: block) => {
         #[allow(unreachable_code)]
         impl <'a> From<&'a $($T)*> for NameId {
+            #[track_caller]
             fn from($name: &'a $($T)*) -> Self {
                 $it
             }
@@ -192,6 +767,7 @@ macro_rules! specialize_signature {
     ((alloc::borrow::Cow<'a, str>) => |$name: ident| $it: block) => {
         #[allow(unreachable_code)]
         impl <'a> From<alloc::borrow::Cow<'a, str>> for NameId {
+            #[track_caller]
             fn from($name: alloc::borrow::Cow<'a, str>) -> Self {
                 $it
             }
@@ -200,45 +776,90 @@ macro_rules! specialize_signature {
     (($($T: tt)*) => |$name: ident| $it: block) => {
         #[allow(unreachable_code)]
         impl From<$($T)*> for NameId {
+            #[track_caller]
             fn from($name: $($T)*) -> Self {
                 $it
             }
         }
     };
 }
+/// Leaks `name` to make it `'static`, keeping track of leaked bytes when
+/// registry is enabled.
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "registry", feature = "registry_static", feature = "debug_name")
+))]
+fn leak_name(name: alloc::string::String) -> &'static str {
+    #[cfg(any(feature = "registry", feature = "registry_static"))]
+    registry::stats::count_leaked(name.len());
+    name.leak()
+}
+
+/// Constructs a `NameId` from a borrowed `name`, reusing the registered copy
+/// of it as label and registry entry.
+///
+/// Only the first conversion of each name leaks it, so this is used by
+/// conversions from string types that are commonly converted repeatedly
+/// (e.g. `SmolStr`). Without a registry there's nowhere to find an earlier
+/// copy, so no label is stored at all. Without `alloc`, names that aren't
+/// registered yet can't be copied, so they aren't labeled nor registered.
+#[track_caller]
+pub(crate) fn from_borrowed_name(name: &str) -> NameId {
+    let value = hash_str(name);
+    #[cfg(any(feature = "registry", feature = "registry_static"))]
+    {
+        let registered = match registry::resolve(value) {
+            Some(registered) if registered == name => Some(registered),
+            #[cfg(feature = "alloc")]
+            _ => Some(leak_name(name.into())),
+            #[cfg(not(feature = "alloc"))]
+            _ => None,
+        };
+        if let Some(name) = registered {
+            registry::register_value(value, name);
+            return NameId::from_value_and_label(value, name);
+        }
+    }
+    NameId::from_value(value)
+}
+
+/// Converts `name` into a `'static` string used as a label and registry entry.
+///
+/// All arms other than `&'static str` allocate (and leak), so they may only be
+/// expanded when `alloc` feature is enabled.
+#[cfg(any(feature = "registry", feature = "registry_static", feature = "debug_name"))]
 macro_rules! specialize_to_debug_name {
     ($name: ident : &'static str) => {$name};
-    ($name: ident : &'a alloc::string::String) => {$name.clone().leak()};
-    ($name: ident : alloc::string::String) => {$name.clone().leak()};
-    ($name: ident : &'a alloc::borrow::Cow<'a, str>) => {alloc::string::ToString::to_string($name).leak()};
-    ($name: ident : alloc::borrow::Cow<'a, str>) => {alloc::string::ToString::to_string(&$name).leak()};
-    ($name: ident : &'a core::ffi::CStr) => {alloc::string::ToString::to_string(&$name.to_string_lossy()).leak()};
-    ($name: ident : &'a alloc::ffi::CString) => {alloc::string::ToString::to_string(&$name.to_string_lossy()).leak()};
-    ($name: ident : alloc::ffi::CString) => {alloc::string::ToString::to_string(&$name.to_string_lossy()).leak()};
-    ($name: ident : &'a [u8]) => {alloc::string::ToString::to_string(&alloc::string::String::from_utf8_lossy($name)).leak()};
-    ($name: ident : &'a alloc::vec::Vec<u8>) => {alloc::string::ToString::to_string(&alloc::string::String::from_utf8_lossy($name)).leak()};
-    ($name: ident : alloc::vec::Vec<u8>) => {alloc::string::ToString::to_string(&alloc::string::String::from_utf8_lossy(&$name)).leak()};
+    ($name: ident : &'a alloc::string::String) => {leak_name($name.clone())};
+    ($name: ident : alloc::string::String) => {leak_name($name)};
+    ($name: ident : &'a alloc::borrow::Cow<'a, str>) => {leak_name(alloc::string::ToString::to_string($name))};
+    ($name: ident : alloc::borrow::Cow<'a, str>) => {leak_name($name.into_owned())};
+    ($name: ident : &'a core::ffi::CStr) => {leak_name($name.to_string_lossy().into_owned())};
+    ($name: ident : &'a alloc::ffi::CString) => {leak_name($name.to_string_lossy().into_owned())};
+    ($name: ident : alloc::ffi::CString) => {leak_name($name.to_string_lossy().into_owned())};
+    ($name: ident : &'a [u8]) => {leak_name(alloc::string::String::from_utf8_lossy($name).into_owned())};
+    ($name: ident : &'a alloc::vec::Vec<u8>) => {leak_name(alloc::string::String::from_utf8_lossy($name).into_owned())};
+    ($name: ident : alloc::vec::Vec<u8>) => {leak_name(alloc::string::String::from_utf8_lossy(&$name).into_owned())};
     ($name: ident : $($T: tt)*) => {$name};
 }
 macro_rules! impl_from {
+    (@unregistered $($T: tt)*) => {
+        specialize_signature!(($($T)*) => |name| {
+            let mut hasher = Hasher::default();
+            name.hash(&mut hasher);
+            let value = hasher.finish();
+            NameId::from_value(value)
+        });
+    };
     ($($T: tt)*) => {
         specialize_signature!(($($T)*) => |name| {
             let mut hasher = Hasher::default();
             name.hash(&mut hasher);
             let value = hasher.finish();
-            #[cfg(feature = "detect_collisions")]
+            #[cfg(any(feature = "registry", feature = "registry_static"))]
             {
                 let name = specialize_to_debug_name!(name: $($T)*);
-                if let Some(previous) = lookup().get(&value) {
-                    assert_eq!(
-                        *previous,
-                        name,
-                        "hash id collision: {} collides with {}",
-                        previous,
-                        name,
-                    );
-                }
-                lookup().insert(value, name);
+                registry::register_value(value, name);
                 #[cfg(feature = "debug_name")]
                 return Self::from_raw(value, name);
             }
@@ -260,26 +881,126 @@ impl_from!(&'a alloc::borrow::Cow<'a, str>);
 #[cfg(feature = "alloc")]
 impl_from!(alloc::borrow::Cow<'a, str>);
 
-#[cfg(any(not(all(debug_assertions, feature = "debug_name")), all(debug_assertions, feature = "debug_name", feature = "alloc")))]
+#[cfg(feature = "alloc")]
 impl_from!(&'a core::ffi::CStr);
+// names can't be stored without allocation, and labels are unused in release
+#[cfg(all(not(feature = "alloc"), not(all(debug_assertions, feature = "debug_name"))))]
+impl_from!(@unregistered &'a core::ffi::CStr);
 #[cfg(feature = "alloc")]
 impl_from!(&'a alloc::ffi::CString);
 #[cfg(feature = "alloc")]
 impl_from!(alloc::ffi::CString);
 
-#[cfg(any(not(all(debug_assertions, feature = "debug_name")), all(debug_assertions, feature = "debug_name", feature = "alloc")))]
+#[cfg(feature = "alloc")]
 impl_from!(&'a [u8]);
+#[cfg(all(not(feature = "alloc"), not(all(debug_assertions, feature = "debug_name"))))]
+impl_from!(@unregistered &'a [u8]);
 #[cfg(feature = "alloc")]
 impl_from!(&'a alloc::vec::Vec<u8>);
 #[cfg(feature = "alloc")]
 impl_from!(alloc::vec::Vec<u8>);
 
+/// Hashes `name` the same way [`From<&CStr>`](NameId#impl-From<%26CStr>-for-NameId)
+/// does, without registering or leaking it.
+fn hash_c_str(name: &core::ffi::CStr) -> u64 {
+    let mut hasher = Hasher::default();
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Compares the id to the id `From<&CStr>` would construct from `other`,
+/// without registering, allocating or leaking a label.
+///
+/// Like `From<&CStr>`, this hashes bytes of the C string (including the nul
+/// terminator) as they are, so non-UTF-8 strings are compared exactly, and
+/// ids of C strings are never equal to ids of `&str`s with the same content.
+impl PartialEq<&core::ffi::CStr> for NameId {
+    fn eq(&self, other: &&core::ffi::CStr) -> bool {
+        self.value == hash_c_str(other)
+    }
+}
+
+/// Same as `PartialEq<&CStr>`.
+impl PartialEq<NameId> for &core::ffi::CStr {
+    fn eq(&self, other: &NameId) -> bool {
+        other == self
+    }
+}
+
+/// Same as `PartialEq<&CStr>`.
+#[cfg(feature = "alloc")]
+impl PartialEq<alloc::ffi::CString> for NameId {
+    fn eq(&self, other: &alloc::ffi::CString) -> bool {
+        self.value == hash_c_str(other)
+    }
+}
+
+/// Same as `PartialEq<&CStr>`.
+#[cfg(feature = "alloc")]
+impl PartialEq<NameId> for alloc::ffi::CString {
+    fn eq(&self, other: &NameId) -> bool {
+        other == self
+    }
+}
+
+/// Compares the id to the id of `other` interpreted as a `str`, so
+/// `id == bytes` is the same as `id == str::from_utf8(bytes).unwrap()`.
+///
+/// Bytes that aren't valid UTF-8 are never equal to any id. They aren't
+/// decoded lossily, as that could make different inputs compare equal.
+///
+/// Note that this differs from `From<&[u8]>`, which hashes the slice as is
+/// and produces ids that aren't equal to ids of the same `str`.
+impl PartialEq<[u8]> for NameId {
+    fn eq(&self, other: &[u8]) -> bool {
+        match core::str::from_utf8(other) {
+            Ok(other) => self.value == hash_str(other),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Same as `PartialEq<[u8]>`.
+impl PartialEq<&[u8]> for NameId {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self == *other
+    }
+}
+
+/// Same as `PartialEq<[u8]>`.
+impl PartialEq<NameId> for [u8] {
+    fn eq(&self, other: &NameId) -> bool {
+        other == self
+    }
+}
+
+/// Same as `PartialEq<[u8]>`.
+impl PartialEq<NameId> for &[u8] {
+    fn eq(&self, other: &NameId) -> bool {
+        other == *self
+    }
+}
+
 impl From<NameId> for u64 {
     fn from(id: NameId) -> Self {
         id.value
     }
 }
 
+/// Bit-casts the hash value, same as [`NameId::value_i64`].
+impl From<NameId> for i64 {
+    fn from(id: NameId) -> Self {
+        id.value_i64()
+    }
+}
+
+/// Bit-casts `value` into a hash value, same as [`NameId::from_raw_i64`].
+impl From<i64> for NameId {
+    fn from(value: i64) -> Self {
+        Self::from_raw_i64(value)
+    }
+}
+
 /// As `NameId` is constant for given input ID, which is only affected by
 /// hashing function that's selected via compile features. It is safe to send a
 /// it across different threads.
@@ -289,28 +1010,38 @@ unsafe impl Send for NameId {}
 /// threads.
 unsafe impl Sync for NameId {}
 
+/// Displays the debug label of `NameId` when available. With `registry`
+/// feature, name recorded by the registry is used for ids that don't carry a
+/// label.
 impl core::fmt::Display for NameId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        #[cfg(all(debug_assertions, feature = "debug_name"))]
-        {
-            write!(f, "#{{{}}}", self.name)
-        }
-        #[cfg(not(all(debug_assertions, feature = "debug_name")))]
-        {
-            write!(f, "NameId({})", self.value)
-        }
+        self.with_display_name(|name| match name {
+            Some(name) => write!(f, "#{{{}}}", name),
+            None => write!(f, "NameId({})", self.value),
+        })
+    }
+}
+
+/// Formats the hash value, same as `u64`.
+impl core::fmt::LowerHex for NameId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(&self.value, f)
+    }
+}
+
+/// Formats the hash value, same as `u64`.
+impl core::fmt::UpperHex for NameId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::UpperHex::fmt(&self.value, f)
     }
 }
 
 impl core::fmt::Debug for NameId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         #[cfg(all(debug_assertions, feature = "debug_name"))]
-        {
-            write!(f, "NameId({})", self.name)
-        }
-        #[cfg(not(all(debug_assertions, feature = "debug_name")))]
-        {
-            write!(f, "NameId({})", self.value)
+        if !self.name.is_empty() {
+            return write!(f, "NameId({})", self.name);
         }
+        write!(f, "NameId({})", self.value)
     }
 }
@@ -0,0 +1,312 @@
+//! Bit sets over a fixed universe of known [`NameId`]s.
+
+use crate::{NameId, NameIdTable};
+
+/// Error returned when an id isn't part of the universe of an [`IdMask`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotInUniverse {
+    /// Id that was looked up.
+    pub id: NameId,
+}
+
+impl core::fmt::Display for NotInUniverse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} isn't part of the mask universe", self.id)
+    }
+}
+
+impl core::error::Error for NotInUniverse {}
+
+/// Ordered set of ids that [`IdMask`] bits refer to.
+///
+/// Implemented for slices and arrays of ids sorted by hash value without
+/// duplicates, and for [`NameIdTable`], whose entries are always sorted.
+/// Results are unspecified for unsorted slices.
+pub trait MaskUniverse {
+    /// Returns the number of ids in the universe.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the universe is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bit index of `id`, or `None` if it's not in the universe.
+    fn index_of(&self, id: NameId) -> Option<usize>;
+
+    /// Returns the id at bit `index`.
+    fn id_at(&self, index: usize) -> Option<NameId>;
+}
+
+impl MaskUniverse for [NameId] {
+    fn len(&self) -> usize {
+        <[NameId]>::len(self)
+    }
+
+    fn index_of(&self, id: NameId) -> Option<usize> {
+        self.binary_search_by_key(&id.value(), NameId::value).ok()
+    }
+
+    fn id_at(&self, index: usize) -> Option<NameId> {
+        self.get(index).copied()
+    }
+}
+
+impl<const N: usize> MaskUniverse for [NameId; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn index_of(&self, id: NameId) -> Option<usize> {
+        self.as_slice().index_of(id)
+    }
+
+    fn id_at(&self, index: usize) -> Option<NameId> {
+        self.get(index).copied()
+    }
+}
+
+impl<V, const N: usize> MaskUniverse for NameIdTable<V, N> {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn index_of(&self, id: NameId) -> Option<usize> {
+        self.entries()
+            .binary_search_by_key(&id.value(), |(id, _)| id.value())
+            .ok()
+    }
+
+    fn id_at(&self, index: usize) -> Option<NameId> {
+        self.entries().get(index).map(|(id, _)| *id)
+    }
+}
+
+/// Set of ids from a fixed universe, stored as `WORDS` 64-bit words with one
+/// bit per id.
+///
+/// Masks don't store their universe, it's passed to methods that translate
+/// ids into bits instead. Set operations between masks (e.g.
+/// [`intersects`](IdMask::intersects)) only compare words, so they're only
+/// meaningful for masks built over the same universe.
+///
+/// ```
+/// # use name_id::{id, IdMask, NameId, NameIdTable};
+/// static EVENTS: NameIdTable<(), 3> = NameIdTable::new([
+///     (id!(spawn), ()),
+///     (id!(despawn), ()),
+///     (id!(damage), ()),
+/// ]);
+///
+/// let handled = IdMask::<1>::from_ids(&EVENTS, [id!(spawn), id!(damage)]).unwrap();
+/// let mut fired = IdMask::<1>::new();
+/// fired.set(&EVENTS, id!(damage)).unwrap();
+/// assert!(fired.is_subset(&handled));
+/// assert!(fired.set(&EVENTS, id!(unknown)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdMask<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> IdMask<WORDS> {
+    /// Number of ids the mask can hold bits for.
+    pub const BITS: usize = WORDS * 64;
+
+    /// Creates an empty mask.
+    pub const fn new() -> Self {
+        IdMask { words: [0; WORDS] }
+    }
+
+    /// Creates a mask from its words.
+    pub const fn from_words(words: [u64; WORDS]) -> Self {
+        IdMask { words }
+    }
+
+    /// Creates a mask with bits of all `ids` set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `universe` has more than [`BITS`](IdMask::BITS) ids.
+    pub fn from_ids<U, I>(universe: &U, ids: I) -> Result<Self, NotInUniverse>
+    where
+        U: MaskUniverse + ?Sized,
+        I: IntoIterator<Item = NameId>,
+    {
+        let mut mask = Self::new();
+        for id in ids {
+            mask.set(universe, id)?;
+        }
+        Ok(mask)
+    }
+
+    fn bit<U: MaskUniverse + ?Sized>(universe: &U, id: NameId) -> Option<usize> {
+        assert!(
+            universe.len() <= Self::BITS,
+            "universe of {} ids doesn't fit into IdMask of {} bits",
+            universe.len(),
+            Self::BITS
+        );
+        universe.index_of(id)
+    }
+
+    /// Sets the bit of `id`, returning `true` if it wasn't set before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `universe` has more than [`BITS`](IdMask::BITS) ids.
+    pub fn set<U: MaskUniverse + ?Sized>(
+        &mut self,
+        universe: &U,
+        id: NameId,
+    ) -> Result<bool, NotInUniverse> {
+        let bit = Self::bit(universe, id).ok_or(NotInUniverse { id })?;
+        let mask = 1 << (bit % 64);
+        let word = &mut self.words[bit / 64];
+        let added = *word & mask == 0;
+        *word |= mask;
+        Ok(added)
+    }
+
+    /// Clears the bit of `id`, returning `true` if it was set before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `universe` has more than [`BITS`](IdMask::BITS) ids.
+    pub fn unset<U: MaskUniverse + ?Sized>(
+        &mut self,
+        universe: &U,
+        id: NameId,
+    ) -> Result<bool, NotInUniverse> {
+        let bit = Self::bit(universe, id).ok_or(NotInUniverse { id })?;
+        let mask = 1 << (bit % 64);
+        let word = &mut self.words[bit / 64];
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        Ok(removed)
+    }
+
+    /// Returns `true` if the bit of `id` is set. Ids outside of `universe`
+    /// are never contained.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `universe` has more than [`BITS`](IdMask::BITS) ids.
+    pub fn contains<U: MaskUniverse + ?Sized>(&self, universe: &U, id: NameId) -> bool {
+        match Self::bit(universe, id) {
+            Some(bit) => self.words[bit / 64] & (1 << (bit % 64)) != 0,
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over ids of set bits, in universe order.
+    pub fn iter<'a, U: MaskUniverse + ?Sized>(
+        &'a self,
+        universe: &'a U,
+    ) -> impl Iterator<Item = NameId> + 'a {
+        (0..Self::BITS)
+            .filter(|bit| self.words[bit / 64] & (1 << (bit % 64)) != 0)
+            .filter_map(|bit| universe.id_at(bit))
+    }
+
+    /// Returns `true` if both masks have a common id.
+    pub const fn intersects(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] & other.words[i] != 0 {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns `true` if all ids of this mask are also in `other`.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] & !other.words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if all ids of `other` are also in this mask.
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns a mask of ids in either mask.
+    pub const fn union(&self, other: &Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] |= other.words[i];
+            i += 1;
+        }
+        IdMask { words }
+    }
+
+    /// Returns a mask of ids in both masks.
+    pub const fn intersection(&self, other: &Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] &= other.words[i];
+            i += 1;
+        }
+        IdMask { words }
+    }
+
+    /// Returns a mask of ids in this mask but not in `other`.
+    pub const fn difference(&self, other: &Self) -> Self {
+        let mut words = self.words;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] &= !other.words[i];
+            i += 1;
+        }
+        IdMask { words }
+    }
+
+    /// Returns the number of set ids.
+    pub const fn len(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < WORDS {
+            count += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns `true` if no ids are set.
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Clears all ids.
+    pub const fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Returns words of the mask.
+    pub const fn words(&self) -> &[u64; WORDS] {
+        &self.words
+    }
+}
+
+impl<const WORDS: usize> Default for IdMask<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,107 @@
+//! Ids together with the result of resolving their names.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::NameId;
+
+/// A [`NameId`] together with its name, if the name could be found.
+///
+/// Resolving a name looks up the registry unless the id carries a debug
+/// label, so ids received from outside (e.g. over the network) can be
+/// resolved once with [`MaybeNamed::resolve`] and then displayed or logged
+/// any number of times without further lookups.
+///
+/// Comparison and hashing only consider the id, so a `Named` value is equal
+/// to an `Unnamed` one with the same id, and to the `NameId` itself.
+///
+/// ```
+/// # use name_id::{MaybeNamed, NameId};
+/// let unknown = MaybeNamed::resolve(NameId::from_value(0x2a));
+/// assert_eq!(unknown.name(), None);
+/// assert_eq!(unknown.to_string(), "0x000000000000002a");
+/// assert_eq!(unknown, NameId::from_value(0x2a));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum MaybeNamed {
+    /// An id with a known name.
+    Named(NameId, &'static str),
+    /// An id whose name isn't known.
+    Unnamed(NameId),
+}
+
+impl MaybeNamed {
+    /// Looks up the name of `id`: its debug label, or its registered name if
+    /// it doesn't have one.
+    pub fn resolve(id: NameId) -> Self {
+        match id.label() {
+            Some(name) => MaybeNamed::Named(id, name),
+            None => MaybeNamed::Unnamed(id),
+        }
+    }
+
+    /// Returns the id.
+    pub const fn id(&self) -> NameId {
+        match self {
+            MaybeNamed::Named(id, _) | MaybeNamed::Unnamed(id) => *id,
+        }
+    }
+
+    /// Returns the name, if it's known.
+    pub const fn name(&self) -> Option<&'static str> {
+        match self {
+            MaybeNamed::Named(_, name) => Some(name),
+            MaybeNamed::Unnamed(_) => None,
+        }
+    }
+}
+
+/// Same as [`MaybeNamed::resolve`].
+impl From<NameId> for MaybeNamed {
+    fn from(id: NameId) -> Self {
+        MaybeNamed::resolve(id)
+    }
+}
+
+impl From<MaybeNamed> for NameId {
+    fn from(value: MaybeNamed) -> Self {
+        value.id()
+    }
+}
+
+/// Writes the name, or the hash value as `0x`-prefixed hex if the name isn't
+/// known.
+impl fmt::Display for MaybeNamed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaybeNamed::Named(_, name) => f.write_str(name),
+            MaybeNamed::Unnamed(id) => write!(f, "{:#018x}", id.value()),
+        }
+    }
+}
+
+impl PartialEq for MaybeNamed {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for MaybeNamed {}
+
+impl PartialEq<NameId> for MaybeNamed {
+    fn eq(&self, other: &NameId) -> bool {
+        self.id() == *other
+    }
+}
+
+impl PartialEq<MaybeNamed> for NameId {
+    fn eq(&self, other: &MaybeNamed) -> bool {
+        *self == other.id()
+    }
+}
+
+impl Hash for MaybeNamed {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state)
+    }
+}
@@ -0,0 +1,182 @@
+//! Runtime loaded table of names.
+
+#[cfg(all(
+    any(feature = "std", feature = "critical-section", feature = "spin"),
+    target_has_atomic = "ptr"
+))]
+use alloc::sync::Arc;
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{NameId, RegistrySnapshot, SnapshotError};
+
+/// Currently installed global table.
+#[cfg(all(
+    any(feature = "std", feature = "critical-section", feature = "spin"),
+    target_has_atomic = "ptr"
+))]
+static GLOBAL: crate::sync::Mutex<Option<Arc<NameTable>>> = crate::sync::Mutex::new(None);
+
+/// Names of ids loaded at runtime, e.g. sent by an editor or read from a
+/// file.
+///
+/// Tables are loaded from registry snapshots (same formats as parsed by
+/// [`RegistrySnapshot`]), and own their names.
+/// Installing a table with [`install_global`](NameTable::install_global)
+/// makes `Display` of ids without a debug label or registered name use names
+/// from it.
+///
+/// ```
+/// # use name_id::{NameId, NameTable};
+/// let table = NameTable::load_from_text("00000000000004d2\tplayer_spawn\n").unwrap();
+/// assert_eq!(table.resolve(NameId::from_value(1234)), Some("player_spawn"));
+/// ```
+///
+/// # Format
+///
+/// - Text: one entry per line, consisting of 16 hex digits of the hash, a tab,
+///   and the name. Backslashes, tabs, carriage returns and newlines in names
+///   are escaped as `\\`, `\t`, `\r` and `\n` respectively.
+/// - Binary: a sequence of entries, each consisting of the hash as `u64` LE,
+///   name length in bytes as `u16` LE, and UTF-8 bytes of the name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NameTable {
+    /// Entries sorted by hash value.
+    entries: Vec<(u64, Box<str>)>,
+}
+
+impl NameTable {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        NameTable {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Parses a table from a snapshot in binary format.
+    ///
+    /// If the snapshot contains a hash more than once, the first name is
+    /// kept.
+    pub fn load_from_bytes(data: &[u8]) -> Result<Self, SnapshotError> {
+        RegistrySnapshot::parse(data).map(Self::from_snapshot)
+    }
+
+    /// Parses a table from a snapshot in text format.
+    ///
+    /// If the snapshot contains a hash more than once, the first name is
+    /// kept.
+    pub fn load_from_text(text: &str) -> Result<Self, SnapshotError> {
+        RegistrySnapshot::parse_text(text).map(Self::from_snapshot)
+    }
+
+    fn from_snapshot(snapshot: RegistrySnapshot<'_>) -> Self {
+        NameTable {
+            entries: snapshot
+                .iter()
+                .map(|(value, name)| (value, Box::from(name)))
+                .collect(),
+        }
+    }
+
+    /// Sets the name of `id`, replacing the previous one.
+    pub fn insert(&mut self, id: NameId, name: &str) {
+        match self
+            .entries
+            .binary_search_by_key(&id.value(), |(value, _)| *value)
+        {
+            Ok(i) => self.entries[i].1 = Box::from(name),
+            Err(i) => self.entries.insert(i, (id.value(), Box::from(name))),
+        }
+    }
+
+    /// Adds all entries of `other` to this table.
+    ///
+    /// Names from `other` take precedence, so merging a newer table into an
+    /// older one updates changed names.
+    pub fn merge(&mut self, other: NameTable) {
+        let mut entries = Vec::with_capacity(self.entries.len() + other.entries.len());
+        let mut old = core::mem::take(&mut self.entries).into_iter().peekable();
+        let mut new = other.entries.into_iter().peekable();
+        loop {
+            let next = match (old.peek(), new.peek()) {
+                (Some((a, _)), Some((b, _))) if a < b => old.next(),
+                (Some((a, _)), Some((b, _))) if a == b => {
+                    old.next();
+                    new.next()
+                }
+                (_, Some(_)) => new.next(),
+                (Some(_), None) => old.next(),
+                (None, None) => break,
+            };
+            entries.extend(next);
+        }
+        self.entries = entries;
+    }
+
+    /// Returns the name of `id`, if the table contains it.
+    pub fn resolve(&self, id: NameId) -> Option<&str> {
+        self.entries
+            .binary_search_by_key(&id.value(), |(value, _)| *value)
+            .ok()
+            .map(|i| self.entries[i].1.as_ref())
+    }
+
+    /// Returns number of names in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the table contains no names.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over `(id, name)` entries in ascending order of
+    /// their hash values.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (NameId, &str)> {
+        self.entries
+            .iter()
+            .map(|(value, name)| (NameId::from_value(*value), name.as_ref()))
+    }
+}
+
+#[cfg(all(
+    any(feature = "std", feature = "critical-section", feature = "spin"),
+    target_has_atomic = "ptr"
+))]
+impl NameTable {
+    /// Makes this table the global one, returning the previously installed
+    /// table.
+    ///
+    /// Tables are swapped atomically. The previous table stays alive for as
+    /// long as it's referenced, so ids that are being formatted while the
+    /// swap happens still use it.
+    pub fn install_global(self) -> Option<Arc<NameTable>> {
+        let table = Arc::new(self);
+        GLOBAL.with(|global| global.replace(table))
+    }
+
+    /// Removes the global table, returning it.
+    pub fn uninstall_global() -> Option<Arc<NameTable>> {
+        GLOBAL.with(|global| global.take())
+    }
+
+    /// Returns the currently installed global table.
+    pub fn global() -> Option<Arc<NameTable>> {
+        GLOBAL.with(|global| global.clone())
+    }
+}
+
+/// Calls `f` with the installed global table, or `None` if there's none or
+/// enabled features don't support one.
+pub(crate) fn with_global<R>(f: impl FnOnce(Option<&NameTable>) -> R) -> R {
+    #[cfg(all(
+        any(feature = "std", feature = "critical-section", feature = "spin"),
+        target_has_atomic = "ptr"
+    ))]
+    return f(NameTable::global().as_deref());
+    #[cfg(not(all(
+        any(feature = "std", feature = "critical-section", feature = "spin"),
+        target_has_atomic = "ptr"
+    )))]
+    f(None)
+}
@@ -0,0 +1,89 @@
+//! Construction of [`NameId`]s from file system paths and OS strings.
+//!
+//! Paths are hashed as their UTF-8 form, exactly as written: separators
+//! aren't normalized, so `assets/door.png` and `assets\door.png` produce
+//! different ids, and neither is made relative or canonical. Normalize paths
+//! before converting them if ids should match across platforms.
+//!
+//! OS strings aren't always valid UTF-8. `From` implementations replace
+//! invalid sequences with `U+FFFD REPLACEMENT CHARACTER` (same as
+//! [`OsStr::to_string_lossy`]), so different invalid paths can produce the same
+//! id. [`NameId::try_from_os_str`] and [`NameId::try_from_path`] return a
+//! [`NonUtf8Name`] error instead.
+
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::NameId;
+
+/// Error returned when an OS string or path isn't valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonUtf8Name;
+
+impl core::fmt::Display for NonUtf8Name {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("name isn't valid UTF-8")
+    }
+}
+
+impl core::error::Error for NonUtf8Name {}
+
+impl NameId {
+    /// Constructs a `NameId` from `name` if it's valid UTF-8.
+    ///
+    /// `TryFrom` can't be implemented alongside the lossy `From<&OsStr>`, so
+    /// this is a separate function.
+    #[track_caller]
+    pub fn try_from_os_str(name: &OsStr) -> Result<Self, NonUtf8Name> {
+        name.to_str()
+            .map(|name| NameId::from(Cow::Borrowed(name)))
+            .ok_or(NonUtf8Name)
+    }
+
+    /// Constructs a `NameId` from `path` if it's valid UTF-8.
+    ///
+    /// Same as [`try_from_os_str`](NameId::try_from_os_str) for the path as
+    /// an OS string.
+    #[track_caller]
+    pub fn try_from_path(path: &Path) -> Result<Self, NonUtf8Name> {
+        Self::try_from_os_str(path.as_os_str())
+    }
+}
+
+/// Invalid UTF-8 is replaced with `U+FFFD`, see
+/// [`try_from_os_str`](NameId::try_from_os_str) for a checked conversion.
+impl From<&OsStr> for NameId {
+    #[track_caller]
+    fn from(name: &OsStr) -> Self {
+        NameId::from(name.to_string_lossy())
+    }
+}
+
+/// Invalid UTF-8 is replaced with `U+FFFD`.
+impl From<OsString> for NameId {
+    #[track_caller]
+    fn from(name: OsString) -> Self {
+        match name.into_string() {
+            Ok(name) => NameId::from(name),
+            Err(name) => NameId::from(name.to_string_lossy()),
+        }
+    }
+}
+
+/// Invalid UTF-8 is replaced with `U+FFFD`, see
+/// [`try_from_path`](NameId::try_from_path) for a checked conversion.
+impl From<&Path> for NameId {
+    #[track_caller]
+    fn from(path: &Path) -> Self {
+        NameId::from(path.as_os_str())
+    }
+}
+
+/// Invalid UTF-8 is replaced with `U+FFFD`.
+impl From<PathBuf> for NameId {
+    #[track_caller]
+    fn from(path: PathBuf) -> Self {
+        NameId::from(path.into_os_string())
+    }
+}
@@ -0,0 +1,98 @@
+//! Parsing of [`NameId`]s from text, such as command line arguments.
+
+use core::str::FromStr;
+
+use crate::NameId;
+
+/// Error returned when a string that looks like a hash value isn't a valid
+/// one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIdError {
+    /// `0x` prefix isn't followed by hexadecimal digits.
+    InvalidHex,
+    /// Hash value doesn't fit into 64 bits.
+    Overflow,
+}
+
+impl core::fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseIdError::InvalidHex => f.write_str("expected hexadecimal digits after \"0x\""),
+            ParseIdError::Overflow => f.write_str("id value doesn't fit into 64 bits"),
+        }
+    }
+}
+
+impl core::error::Error for ParseIdError {}
+
+/// Parses an id from a name or a hash value.
+///
+/// Accepts:
+/// - `0x` (or `0X`) prefixed hexadecimal digits, parsed as a hash value,
+/// - decimal digits, parsed as a hash value,
+/// - `NameId(<decimal digits>)`, parsed as a hash value (the
+///   [`Display`](core::fmt::Display) output of ids without a name),
+/// - `#{<name>}`, parsed as `<name>` (the `Display` output of named ids),
+/// - any other string, treated as a name and hashed.
+///
+/// Unlike [`NameId::new`], this means names consisting only of digits can't
+/// be parsed, as `"123"` is the hash value `123`. Use the `#{123}` form for
+/// those.
+///
+/// ```
+/// # use name_id::NameId;
+/// let spawn: NameId = "player_spawn".parse().unwrap();
+/// assert_eq!(spawn, NameId::new("player_spawn"));
+/// assert_eq!("0x2a".parse(), Ok(NameId::from_value(42)));
+/// assert_eq!("42".parse(), Ok(NameId::from_value(42)));
+/// assert_eq!("#{42}".parse(), Ok(NameId::new("42")));
+/// ```
+///
+/// With a registry, names are registered and labeled like with `From<String>`
+/// conversion, but a name that's already registered isn't leaked again.
+/// Without one, parsed ids don't carry a debug label, as repeatedly parsed
+/// names would each leak a copy.
+impl FromStr for NameId {
+    type Err = ParseIdError;
+
+    #[track_caller]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_value(s) {
+            Some(value) => value,
+            None => Ok(crate::from_borrowed_name(parse_name(s))),
+        }
+    }
+}
+
+/// Parses `s` if it's in one of the hash value forms accepted by
+/// `NameId::from_str`, returning `None` if it's a name.
+pub(crate) fn parse_value(s: &str) -> Option<Result<NameId, ParseIdError>> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return Some(parse_digits(hex, 16).unwrap_or(Err(ParseIdError::InvalidHex)));
+    }
+    parse_digits(s, 10).or_else(|| {
+        s.strip_prefix("NameId(")
+            .and_then(|it| it.strip_suffix(')'))
+            .and_then(|it| parse_digits(it, 10))
+    })
+}
+
+/// Returns the name `s` stands for if [`parse_value`] doesn't accept it.
+pub(crate) fn parse_name(s: &str) -> &str {
+    s.strip_prefix("#{")
+        .and_then(|it| it.strip_suffix('}'))
+        .unwrap_or(s)
+}
+
+/// Parses `digits` in `radix` as a hash value, returning `None` if `digits`
+/// is empty or contains anything other than digits.
+fn parse_digits(digits: &str, radix: u32) -> Option<Result<NameId, ParseIdError>> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    Some(
+        u64::from_str_radix(digits, radix)
+            .map(NameId::from_value)
+            .map_err(|_| ParseIdError::Overflow),
+    )
+}
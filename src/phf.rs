@@ -0,0 +1,114 @@
+//! Perfect hash tables keyed by [`NameId`], produced by [`id_phf!`](crate::id_phf).
+
+use crate::NameId;
+
+/// Mixing function of perfect hash tables, must match the one used by
+/// `id_phf!` macro.
+const fn mix(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58476d1ce4e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+/// Returns the slot of `value` with `displacement`, must match the one used
+/// by `id_phf!` macro.
+const fn slot(value: u64, salt: u64, displacement: u32, len: usize) -> usize {
+    let seed = (displacement as u64).wrapping_mul(0x9e3779b97f4a7c15);
+    (mix(value ^ salt ^ seed) % len as u64) as usize
+}
+
+/// Minimal perfect hash table keyed by [`NameId`], constructed at compile time
+/// by [`id_phf!`](crate::id_phf) macro.
+///
+/// Each key is assigned a distinct slot of an array with exactly as many
+/// entries as there are keys, so lookups take constant time: a bucket
+/// displacement and an entry are read, and the stored id is compared with
+/// the requested one.
+///
+/// ```
+/// # use name_id::{id, id_phf, NameIdPhf};
+/// static SPEEDS: NameIdPhf<f32> = id_phf! {
+///     walk => 1.0,
+///     run => 2.5,
+///     "crawl slowly" => 0.3,
+/// };
+///
+/// assert_eq!(SPEEDS.get(id!(run)), Some(&2.5));
+/// assert_eq!(SPEEDS.get(id!(crawl slowly)), Some(&0.3));
+/// assert_eq!(SPEEDS.get(id!(fly)), None);
+/// ```
+///
+/// Duplicate keys are rejected at compile time:
+///
+/// ```compile_fail
+/// # use name_id::{id_phf, NameIdPhf};
+/// static DUPLICATES: NameIdPhf<u8> = id_phf! { walk => 1, walk => 2 };
+/// ```
+///
+/// Fields are only public so that the macro can construct the table, they
+/// aren't part of the public API.
+#[derive(Debug)]
+pub struct NameIdPhf<V: 'static> {
+    #[doc(hidden)]
+    pub salt: u64,
+    #[doc(hidden)]
+    pub displacements: &'static [u32],
+    #[doc(hidden)]
+    pub entries: &'static [(NameId, V)],
+}
+
+impl<V: 'static> NameIdPhf<V> {
+    /// Returns the value of `id`.
+    #[inline]
+    pub const fn get(&self, id: NameId) -> Option<&'static V> {
+        let entries = self.entries;
+        if entries.is_empty() {
+            return None;
+        }
+        let value = id.value();
+        let bucket = (mix(value ^ self.salt) % self.displacements.len() as u64) as usize;
+        let entry = &entries[slot(value, self.salt, self.displacements[bucket], entries.len())];
+        if entry.0.value() == value {
+            Some(&entry.1)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the table contains `id`.
+    #[inline]
+    pub const fn contains_key(&self, id: NameId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Returns the number of entries.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the table has no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns entries in the order of their slots.
+    pub const fn entries(&self) -> &'static [(NameId, V)] {
+        self.entries
+    }
+
+    /// Returns an iterator over entries in the order of their slots.
+    pub fn iter(&self) -> core::slice::Iter<'static, (NameId, V)> {
+        self.entries.iter()
+    }
+}
+
+impl<V: 'static> IntoIterator for &NameIdPhf<V> {
+    type Item = &'static (NameId, V);
+    type IntoIter = core::slice::Iter<'static, (NameId, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,65 @@
+//! [`proptest`](::proptest) strategies for generating [`NameId`]s.
+//!
+//! ```
+//! use name_id::proptest::named_id;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     // usually annotated with #[test]
+//!     fn matches_name((name, id) in named_id()) {
+//!         prop_assert_eq!(id, name);
+//!     }
+//! }
+//! # matches_name();
+//! ```
+
+use alloc::string::String;
+
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::strategy::{Map, Strategy};
+
+use crate::NameId;
+
+/// Regex of names generated by [`named_id`].
+pub const DEFAULT_NAME_REGEX: &str = "[a-z_][a-z0-9_]{0,31}";
+
+/// Generates ids from uniformly distributed raw values, equivalent to
+/// `any::<NameId>()`.
+///
+/// Values shrink towards zero.
+pub fn any_id() -> impl Strategy<Value = NameId> {
+    any::<NameId>()
+}
+
+/// Generates `(name, id)` pairs of identifier-like names matching
+/// [`DEFAULT_NAME_REGEX`] and their ids.
+///
+/// Names shrink towards shorter and simpler strings.
+pub fn named_id() -> impl Strategy<Value = (String, NameId)> {
+    named_id_with(DEFAULT_NAME_REGEX)
+}
+
+/// Generates `(name, id)` pairs from names produced by `names` strategy.
+///
+/// Regular expressions can be used as strategies directly, e.g.
+/// `named_id_with("[A-Z]{1,8}")`. Ids are the hash of generated names, like
+/// runtime constructors would produce, but names aren't registered and ids
+/// don't carry a debug label.
+pub fn named_id_with<S>(names: S) -> impl Strategy<Value = (String, NameId)>
+where
+    S: Strategy<Value = String>,
+{
+    names.prop_map(|name| {
+        let id = NameId::from_value(crate::hash_str(&name));
+        (name, id)
+    })
+}
+
+impl Arbitrary for NameId {
+    type Parameters = ();
+    type Strategy = Map<<u64 as Arbitrary>::Strategy, fn(u64) -> NameId>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<u64>().prop_map(NameId::from_value)
+    }
+}
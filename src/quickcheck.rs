@@ -0,0 +1,66 @@
+//! [`quickcheck`](::quickcheck) support for [`NameId`].
+//!
+//! [`NameId`] implements [`Arbitrary`] by generating raw values, while
+//! [`NamedId`] can be used for properties which need to know the name an id
+//! was created from.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::NameId;
+
+/// Characters used by names generated by [`NamedId`].
+const NAME_CHARS: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '_',
+];
+
+/// Generates random raw values, shrinking towards zero.
+impl Arbitrary for NameId {
+    fn arbitrary(g: &mut Gen) -> Self {
+        NameId::from_value(u64::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value().shrink().map(NameId::from_value))
+    }
+}
+
+/// A name together with the id it hashes to.
+///
+/// Generated names are non-empty and consist of lowercase ASCII letters,
+/// digits and underscores, with length bounded by [`Gen::size`]. Shrinking
+/// shortens the name and re-hashes it, so `id` always equals the hash of
+/// `name`.
+///
+/// Names aren't registered and ids don't carry a debug label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedId(pub String, pub NameId);
+
+impl NamedId {
+    fn new(name: String) -> Self {
+        let id = NameId::from_value(crate::hash_str(&name));
+        NamedId(name, id)
+    }
+}
+
+impl Arbitrary for NamedId {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % g.size().max(1) + 1;
+        let name = (0..len)
+            .map(|_| *g.choose(NAME_CHARS).expect("NAME_CHARS isn't empty"))
+            .collect();
+        NamedId::new(name)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.0
+                .shrink()
+                .filter(|name| !name.is_empty())
+                .map(NamedId::new),
+        )
+    }
+}
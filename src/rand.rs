@@ -0,0 +1,98 @@
+//! [`rand`](::rand) support for generating [`NameId`]s.
+//!
+//! Only `no_std` parts of `rand` are used, so any [`Rng`] implementation can
+//! drive these distributions.
+
+use ::rand::distributions::{Distribution, Standard};
+use ::rand::Rng;
+use rand_distr::Zipf;
+
+use crate::NameId;
+
+/// Samples uniformly distributed raw values.
+impl Distribution<NameId> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NameId {
+        NameId::from_value(rng.gen())
+    }
+}
+
+/// Error returned when constructing a [`NameIdSampler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SamplerError {
+    /// List of names is empty.
+    NoNames,
+    /// Zipf exponent is negative or NaN.
+    InvalidExponent,
+}
+
+impl core::fmt::Display for SamplerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SamplerError::NoNames => f.write_str("sampler requires at least one name"),
+            SamplerError::InvalidExponent => {
+                f.write_str("zipf exponent must be a non-negative number")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SamplerError {}
+
+/// Distribution which picks a name from a list and yields its id.
+///
+/// Ids are the hash of picked names, like runtime constructors would produce,
+/// but names aren't registered and ids don't carry a debug label.
+#[derive(Debug, Clone, Copy)]
+pub struct NameIdSampler<'a> {
+    names: &'a [&'a str],
+    zipf: Option<Zipf<f64>>,
+}
+
+impl<'a> NameIdSampler<'a> {
+    /// Creates a sampler which picks each name with equal probability.
+    pub fn uniform(names: &'a [&'a str]) -> Result<Self, SamplerError> {
+        if names.is_empty() {
+            return Err(SamplerError::NoNames);
+        }
+        Ok(Self { names, zipf: None })
+    }
+
+    /// Creates a sampler which picks names following Zipf's law: name at
+    /// index `k` is picked with probability proportional to
+    /// `1 / (k + 1)^exponent`.
+    ///
+    /// Names should therefore be ordered from the most to the least common.
+    /// `exponent` of `0` is equivalent to [`uniform`](NameIdSampler::uniform).
+    pub fn zipf(names: &'a [&'a str], exponent: f64) -> Result<Self, SamplerError> {
+        if names.is_empty() {
+            return Err(SamplerError::NoNames);
+        }
+        let zipf =
+            Zipf::new(names.len() as u64, exponent).map_err(|_| SamplerError::InvalidExponent)?;
+        Ok(Self {
+            names,
+            zipf: Some(zipf),
+        })
+    }
+
+    /// Returns names this sampler picks from.
+    pub fn names(&self) -> &'a [&'a str] {
+        self.names
+    }
+
+    /// Picks a name without hashing it.
+    pub fn sample_name<R: Rng + ?Sized>(&self, rng: &mut R) -> &'a str {
+        let index = match &self.zipf {
+            // Zipf samples integral ranks in 1..=len
+            Some(zipf) => (zipf.sample(rng) as usize - 1).min(self.names.len() - 1),
+            None => rng.gen_range(0..self.names.len()),
+        };
+        self.names[index]
+    }
+}
+
+impl Distribution<NameId> for NameIdSampler<'_> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NameId {
+        NameId::from_value(crate::hash_str(self.sample_name(rng)))
+    }
+}
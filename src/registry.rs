@@ -0,0 +1,292 @@
+//! Global registry of names used to construct [`NameId`]s at runtime.
+
+use core::ops::Bound;
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::sync::Mutex;
+use crate::NameId;
+
+pub(crate) mod stats;
+mod storage;
+#[cfg(feature = "alloc")]
+mod suggest;
+
+#[cfg(feature = "registry_static")]
+pub use storage::REGISTRY_CAPACITY;
+use storage::{Entry, Storage};
+#[cfg(feature = "alloc")]
+pub use suggest::{registry_suggest, registry_suggest_for_id};
+
+static LOOKUP: Mutex<Storage> = Mutex::new(Storage::new());
+
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+static COLLISION_HANDLER: AtomicPtr<()> = AtomicPtr::new(panic_on_collision as *mut ());
+
+/// Information about a detected hash collision, passed to
+/// [`CollisionHandler`].
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CollisionInfo {
+    /// Kind of the reported problem.
+    pub kind: CollisionKind,
+    /// Hash value shared by both names.
+    pub hash: u64,
+    /// Name that was registered for `hash` first and is kept by the registry.
+    ///
+    /// Empty for [`CollisionKind::RegistryFull`].
+    pub previous: &'static str,
+    /// Newly constructed name that collides with `previous`.
+    pub name: &'static str,
+    /// Call site which registered `previous`.
+    ///
+    /// `None` for [`CollisionKind::RegistryFull`].
+    #[cfg(feature = "collision_locations")]
+    pub previous_location: Option<&'static core::panic::Location<'static>>,
+    /// Call site which constructed `name`.
+    #[cfg(feature = "collision_locations")]
+    pub location: &'static core::panic::Location<'static>,
+}
+
+/// Kind of problem reported through [`CollisionInfo`].
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CollisionKind {
+    /// Two different names produced the same hash.
+    Collision,
+    /// Fixed-capacity registry (`detect_collisions_static`) has no room left
+    /// for `name`, so it can't be checked for collisions.
+    RegistryFull,
+}
+
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+impl core::fmt::Display for CollisionInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            CollisionKind::Collision => {
+                write!(f, "hash id collision: {}", self.previous)?;
+                #[cfg(feature = "collision_locations")]
+                if let Some(location) = self.previous_location {
+                    write!(f, " (at {})", location)?;
+                }
+                write!(f, " collides with {}", self.name)?;
+            }
+            CollisionKind::RegistryFull => write!(
+                f,
+                "name registry is full: {} can't be checked for collisions",
+                self.name
+            )?,
+        }
+        #[cfg(feature = "collision_locations")]
+        write!(f, " (at {})", self.location)?;
+        Ok(())
+    }
+}
+
+/// Function called when a collision is detected.
+///
+/// A plain function pointer is used so handlers can be installed in `no_std`
+/// environments without allocation.
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+pub type CollisionHandler = fn(CollisionInfo);
+
+/// Default [`CollisionHandler`] which panics with a message naming both
+/// colliding names.
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+pub fn panic_on_collision(info: CollisionInfo) {
+    panic!("{}", info);
+}
+
+/// [`CollisionHandler`] which panics in debug builds and only reports the
+/// collision in release builds.
+///
+/// In release builds the collision is printed to standard error if `std`
+/// feature is enabled, and ignored otherwise.
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+pub fn debug_panic_on_collision(info: CollisionInfo) {
+    #[cfg(debug_assertions)]
+    panic!("{}", info);
+    #[cfg(all(not(debug_assertions), feature = "std"))]
+    std::eprintln!("{}", info);
+    #[cfg(all(not(debug_assertions), not(feature = "std")))]
+    let _ = info;
+}
+
+/// Replaces the function called when a collision is detected.
+///
+/// Handler is swapped atomically and can be replaced any number of times, the
+/// default one is [`panic_on_collision`]. Collisions are detected in both debug
+/// and release builds, use [`debug_panic_on_collision`] to only panic in debug
+/// builds.
+///
+/// # Re-entrancy
+///
+/// Handlers are called after the registry lock has been released, so they may
+/// query the registry. They must not however construct new `NameId`s at
+/// runtime (or call [`register`]), as a colliding name would invoke the
+/// handler recursively.
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+pub fn set_collision_handler(handler: CollisionHandler) {
+    COLLISION_HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+#[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+fn collision_handler() -> CollisionHandler {
+    let handler = COLLISION_HANDLER.load(Ordering::Acquire);
+    // SAFETY: COLLISION_HANDLER only ever stores CollisionHandler pointers.
+    unsafe { core::mem::transmute::<*mut (), CollisionHandler>(handler) }
+}
+
+/// Records `name` as the source of `id`.
+///
+/// Runtime constructors call this automatically. It's useful for ids
+/// constructed via [`NameId::from_raw`] or [`id!`](crate::id) macro, which
+/// bypass the registry.
+///
+/// If a different name has already been registered for the same hash value,
+/// the previously registered name is kept. With `detect_collisions` feature,
+/// installed `CollisionHandler` is called in that case, as well as when the
+/// fixed-capacity registry runs out of space. The registry lock is released
+/// before calling it, so the registry remains usable even if the handler
+/// panics.
+///
+/// With `collision_locations` feature, the caller of this function (or of
+/// the runtime constructor) is recorded together with `name` and reported
+/// with collisions.
+#[track_caller]
+pub fn register(id: NameId, name: &'static str) {
+    register_value(id.value(), name);
+}
+
+#[track_caller]
+pub(crate) fn register_value(value: u64, name: &'static str) {
+    #[cfg(feature = "collision_locations")]
+    let location = core::panic::Location::caller();
+    stats::count_lookup();
+    #[cfg_attr(
+        not(any(feature = "detect_collisions", feature = "detect_collisions_static")),
+        allow(unused_variables)
+    )]
+    let conflict = LOOKUP.with(|lookup| match lookup.get(value) {
+        Some(previous) if previous.name != name => Some((previous, false)),
+        Some(_) => None,
+        None => {
+            let entry = Entry {
+                name,
+                #[cfg(feature = "collision_locations")]
+                location: Some(location),
+            };
+            lookup
+                .insert(value, entry)
+                .err()
+                .map(|_| (Entry::EMPTY, true))
+        }
+    });
+
+    #[cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+    if let Some((previous, full)) = conflict {
+        collision_handler()(CollisionInfo {
+            kind: if full {
+                CollisionKind::RegistryFull
+            } else {
+                CollisionKind::Collision
+            },
+            hash: value,
+            previous: previous.name,
+            name,
+            #[cfg(feature = "collision_locations")]
+            previous_location: previous.location,
+            #[cfg(feature = "collision_locations")]
+            location,
+        });
+    }
+}
+
+pub(crate) fn resolve(value: u64) -> Option<&'static str> {
+    stats::count_lookup();
+    LOOKUP.with(|lookup| lookup.get(value).map(|entry| entry.name))
+}
+
+/// Returns number of names stored in the registry.
+pub fn registry_len() -> usize {
+    LOOKUP.with(|lookup| lookup.len())
+}
+
+/// Returns `true` if a name has been registered for `id`.
+pub fn registry_contains(id: NameId) -> bool {
+    stats::count_lookup();
+    LOOKUP.with(|lookup| lookup.get(id.value()).is_some())
+}
+
+/// Returns an iterator over all `(hash, name)` pairs stored in the registry.
+///
+/// Entries are yielded in ascending order of their hash values.
+///
+/// The registry lock is only held while each individual entry is being looked
+/// up, so it's fine to construct new ids while iterating. Entries registered
+/// during iteration are yielded only if their hash is greater than that of the
+/// last yielded entry.
+pub fn registry_iter() -> RegistryIter {
+    RegistryIter { last: None }
+}
+
+/// Calls `f` with every registered name starting with `prefix`, and its id.
+///
+/// ```
+/// # use name_id::NameId;
+/// name_id::register(NameId::from_value(1), "ui.button.save");
+/// name_id::register(NameId::from_value(2), "ui.label");
+/// let mut found = Vec::new();
+/// name_id::registry_find_prefix("ui.but", |name, _| found.push(name));
+/// assert_eq!(found, ["ui.button.save"]);
+/// ```
+///
+/// The registry is ordered by hash values, so this is a linear scan over all
+/// entries (`O(n log n)`, as each entry is looked up separately), which keeps
+/// the registry free of a second index. Names are visited in ascending order
+/// of their hash values.
+///
+/// Same as with [`registry_iter`], the registry lock is only held while each
+/// entry is looked up and is released before `f` is called, so `f` may
+/// construct new ids.
+pub fn registry_find_prefix(prefix: &str, mut f: impl FnMut(&'static str, NameId)) {
+    for (value, name) in registry_iter() {
+        if name.starts_with(prefix) {
+            f(name, NameId::from_value(value));
+        }
+    }
+}
+
+/// Returns all registered names starting with `prefix`, and their ids,
+/// sorted by name.
+///
+/// See [`registry_find_prefix`].
+#[cfg(feature = "alloc")]
+pub fn registry_find_prefix_vec(prefix: &str) -> alloc::vec::Vec<(&'static str, NameId)> {
+    let mut found = alloc::vec::Vec::new();
+    registry_find_prefix(prefix, |name, id| found.push((name, id)));
+    found.sort_unstable_by_key(|(name, _)| *name);
+    found
+}
+
+/// Iterator over registry entries, returned by [`registry_iter`].
+#[derive(Debug, Clone)]
+pub struct RegistryIter {
+    last: Option<u64>,
+}
+
+impl Iterator for RegistryIter {
+    type Item = (u64, &'static str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lower = match self.last {
+            Some(last) => Bound::Excluded(last),
+            None => Bound::Unbounded,
+        };
+        let (value, name) = LOOKUP.with(|lookup| lookup.first_from(lower))?;
+        self.last = Some(value);
+        Some((value, name))
+    }
+}
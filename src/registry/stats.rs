@@ -0,0 +1,58 @@
+//! Counters describing registry usage.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "testing")]
+use super::LOOKUP;
+
+static LEAKED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Snapshot of registry usage counters, returned by [`registry_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct RegistryStats {
+    /// Number of names stored in the registry.
+    pub entries: usize,
+    /// Total number of bytes leaked to produce `'static` names from non-static
+    /// strings (for registry entries and debug labels).
+    pub leaked_bytes: usize,
+    /// Number of registry lookups performed, including those done while
+    /// registering names.
+    pub lookups: usize,
+}
+
+/// Returns current registry usage counters.
+///
+/// Counters are updated with relaxed ordering, so values aren't guaranteed to
+/// be consistent with each other while other threads are constructing ids.
+pub fn registry_stats() -> RegistryStats {
+    RegistryStats {
+        entries: super::registry_len(),
+        leaked_bytes: LEAKED_BYTES.load(Ordering::Relaxed),
+        lookups: LOOKUPS.load(Ordering::Relaxed),
+    }
+}
+
+/// Removes all entries from the registry.
+///
+/// **Only intended for tests.** Ids constructed before clearing are forgotten
+/// by the registry, so collisions between them and ids constructed afterwards
+/// won't be detected, and they can no longer be resolved. Leaked names aren't
+/// freed, and [`RegistryStats::leaked_bytes`] and [`RegistryStats::lookups`]
+/// counters aren't reset.
+#[cfg(feature = "testing")]
+pub fn registry_clear() {
+    LOOKUP.with(|lookup| lookup.clear());
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn count_leaked(bytes: usize) {
+    LEAKED_BYTES.fetch_add(bytes, Ordering::Relaxed);
+}
+
+#[inline]
+pub(super) fn count_lookup() {
+    LOOKUPS.fetch_add(1, Ordering::Relaxed);
+}
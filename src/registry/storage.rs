@@ -0,0 +1,163 @@
+//! Storage backends of the global registry.
+//!
+//! [`BTreeMap`](alloc::collections::BTreeMap) is used with `registry` feature,
+//! while `registry_static` replaces it with a fixed-capacity sorted array that
+//! doesn't require allocation.
+
+use core::ops::Bound;
+
+/// Data stored for each registered hash value.
+#[derive(Clone, Copy)]
+pub(crate) struct Entry {
+    pub(crate) name: &'static str,
+    /// Call site which registered `name`.
+    #[cfg(feature = "collision_locations")]
+    pub(crate) location: Option<&'static core::panic::Location<'static>>,
+}
+
+impl Entry {
+    pub(crate) const EMPTY: Entry = Entry {
+        name: "",
+        #[cfg(feature = "collision_locations")]
+        location: None,
+    };
+}
+
+/// Maximum number of entries stored by the `registry_static` backend.
+///
+/// Defaults to 1024 and can be overridden at compile time by setting
+/// `NAME_ID_REGISTRY_CAPACITY` environment variable to a decimal number.
+#[cfg(feature = "registry_static")]
+pub const REGISTRY_CAPACITY: usize = match option_env!("NAME_ID_REGISTRY_CAPACITY") {
+    Some(value) => parse_capacity(value),
+    None => 1024,
+};
+
+#[cfg(feature = "registry_static")]
+const fn parse_capacity(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    assert!(
+        !bytes.is_empty(),
+        "NAME_ID_REGISTRY_CAPACITY must be a decimal number"
+    );
+    let mut result = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = bytes[i];
+        assert!(
+            digit.is_ascii_digit(),
+            "NAME_ID_REGISTRY_CAPACITY must be a decimal number"
+        );
+        result = result * 10 + (digit - b'0') as usize;
+        i += 1;
+    }
+    result
+}
+
+/// Returned by [`Storage::insert`] when there's no room for a new entry.
+pub(crate) struct StorageFull;
+
+#[cfg(not(feature = "registry_static"))]
+pub(crate) struct Storage {
+    entries: alloc::collections::BTreeMap<u64, Entry>,
+}
+
+#[cfg(not(feature = "registry_static"))]
+impl Storage {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: alloc::collections::BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, value: u64) -> Option<Entry> {
+        self.entries.get(&value).copied()
+    }
+
+    /// Inserts a new entry, `value` must not be present already.
+    pub(crate) fn insert(&mut self, value: u64, entry: Entry) -> Result<(), StorageFull> {
+        self.entries.insert(value, entry);
+        Ok(())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[cfg(feature = "testing")]
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the first entry with hash value within `lower` bound.
+    pub(crate) fn first_from(&self, lower: Bound<u64>) -> Option<(u64, &'static str)> {
+        self.entries
+            .range((lower, Bound::Unbounded))
+            .next()
+            .map(|(value, entry)| (*value, entry.name))
+    }
+}
+
+/// Fixed-capacity map which keeps its entries sorted by hash value so that
+/// lookups can use binary search.
+#[cfg(feature = "registry_static")]
+pub(crate) struct Storage {
+    len: usize,
+    entries: [(u64, Entry); REGISTRY_CAPACITY],
+}
+
+#[cfg(feature = "registry_static")]
+impl Storage {
+    pub(crate) const fn new() -> Self {
+        Self {
+            len: 0,
+            entries: [(0, Entry::EMPTY); REGISTRY_CAPACITY],
+        }
+    }
+
+    fn search(&self, value: u64) -> Result<usize, usize> {
+        self.entries[..self.len].binary_search_by_key(&value, |(value, _)| *value)
+    }
+
+    pub(crate) fn get(&self, value: u64) -> Option<Entry> {
+        self.search(value).ok().map(|i| self.entries[i].1)
+    }
+
+    /// Inserts a new entry, `value` must not be present already.
+    pub(crate) fn insert(&mut self, value: u64, entry: Entry) -> Result<(), StorageFull> {
+        if self.len == REGISTRY_CAPACITY {
+            return Err(StorageFull);
+        }
+        let i = match self.search(value) {
+            Ok(i) | Err(i) => i,
+        };
+        self.entries.copy_within(i..self.len, i + 1);
+        self.entries[i] = (value, entry);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    #[cfg(feature = "testing")]
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the first entry with hash value within `lower` bound.
+    pub(crate) fn first_from(&self, lower: Bound<u64>) -> Option<(u64, &'static str)> {
+        let i = match lower {
+            Bound::Included(value) => self.search(value).unwrap_or_else(|i| i),
+            Bound::Excluded(value) => match self.search(value) {
+                Ok(i) => i + 1,
+                Err(i) => i,
+            },
+            Bound::Unbounded => 0,
+        };
+        self.entries[..self.len]
+            .get(i)
+            .map(|(value, entry)| (*value, entry.name))
+    }
+}
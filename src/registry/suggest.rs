@@ -0,0 +1,54 @@
+//! "Did you mean" suggestions of registered names.
+
+use alloc::vec::Vec;
+
+use super::registry_iter;
+use crate::suggest::similar_names;
+use crate::NameId;
+
+/// Returns up to `max` registered names that are similar to `input`, most
+/// similar first.
+///
+/// Names are ranked by their edit (Levenshtein) distance to `input`, and
+/// names sharing a longer prefix with `input` come first among names with
+/// the same distance. A name is considered similar if it's at most a third
+/// of `input` length (and at least 2 edits) away from it, or if it starts
+/// with `input`.
+///
+/// ```
+/// # use name_id::NameId;
+/// let _ = NameId::new("player_spawn");
+/// let suggestion = name_id::registry_suggest("player_spwan", 1).next();
+/// assert_eq!(suggestion, Some("player_spawn"));
+/// ```
+///
+/// Like [`registry_find_prefix`](crate::registry_find_prefix), this scans
+/// all registry entries. A single row of the distance matrix is allocated and
+/// reused for all names.
+pub fn registry_suggest(input: &str, max: usize) -> impl Iterator<Item = &'static str> {
+    similar_names(input, registry_iter().map(|(_, name)| name), max)
+}
+
+/// Returns registered names with hash values that are near misses of `id`,
+/// closest first.
+///
+/// Useful for finding the name of an id that got corrupted, as a value is
+/// reported if `id`:
+/// - differs from it in at most 4 bits,
+/// - is its lower 32 bits (value truncated to `u32`),
+/// - is the value rounded to 53 significant bits, as happens when it's
+///   stored as an `f64` (e.g. a number in JavaScript).
+///
+/// Names are ordered by the number of bits their value differs from `id` in.
+pub fn registry_suggest_for_id(id: NameId) -> impl Iterator<Item = &'static str> {
+    let target = id.value();
+    let mut found: Vec<(u32, &'static str)> = registry_iter()
+        .filter_map(|(value, name)| {
+            let bits = (value ^ target).count_ones();
+            let near = bits <= 4 || value as u32 as u64 == target || value as f64 as u64 == target;
+            near.then_some((bits, name))
+        })
+        .collect();
+    found.sort_unstable();
+    found.into_iter().map(|(_, name)| name)
+}
@@ -0,0 +1,94 @@
+//! [`Debug`](core::fmt::Debug) output with ids resolved to their names.
+
+use core::fmt;
+
+use crate::NameId;
+
+/// [`Debug`](fmt::Debug) adapter which prints ids as their names.
+///
+/// Each id is printed as its embedded debug label, or its registered name if
+/// there's no label, and as a `0x`-prefixed hex hash value if neither is
+/// available. Unlike the `NameId` `Debug` output, this keeps map dumps
+/// readable in release builds as long as names were registered.
+///
+/// Supports a single [`NameId`], slices of ids, slices of `(NameId, V)` pairs
+/// and maps keyed by ids (`BTreeMap` with `alloc` feature, `hashbrown` maps
+/// with `hashbrown` feature, `std::collections::HashMap` with `std` feature and
+/// `IdMap` with `collections` feature). Values are printed with their own `Debug`
+/// implementation.
+///
+/// ```
+/// # use name_id::{NameId, Resolved};
+/// let health = [(NameId::from_value(0x2a), 100)];
+/// assert_eq!(format!("{:?}", Resolved(&health[..])), "{0x000000000000002a: 100}");
+/// ```
+pub struct Resolved<'a, T: ?Sized>(pub &'a T);
+
+/// Prints a single id as described in [`Resolved`] documentation.
+struct Name(NameId);
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.label() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{:#018x}", self.0.value()),
+        }
+    }
+}
+
+fn debug_map<'a, V: fmt::Debug + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    entries: impl IntoIterator<Item = (&'a NameId, &'a V)>,
+) -> fmt::Result {
+    f.debug_map()
+        .entries(entries.into_iter().map(|(id, value)| (Name(*id), value)))
+        .finish()
+}
+
+impl fmt::Debug for Resolved<'_, NameId> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Name(*self.0).fmt(f)
+    }
+}
+
+impl fmt::Debug for Resolved<'_, [NameId]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|id| Name(*id)))
+            .finish()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Resolved<'_, [(NameId, V)]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_map(f, self.0.iter().map(|(id, value)| (id, value)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: fmt::Debug> fmt::Debug for Resolved<'_, alloc::collections::BTreeMap<NameId, V>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_map(f, self.0)
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<V: fmt::Debug, S> fmt::Debug for Resolved<'_, hashbrown::HashMap<NameId, V, S>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_map(f, self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: fmt::Debug, S> fmt::Debug for Resolved<'_, std::collections::HashMap<NameId, V, S>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_map(f, self.0)
+    }
+}
+
+#[cfg(feature = "collections")]
+impl<V: fmt::Debug> fmt::Debug for Resolved<'_, crate::IdMap<V>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_map(f, self.0)
+    }
+}
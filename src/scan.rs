@@ -0,0 +1,107 @@
+//! Offline collision checks of name lists.
+
+/// Two different names with the same hash value, found by
+/// [`scan_collisions`](crate::scan_collisions) or [`scan_collisions_in`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Collision<'a> {
+    /// Lexicographically smaller of the two names.
+    pub first: &'a str,
+    /// Lexicographically greater of the two names.
+    pub second: &'a str,
+    /// Hash value shared by both names.
+    pub hash: u64,
+}
+
+impl core::fmt::Display for Collision<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} and {:?} have the same hash value {:#018x}",
+            self.first, self.second, self.hash
+        )
+    }
+}
+
+/// Hashes all `names` with the selected hasher and returns every pair of
+/// different names with the same hash value.
+///
+/// Unlike collision detection of the global registry, this doesn't require
+/// any feature and doesn't register the names, so it can be used in build
+/// scripts and tests to check a list of names before they're shipped:
+///
+/// ```
+/// let names = ["ui.button.save", "ui.button.cancel", "ui.button.save"];
+/// // repeated names aren't collisions
+/// assert_eq!(name_id::scan_collisions(names), Ok(()));
+/// ```
+///
+/// Names are hashed the same way [`NameId::new`](crate::NameId::new) hashes
+/// them. If more than two names share a hash value, each pair of them is
+/// reported. Collisions are ordered by hash value and then by names.
+///
+/// Takes `O(n log n)` time and `O(n)` memory. See [`scan_collisions_in`] for
+/// a variant that doesn't allocate.
+#[cfg(feature = "alloc")]
+pub fn scan_collisions<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> Result<(), alloc::vec::Vec<Collision<'a>>> {
+    let mut hashed: alloc::vec::Vec<(u64, &'a str)> = names
+        .into_iter()
+        .map(|name| (crate::hash_str(name), name))
+        .collect();
+    hashed.sort_unstable();
+    let mut collisions = alloc::vec::Vec::new();
+    report_sorted(&hashed, |it| *it, |collision| collisions.push(collision));
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(collisions)
+    }
+}
+
+/// Same as [`scan_collisions`](crate::scan_collisions), but calls `f` with
+/// each collision instead of allocating a list of them.
+///
+/// `names` are reordered (by their hash values) in place, so no memory is
+/// allocated. Takes `O(n log n)` time, hashing each name `O(log n)` times.
+///
+/// ```
+/// let mut names = ["sword", "shield", "sword"];
+/// let mut found = 0;
+/// name_id::scan_collisions_in(&mut names, |_| found += 1);
+/// assert_eq!(found, 0);
+/// ```
+pub fn scan_collisions_in<'a>(names: &mut [&'a str], f: impl FnMut(Collision<'a>)) {
+    names.sort_unstable_by(|a, b| (crate::hash_str(a), a).cmp(&(crate::hash_str(b), b)));
+    report_sorted(names, |name| (crate::hash_str(name), *name), f);
+}
+
+/// Reports collisions in `sorted` items, ordered by hash value and name
+/// returned by `key`.
+pub(crate) fn report_sorted<'a, T>(
+    sorted: &[T],
+    key: impl Fn(&T) -> (u64, &'a str),
+    mut f: impl FnMut(Collision<'a>),
+) {
+    for run in sorted.chunk_by(|a, b| key(a).0 == key(b).0) {
+        for (i, item) in run.iter().enumerate() {
+            let (hash, first) = key(item);
+            // equal names are adjacent, only the first one of them is paired
+            if i > 0 && key(&run[i - 1]).1 == first {
+                continue;
+            }
+            let mut last = first;
+            for other in &run[i + 1..] {
+                let (_, second) = key(other);
+                if second != last {
+                    f(Collision {
+                        first,
+                        second,
+                        hash,
+                    });
+                    last = second;
+                }
+            }
+        }
+    }
+}
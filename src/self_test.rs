@@ -0,0 +1,136 @@
+//! Verification that `id!` macro and runtime constructors agree on hash values.
+//!
+//! `name-id-macros` crate is compiled separately from `name-id` and resolves
+//! its hasher features on its own. If the two end up hashing differently (e.g.
+//! due to feature resolution in a workspace), ids produced by `id!` will never
+//! match ids constructed at runtime. Functions in this module turn that silent
+//! mismatch into an error.
+
+use crate::NameId;
+
+/// Expands into an array of `(input, id!(input))` reference vectors.
+///
+/// Used by both [`GOLDEN_VECTORS`] and [`verify!`](crate::verify), so that
+/// the latter is expanded with the macro crate as resolved for the calling
+/// crate.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __golden_vectors {
+    () => {
+        [
+            ("", $crate::id!("")),
+            ("a", $crate::id!("a")),
+            ("name_id", $crate::id!(name_id)),
+            ("some_id_ident", $crate::id!(some_id_ident)),
+            ("can even be 6 or more", $crate::id!(can even be 6 or more)),
+            (
+                "id macro supports string values",
+                $crate::id!("id macro supports string values"),
+            ),
+            ("256", $crate::id!(256)),
+            ("!%$#", $crate::id!("!%$#")),
+            ("ünïcødé ✓", $crate::id!("ünïcødé ✓")),
+            (
+                "a somewhat longer input spanning multiple hasher blocks",
+                $crate::id!("a somewhat longer input spanning multiple hasher blocks"),
+            ),
+        ]
+    };
+}
+
+/// Reference vectors with `id!` values computed when compiling this crate.
+#[doc(hidden)]
+pub const GOLDEN_VECTORS: [(&str, NameId); 10] = __golden_vectors!();
+
+/// Expected hash values of [`GOLDEN_VECTORS`] for the selected hasher.
+#[doc(hidden)]
+#[cfg(feature = "ahash")]
+pub const EXPECTED_HASHES: [u64; 10] = [
+    12047041684934772681,
+    13317008914703140273,
+    1826394422878227844,
+    498745218805183219,
+    10829861378123449861,
+    10398550419565578837,
+    9091362107251849601,
+    442163473201277466,
+    4996932876321561428,
+    17470657224670101188,
+];
+
+/// Describes a hash value disagreement found by [`self_test`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelfTestError {
+    /// Hashed input string.
+    pub input: &'static str,
+    /// Value computed by `id!` macro at compile time.
+    pub macro_value: u64,
+    /// Value computed by this crate at runtime.
+    pub runtime_value: u64,
+    /// Value expected for the selected hasher.
+    pub expected: u64,
+}
+
+impl core::fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.macro_value != self.runtime_value {
+            write!(
+                f,
+                "id! macro and runtime hashes of {:?} differ (macro: {}, runtime: {}); name-id and name-id-macros were likely built with different hasher features",
+                self.input, self.macro_value, self.runtime_value
+            )
+        } else {
+            write!(
+                f,
+                "hash of {:?} is {} instead of expected {}; hasher implementation changed",
+                self.input, self.runtime_value, self.expected
+            )
+        }
+    }
+}
+
+impl core::error::Error for SelfTestError {}
+
+/// Hashes reference vectors at runtime and compares them with values computed
+/// by `id!` macro when this crate was compiled, as well as values expected for
+/// the selected hasher.
+///
+/// Use [`verify!`](crate::verify) to also check the macro as resolved for the
+/// calling crate.
+pub fn self_test() -> Result<(), SelfTestError> {
+    check_vectors(&GOLDEN_VECTORS)
+}
+
+#[doc(hidden)]
+pub fn check_vectors(vectors: &[(&'static str, NameId)]) -> Result<(), SelfTestError> {
+    for ((input, id), expected) in vectors.iter().zip(EXPECTED_HASHES) {
+        let runtime_value = crate::hash_str(input);
+        if id.value() != runtime_value || runtime_value != expected {
+            return Err(SelfTestError {
+                input,
+                macro_value: id.value(),
+                runtime_value,
+                expected,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Panics if `id!` macro, as expanded in the calling crate, and runtime
+/// constructors disagree on hash values.
+///
+/// Intended to be placed in a test:
+///
+/// ```
+/// // in #[test] function:
+/// name_id::verify!();
+/// ```
+#[macro_export]
+macro_rules! verify {
+    () => {
+        if let Err(err) = $crate::self_test::check_vectors(&$crate::__golden_vectors!()) {
+            panic!("{}", err);
+        }
+    };
+}
@@ -0,0 +1,124 @@
+//! Serde with-module for maps keyed by [`NameId`].
+//!
+//! Many formats (e.g. JSON) only allow strings as map keys. This module
+//! serializes keys as `0x`-prefixed hex strings of their hash value (e.g.
+//! `"0x00000000000004d2"`), and parses keys back as follows:
+//! - strings starting with `0x` are parsed as hex hash values,
+//! - strings consisting only of ASCII digits are parsed as decimal hash values,
+//! - any other string is treated as a name and hashed.
+//!
+//! Integer keys are accepted as well for formats that support them.
+//!
+//! Works with any map type that can be iterated over by reference and built
+//! via `Default` + `Extend`, such as `HashMap` and `BTreeMap`:
+//!
+//! ```
+//! # use std::collections::HashMap;
+//! # use name_id::NameId;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Save {
+//!     #[serde(with = "name_id::serde_key")]
+//!     scores: HashMap<NameId, u32>,
+//! }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::NameId;
+
+/// Serializes `map` with keys formatted as `0x`-prefixed hex strings.
+pub fn serialize<'a, M, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a NameId, &'a V)>,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(map.into_iter().map(|(key, value)| (HexKey(*key), value)))
+}
+
+/// Deserializes a map with keys in any of the forms described in
+/// [module documentation](self).
+pub fn deserialize<'de, M, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: Default + Extend<(NameId, V)>,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+struct HexKey(NameId);
+
+impl Serialize for HexKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("0x{:016x}", self.0.value()))
+    }
+}
+
+struct MapVisitor<M, V>(PhantomData<(M, V)>);
+
+impl<'de, M, V> Visitor<'de> for MapVisitor<M, V>
+where
+    M: Default + Extend<(NameId, V)>,
+    V: Deserialize<'de>,
+{
+    type Value = M;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map keyed by ids")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<M, A::Error> {
+        let mut map = M::default();
+        while let Some((key, value)) = access.next_entry::<Key, V>()? {
+            map.extend(core::iter::once((key.0, value)));
+        }
+        Ok(map)
+    }
+}
+
+/// Map key which accepts hash values and names.
+struct Key(NameId);
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(KeyVisitor).map(Key)
+    }
+}
+
+struct KeyVisitor;
+
+impl Visitor<'_> for KeyVisitor {
+    type Value = NameId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a hex (0x...) or decimal id value, or a name string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<NameId, E> {
+        Ok(NameId::from_value(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<NameId, E> {
+        u64::try_from(value)
+            .map(NameId::from_value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<NameId, E> {
+        let parsed = if let Some(hex) = value.strip_prefix("0x") {
+            u64::from_str_radix(hex, 16).ok()
+        } else if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+            value.parse().ok()
+        } else {
+            return Ok(NameId::from_value(crate::hash_str(value)));
+        };
+        parsed
+            .map(NameId::from_value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
@@ -0,0 +1,62 @@
+//! Serde with-module for maps keyed by [`NameId`], with readable key names.
+//!
+//! Keys are serialized as their name when one is available (the embedded
+//! debug label, or the registered name), and as `0x`-prefixed hex strings of
+//! their hash value otherwise. Names that would be read back as hash values
+//! (decimal numbers or `0x` prefixed strings) are also written in hex form.
+//!
+//! Keys are deserialized the same way as with [`serde_key`](crate::serde_key),
+//! so names are hashed and numeric strings are parsed as hash values:
+//!
+//! ```
+//! # use std::collections::BTreeMap;
+//! # use name_id::NameId;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Save {
+//!     #[serde(with = "name_id::serde_named_keys")]
+//!     spawns: BTreeMap<NameId, (f32, f32)>,
+//! }
+//! ```
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::NameId;
+
+/// Serializes `map` with keys formatted as names where available, and as
+/// `0x`-prefixed hex strings otherwise.
+pub fn serialize<'a, M, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a NameId, &'a V)>,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(map.into_iter().map(|(key, value)| (NamedKey(*key), value)))
+}
+
+/// Deserializes a map with keys in any of the forms accepted by
+/// [`serde_key`](crate::serde_key::deserialize).
+pub fn deserialize<'de, M, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: Default + Extend<(NameId, V)>,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    crate::serde_key::deserialize(deserializer)
+}
+
+struct NamedKey(NameId);
+
+impl Serialize for NamedKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.label() {
+            Some(name) if !reads_as_value(name) => serializer.serialize_str(name),
+            _ => serializer.collect_str(&format_args!("0x{:016x}", self.0.value())),
+        }
+    }
+}
+
+/// Whether `name` would be parsed as a hash value instead of being hashed.
+fn reads_as_value(name: &str) -> bool {
+    name.starts_with("0x") || (!name.is_empty() && name.bytes().all(|b| b.is_ascii_digit()))
+}
@@ -0,0 +1,64 @@
+//! Serde with-module storing a single [`NameId`] field as its name.
+//!
+//! Intended for human-edited files. Ids are serialized as strings:
+//! - the embedded debug label if there is one, or the registered name
+//!   otherwise,
+//! - `#` followed by 16 hex digits of the hash value (e.g.
+//!   `"#00000000000004d2"`) if no name is known, or if the name itself starts
+//!   with `#`.
+//!
+//! Deserialization parses `#`-prefixed strings as hex hash values and hashes
+//! any other string as a name, so serialized ids always read back as the same
+//! value.
+//!
+//! Unlike the default `NameId` serde implementation (which stays numeric),
+//! this only accepts strings:
+//!
+//! ```
+//! # use name_id::NameId;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Door {
+//!     #[serde(with = "name_id::serde_str")]
+//!     leads_to: NameId,
+//! }
+//! ```
+
+use core::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use crate::NameId;
+
+/// Serializes `id` as its name if it's known, and as a `#`-prefixed hex
+/// string of its hash value otherwise.
+pub fn serialize<S: Serializer>(id: &NameId, serializer: S) -> Result<S::Ok, S::Error> {
+    match id.label() {
+        Some(name) if !name.starts_with('#') => serializer.serialize_str(name),
+        _ => serializer.collect_str(&format_args!("#{:016x}", id.value())),
+    }
+}
+
+/// Deserializes an id from a name or a `#`-prefixed hex hash value string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NameId, D::Error> {
+    deserializer.deserialize_str(StrVisitor)
+}
+
+struct StrVisitor;
+
+impl Visitor<'_> for StrVisitor {
+    type Value = NameId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a name string or a \"#\" prefixed hex id value string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<NameId, E> {
+        match value.strip_prefix('#') {
+            Some(hex) => u64::from_str_radix(hex, 16)
+                .map(NameId::from_value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self)),
+            None => Ok(NameId::from_value(crate::hash_str(value))),
+        }
+    }
+}
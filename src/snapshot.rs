@@ -0,0 +1,222 @@
+//! Registry snapshots for offline symbolication of raw hash values.
+//!
+//! Snapshots can be written only with `registry` or `registry_static`
+//! features, while parsing them only requires `alloc`.
+//!
+//! Two formats are supported:
+//!
+//! - Text, written by [`registry_snapshot`]: one entry per line, consisting of
+//!   16 lowercase hex digits of the hash, a tab, and the name followed by
+//!   `\n`. Backslashes, tabs, carriage returns and newlines in names are
+//!   escaped as `\\`, `\t`, `\r` and `\n` respectively.
+//! - Binary, written by [`registry_snapshot_bytes`]: a sequence of entries,
+//!   each consisting of the hash as `u64` LE, name length in bytes as `u16`
+//!   LE, and UTF-8 bytes of the name. Names longer than `u16::MAX` bytes are
+//!   truncated at a character boundary.
+//!
+//! Entries are written in ascending order of their hash values.
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+use core::fmt::Write;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+use crate::registry::registry_iter;
+
+/// Writes all registry entries into `writer` using the text format described
+/// in [module documentation](self).
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+pub fn registry_snapshot(writer: &mut impl Write) -> core::fmt::Result {
+    for (value, name) in registry_iter() {
+        write!(writer, "{:016x}\t", value)?;
+        write_escaped(writer, name)?;
+        writer.write_char('\n')?;
+    }
+    Ok(())
+}
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+fn write_escaped(writer: &mut impl Write, name: &str) -> core::fmt::Result {
+    let mut rest = name;
+    while let Some(i) = rest.find(['\\', '\t', '\r', '\n']) {
+        writer.write_str(&rest[..i])?;
+        writer.write_str(match rest.as_bytes()[i] {
+            b'\\' => "\\\\",
+            b'\t' => "\\t",
+            b'\r' => "\\r",
+            _ => "\\n",
+        })?;
+        rest = &rest[i + 1..];
+    }
+    writer.write_str(rest)
+}
+
+/// Returns all registry entries encoded in the binary format described in
+/// [module documentation](self).
+#[cfg(all(
+    any(feature = "registry", feature = "registry_static"),
+    feature = "alloc"
+))]
+pub fn registry_snapshot_bytes() -> Vec<u8> {
+    let mut result = Vec::new();
+    for (value, name) in registry_iter() {
+        let mut len = name.len().min(u16::MAX as usize);
+        while !name.is_char_boundary(len) {
+            len -= 1;
+        }
+        result.extend_from_slice(&value.to_le_bytes());
+        result.extend_from_slice(&(len as u16).to_le_bytes());
+        result.extend_from_slice(&name.as_bytes()[..len]);
+    }
+    result
+}
+
+/// Error returned when parsing a malformed registry snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// Input ended in the middle of an entry starting at `offset` bytes.
+    Truncated { offset: usize },
+    /// Name of entry starting at `offset` bytes isn't valid UTF-8.
+    InvalidUtf8 { offset: usize },
+    /// Text entry on `line` doesn't have the `hash<TAB>name` form.
+    InvalidLine { line: usize },
+    /// Text entry on `line` contains an unknown escape sequence.
+    InvalidEscape { line: usize },
+}
+
+impl core::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SnapshotError::Truncated { offset } => {
+                write!(f, "snapshot entry at offset {} is truncated", offset)
+            }
+            SnapshotError::InvalidUtf8 { offset } => {
+                write!(f, "snapshot entry at offset {} isn't valid UTF-8", offset)
+            }
+            SnapshotError::InvalidLine { line } => {
+                write!(f, "snapshot line {} isn't in 'hash<TAB>name' form", line)
+            }
+            SnapshotError::InvalidEscape { line } => {
+                write!(
+                    f,
+                    "snapshot line {} contains an invalid escape sequence",
+                    line
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for SnapshotError {}
+
+/// Parsed registry snapshot, used to resolve hash values back into names.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistrySnapshot<'a> {
+    /// Entries sorted by hash value.
+    entries: Vec<(u64, Cow<'a, str>)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> RegistrySnapshot<'a> {
+    /// Parses a snapshot in binary format, borrowing names from `data`.
+    pub fn parse(data: &'a [u8]) -> Result<Self, SnapshotError> {
+        let mut entries = Vec::new();
+        let mut offset = 0;
+        while offset < data.len() {
+            let rest = &data[offset..];
+            if rest.len() < 10 {
+                return Err(SnapshotError::Truncated { offset });
+            }
+            let value = u64::from_le_bytes(rest[..8].try_into().unwrap());
+            let len = u16::from_le_bytes(rest[8..10].try_into().unwrap()) as usize;
+            let name = rest
+                .get(10..10 + len)
+                .ok_or(SnapshotError::Truncated { offset })?;
+            let name =
+                core::str::from_utf8(name).map_err(|_| SnapshotError::InvalidUtf8 { offset })?;
+            entries.push((value, Cow::Borrowed(name)));
+            offset += 10 + len;
+        }
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Parses a snapshot in text format.
+    ///
+    /// Names are borrowed from `text` unless they contain escape sequences.
+    pub fn parse_text(text: &'a str) -> Result<Self, SnapshotError> {
+        let mut entries = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            if line.is_empty() {
+                continue;
+            }
+            let (hash, name) = line
+                .split_once('\t')
+                .ok_or(SnapshotError::InvalidLine { line: line_number })?;
+            let value = u64::from_str_radix(hash, 16)
+                .map_err(|_| SnapshotError::InvalidLine { line: line_number })?;
+            let name = unescape(name).ok_or(SnapshotError::InvalidEscape { line: line_number })?;
+            entries.push((value, name));
+        }
+        Ok(Self::from_entries(entries))
+    }
+
+    fn from_entries(mut entries: Vec<(u64, Cow<'a, str>)>) -> Self {
+        // stable sort keeps the first occurrence of duplicate hashes in front
+        entries.sort_by_key(|(value, _)| *value);
+        entries.dedup_by_key(|(value, _)| *value);
+        Self { entries }
+    }
+
+    /// Returns the name recorded for `value` hash, if any.
+    pub fn resolve(&self, value: u64) -> Option<&str> {
+        self.entries
+            .binary_search_by_key(&value, |(value, _)| *value)
+            .ok()
+            .map(|i| self.entries[i].1.as_ref())
+    }
+
+    /// Returns number of entries in the snapshot.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the snapshot contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over `(hash, name)` entries in ascending order of
+    /// their hash values.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &str)> {
+        self.entries
+            .iter()
+            .map(|(value, name)| (*value, name.as_ref()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn unescape(name: &str) -> Option<Cow<'_, str>> {
+    if !name.contains('\\') {
+        return Some(Cow::Borrowed(name));
+    }
+    let mut result = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        result.push(match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'r' => '\r',
+            'n' => '\n',
+            _ => return None,
+        });
+    }
+    Some(Cow::Owned(result))
+}
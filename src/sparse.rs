@@ -0,0 +1,252 @@
+//! Sparse set storage keyed by [`NameId`].
+
+use alloc::vec::Vec;
+
+use crate::{IdHashMap, IdentityBuildHasher, NameId};
+
+/// Map keyed by [`NameId`] which stores values in a contiguous array.
+///
+/// Ids are mapped to slots of a dense array of values, so iterating over
+/// values is as fast as iterating over a slice, while lookups go through a
+/// hash map which doesn't rehash ids.
+///
+/// Values are kept in insertion order until one is removed:
+/// [`remove`](SparseIdMap::remove) moves the last value into the slot of the
+/// removed one (as [`Vec::swap_remove`] does), so removal takes `O(1)` time
+/// but changes the order of remaining values.
+///
+/// At most `u32::MAX` values can be stored.
+///
+/// Requires `alloc` feature, and `std` or `hashbrown` feature for the map of
+/// slots.
+#[derive(Clone)]
+pub struct SparseIdMap<V> {
+    slots: IdHashMap<u32>,
+    ids: Vec<NameId>,
+    values: Vec<V>,
+}
+
+impl<V> SparseIdMap<V> {
+    /// Creates an empty map.
+    pub const fn new() -> Self {
+        SparseIdMap {
+            slots: IdHashMap::with_hasher(IdentityBuildHasher),
+            ids: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Creates an empty map with space for at least `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SparseIdMap {
+            slots: IdHashMap::with_capacity_and_hasher(capacity, IdentityBuildHasher),
+            ids: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts `value` for `id` and returns the previous value.
+    ///
+    /// New values are appended to the end of the dense array, while existing
+    /// values are replaced in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map already contains `u32::MAX` values.
+    pub fn insert(&mut self, id: NameId, value: V) -> Option<V> {
+        if let Some(&slot) = self.slots.get(&id) {
+            return Some(core::mem::replace(&mut self.values[slot as usize], value));
+        }
+        assert!(
+            self.values.len() < u32::MAX as usize,
+            "SparseIdMap can't store more than u32::MAX values"
+        );
+        self.slots.insert(id, self.values.len() as u32);
+        self.ids.push(id);
+        self.values.push(value);
+        None
+    }
+
+    /// Returns a reference to the value of `id`.
+    pub fn get(&self, id: NameId) -> Option<&V> {
+        self.slot(id).map(|slot| &self.values[slot])
+    }
+
+    /// Returns a mutable reference to the value of `id`.
+    pub fn get_mut(&mut self, id: NameId) -> Option<&mut V> {
+        self.slot(id).map(|slot| &mut self.values[slot])
+    }
+
+    /// Returns `true` if the map contains a value for `id`.
+    pub fn contains_key(&self, id: NameId) -> bool {
+        self.slots.contains_key(&id)
+    }
+
+    /// Returns the index of the value of `id` in [`values`](SparseIdMap::values).
+    pub fn slot(&self, id: NameId) -> Option<usize> {
+        self.slots.get(&id).map(|&slot| slot as usize)
+    }
+
+    /// Removes the value of `id` and returns it.
+    ///
+    /// The last value is moved into the slot of the removed one. When
+    /// removing values while walking over slots, the same slot must be
+    /// visited again after a removal, see [`retain`](SparseIdMap::retain).
+    pub fn remove(&mut self, id: NameId) -> Option<V> {
+        let slot = self.slots.remove(&id)? as usize;
+        self.ids.swap_remove(slot);
+        if let Some(moved) = self.ids.get(slot) {
+            self.slots.insert(*moved, slot as u32);
+        }
+        Some(self.values.swap_remove(slot))
+    }
+
+    /// Removes all values for which `keep` returns `false`.
+    ///
+    /// Values are visited in dense order, with the same reordering as
+    /// [`remove`](SparseIdMap::remove).
+    pub fn retain(&mut self, mut keep: impl FnMut(NameId, &mut V) -> bool) {
+        let mut slot = 0;
+        while slot < self.values.len() {
+            if keep(self.ids[slot], &mut self.values[slot]) {
+                slot += 1;
+            } else {
+                self.remove(self.ids[slot]);
+            }
+        }
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the map contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all values, keeping allocated capacity.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.ids.clear();
+        self.values.clear();
+    }
+
+    /// Returns ids in dense order, matching [`values`](SparseIdMap::values).
+    pub fn ids(&self) -> &[NameId] {
+        &self.ids
+    }
+
+    /// Returns values in dense order.
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Returns mutable values in dense order.
+    pub fn values_mut(&mut self) -> &mut [V] {
+        &mut self.values
+    }
+
+    /// Returns an iterator over ids and their values in dense order.
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter {
+            inner: self.ids.iter().zip(self.values.iter()),
+        }
+    }
+
+    /// Returns an iterator over ids and mutable values in dense order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut {
+            inner: self.ids.iter().zip(self.values.iter_mut()),
+        }
+    }
+}
+
+impl<V> Default for SparseIdMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: core::fmt::Debug> core::fmt::Debug for SparseIdMap<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> FromIterator<(NameId, V)> for SparseIdMap<V> {
+    fn from_iter<I: IntoIterator<Item = (NameId, V)>>(iter: I) -> Self {
+        let mut map = SparseIdMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(NameId, V)> for SparseIdMap<V> {
+    fn extend<I: IntoIterator<Item = (NameId, V)>>(&mut self, iter: I) {
+        for (id, value) in iter {
+            self.insert(id, value);
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a SparseIdMap<V> {
+    type Item = (NameId, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut SparseIdMap<V> {
+    type Item = (NameId, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over ids and values of a [`SparseIdMap`], returned by
+/// [`SparseIdMap::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, V> {
+    inner: core::iter::Zip<core::slice::Iter<'a, NameId>, core::slice::Iter<'a, V>>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (NameId, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(id, value)| (*id, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> ExactSizeIterator for Iter<'_, V> {}
+
+/// Iterator over ids and mutable values of a [`SparseIdMap`], returned by
+/// [`SparseIdMap::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, V> {
+    inner: core::iter::Zip<core::slice::Iter<'a, NameId>, core::slice::IterMut<'a, V>>,
+}
+
+impl<'a, V> Iterator for IterMut<'a, V> {
+    type Item = (NameId, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(id, value)| (*id, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> ExactSizeIterator for IterMut<'_, V> {}
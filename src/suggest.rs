@@ -0,0 +1,47 @@
+//! Ranking of names similar to a misspelled one.
+
+use alloc::vec::Vec;
+
+/// Returns up to `max` of `names` that are similar to `input`, most similar
+/// first, as described in [`registry_suggest`](crate::registry_suggest)
+/// documentation.
+pub(crate) fn similar_names<'a>(
+    input: &str,
+    names: impl IntoIterator<Item = &'a str>,
+    max: usize,
+) -> impl Iterator<Item = &'a str> {
+    let input: Vec<char> = input.chars().collect();
+    let threshold = (input.len() / 3).max(2);
+    let mut row = Vec::with_capacity(input.len() + 1);
+    let mut found = Vec::new();
+    for name in names {
+        let prefix = input
+            .iter()
+            .zip(name.chars())
+            .take_while(|(a, b)| *a == b)
+            .count();
+        let distance = edit_distance(&input, name, &mut row);
+        if distance <= threshold || prefix == input.len() {
+            found.push((distance, core::cmp::Reverse(prefix), name));
+        }
+    }
+    found.sort_unstable();
+    found.into_iter().take(max).map(|(_, _, name)| name)
+}
+
+/// Levenshtein distance between `a` and `b` in characters, using `row` as
+/// scratch space.
+fn edit_distance(a: &[char], b: &str, row: &mut Vec<usize>) -> usize {
+    row.clear();
+    row.extend(0..=a.len());
+    for (j, b) in b.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = j + 1;
+        for (i, a) in a.iter().enumerate() {
+            let substitution = diagonal + usize::from(*a != b);
+            diagonal = row[i + 1];
+            row[i + 1] = substitution.min(row[i] + 1).min(diagonal + 1);
+        }
+    }
+    row[a.len()]
+}
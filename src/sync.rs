@@ -0,0 +1,79 @@
+//! Locking abstraction used to guard global state.
+//!
+//! Backend is selected by crate features. When several are enabled, the first
+//! one in the following order is used:
+//! - `std` - [`std::sync::Mutex`], poisoning is ignored,
+//! - `critical-section` - [`critical_section::Mutex`] wrapping a
+//!   [`RefCell`](core::cell::RefCell), for interrupt-free embedded contexts,
+//! - `spin` (_default_) - [`spin::Mutex`].
+
+#[cfg(not(any(feature = "std", feature = "critical-section", feature = "spin")))]
+compile_error!(
+    "global registry requires a locking backend, enable one of \"std\", \"critical-section\" or \"spin\" features"
+);
+
+/// Mutual exclusion primitive that only exposes its contents within a closure.
+///
+/// Closure based access is used because that's the only form supported by
+/// `critical-section`. It also makes it hard to accidentally hold the lock
+/// across user code.
+pub(crate) struct Mutex<T> {
+    #[cfg(feature = "std")]
+    inner: std::sync::Mutex<T>,
+    #[cfg(all(not(feature = "std"), feature = "critical-section"))]
+    inner: critical_section::Mutex<core::cell::RefCell<T>>,
+    #[cfg(all(
+        not(feature = "std"),
+        not(feature = "critical-section"),
+        feature = "spin"
+    ))]
+    inner: spin::Mutex<T>,
+}
+
+impl<T> Mutex<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            inner: std::sync::Mutex::new(value),
+            #[cfg(all(not(feature = "std"), feature = "critical-section"))]
+            inner: critical_section::Mutex::new(core::cell::RefCell::new(value)),
+            #[cfg(all(
+                not(feature = "std"),
+                not(feature = "critical-section"),
+                feature = "spin"
+            ))]
+            inner: spin::Mutex::new(value),
+        }
+    }
+
+    /// Runs `f` with exclusive access to guarded value.
+    ///
+    /// The lock isn't reentrant: calling `with` on the same mutex from within
+    /// `f` will deadlock (`std`, `spin`) or panic (`critical-section`).
+    ///
+    /// A panic inside of `f` releases the lock and doesn't prevent subsequent
+    /// calls from acquiring it.
+    #[inline]
+    pub(crate) fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        #[cfg(feature = "std")]
+        {
+            let mut guard = self
+                .inner
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            f(&mut guard)
+        }
+        #[cfg(all(not(feature = "std"), feature = "critical-section"))]
+        {
+            critical_section::with(|cs| f(&mut self.inner.borrow_ref_mut(cs)))
+        }
+        #[cfg(all(
+            not(feature = "std"),
+            not(feature = "critical-section"),
+            feature = "spin"
+        ))]
+        {
+            f(&mut self.inner.lock())
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Constant lookup table keyed by [`NameId`].
+
+use crate::NameId;
+
+/// Table of `N` values keyed by [`NameId`], stored as an array sorted by hash
+/// value.
+///
+/// Tables can be constructed in constant context (e.g. `static` items) from
+/// ids produced by [`id!`](crate::id) macro, and looked up with a binary
+/// search:
+///
+/// ```
+/// # use name_id::{id, NameIdTable};
+/// static SPEEDS: NameIdTable<f32, 3> = NameIdTable::new([
+///     (id!(walk), 1.0),
+///     (id!(run), 2.5),
+///     (id!(crawl), 0.3),
+/// ]);
+///
+/// const RUN: f32 = *SPEEDS.const_get(id!(run)).unwrap();
+/// assert_eq!(RUN, 2.5);
+/// assert_eq!(SPEEDS.get(id!(walk)), Some(&1.0));
+/// assert_eq!(SPEEDS.get(id!(fly)), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NameIdTable<V, const N: usize> {
+    entries: [(NameId, V); N],
+}
+
+impl<V, const N: usize> NameIdTable<V, N> {
+    /// Creates a table from `entries`, sorting them by hash value.
+    ///
+    /// Sorting is done with an insertion sort, which is fast for the small
+    /// tables this type is meant for and doesn't require comparing values.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if an id appears more than once (a compile
+    /// error in constant context). In release builds only one of the
+    /// duplicate entries can be looked up.
+    pub const fn new(mut entries: [(NameId, V); N]) -> Self {
+        let mut i = 1;
+        while i < N {
+            let mut j = i;
+            while j > 0 && entries[j - 1].0.value() > entries[j].0.value() {
+                entries.swap(j - 1, j);
+                j -= 1;
+            }
+            i += 1;
+        }
+        #[cfg(debug_assertions)]
+        {
+            let mut i = 1;
+            while i < N {
+                assert!(
+                    entries[i - 1].0.value() != entries[i].0.value(),
+                    "NameIdTable contains duplicate ids"
+                );
+                i += 1;
+            }
+        }
+        NameIdTable { entries }
+    }
+
+    /// Returns the value of `id`.
+    ///
+    /// Same as [`get`](NameIdTable::get), but usable in constant context.
+    pub const fn const_get(&self, id: NameId) -> Option<&V> {
+        match self.const_position(id) {
+            Some(i) => Some(&self.entries[i].1),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if the table contains `id`, usable in constant context.
+    pub const fn const_contains_key(&self, id: NameId) -> bool {
+        self.const_position(id).is_some()
+    }
+
+    /// Returns the value of `id`.
+    pub fn get(&self, id: NameId) -> Option<&V> {
+        self.entries
+            .binary_search_by_key(&id.value(), |(id, _)| id.value())
+            .ok()
+            .map(|i| &self.entries[i].1)
+    }
+
+    /// Returns `true` if the table contains `id`.
+    pub fn contains_key(&self, id: NameId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Returns entries sorted by hash value.
+    pub const fn entries(&self) -> &[(NameId, V); N] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the table has no entries.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns an iterator over entries sorted by hash value.
+    pub fn iter(&self) -> core::slice::Iter<'_, (NameId, V)> {
+        self.entries.iter()
+    }
+
+    const fn const_position(&self, id: NameId) -> Option<usize> {
+        let value = id.value();
+        let (mut low, mut high) = (0, N);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let current = self.entries[mid].0.value();
+            if current == value {
+                return Some(mid);
+            } else if current < value {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        None
+    }
+}
+
+impl<'a, V, const N: usize> IntoIterator for &'a NameIdTable<V, N> {
+    type Item = &'a (NameId, V);
+    type IntoIter = core::slice::Iter<'a, (NameId, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,50 @@
+//! Helpers for recording [`NameId`]s as structured [`tracing`](::tracing)
+//! fields.
+//!
+//! `tracing::Value` trait is sealed, so ids are recorded through their `u64`
+//! hash value:
+//!
+//! ```
+//! # use name_id::id;
+//! let asset = id!(player_model);
+//! tracing::info!(asset = asset.as_value(), "loading");
+//! ```
+
+use ::tracing::Span;
+
+use crate::NameId;
+
+impl NameId {
+    /// Returns the hash value, which `tracing` records as `u64` (using
+    /// `record_u64`) instead of a formatted string.
+    #[inline]
+    pub const fn as_value(&self) -> u64 {
+        self.value()
+    }
+}
+
+/// Records `id` hash value into `field` of `span`, and its name into
+/// `{field}.name` field if the name is known.
+///
+/// The name is either the debug label embedded in `id`, or its registered
+/// name. Like with [`Span::record`], both fields must be declared when the
+/// span is created, e.g. `info_span!("load", asset = Empty, asset.name =
+/// Empty)`, otherwise they're ignored.
+pub fn record_named(span: &Span, field: &str, id: NameId) {
+    span.record(field, id.value());
+
+    let Some(name) = id.label() else {
+        return;
+    };
+    let Some(metadata) = span.metadata() else {
+        return;
+    };
+    let name_field = metadata.fields().iter().find(|it| {
+        it.name()
+            .strip_prefix(field)
+            .is_some_and(|rest| rest == ".name")
+    });
+    if let Some(name_field) = name_field {
+        span.record(&name_field, name);
+    }
+}
@@ -0,0 +1,160 @@
+//! Mapping between Rust types and [`NameId`]s.
+
+use alloc::collections::BTreeMap;
+use core::any::TypeId;
+
+use crate::sync::Mutex;
+use crate::NameId;
+
+/// Error returned when a registration conflicts with an existing one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeConflict {
+    /// Id that was being registered.
+    pub id: NameId,
+    /// Different id the type is already registered with.
+    pub registered_id: Option<NameId>,
+    /// Different type `id` is already registered for.
+    pub registered_type: Option<TypeId>,
+}
+
+impl core::fmt::Display for TypeConflict {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.registered_id {
+            Some(registered) => write!(
+                f,
+                "can't register type as {}, it's already registered as {registered}",
+                self.id
+            ),
+            None => write!(f, "{} is already registered for a different type", self.id),
+        }
+    }
+}
+
+impl core::error::Error for TypeConflict {}
+
+struct Entries {
+    ids: BTreeMap<TypeId, NameId>,
+    types: BTreeMap<NameId, TypeId>,
+}
+
+/// Thread-safe two-way mapping between [`TypeId`]s and [`NameId`]s.
+///
+/// `TypeId`s differ between builds, so they can't be stored or sent to other
+/// processes, while ids of type names can:
+///
+/// ```
+/// # use core::any::TypeId;
+/// # use name_id::{id, NameId, TypeRegistry};
+/// struct Health(u32);
+/// struct Mana(u32);
+///
+/// let types = TypeRegistry::new();
+/// let health = types.register::<Health>("component.health")?;
+/// types.register_id::<Mana>(id!("component.mana"))?;
+///
+/// assert_eq!(types.id_of::<Health>(), Some(health));
+/// assert_eq!(types.type_of(id!("component.mana")), Some(TypeId::of::<Mana>()));
+/// assert_eq!(types.type_of(id!("component.stamina")), None);
+/// # Ok::<(), name_id::TypeConflict>(())
+/// ```
+///
+/// Each type can be registered with one id, and each id for one type.
+/// Registering the same pair again succeeds without changing anything,
+/// while registering a type with a different id, or an id for a different
+/// type, fails with [`TypeConflict`].
+///
+/// Registries are independent of each other, and [`global`](TypeRegistry::global)
+/// returns a registry shared by the whole process.
+pub struct TypeRegistry {
+    entries: Mutex<Entries>,
+}
+
+static GLOBAL: TypeRegistry = TypeRegistry::new();
+
+impl TypeRegistry {
+    /// Creates an empty registry. Usable in `static` items.
+    pub const fn new() -> Self {
+        TypeRegistry {
+            entries: Mutex::new(Entries {
+                ids: BTreeMap::new(),
+                types: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// Returns the registry shared by the whole process.
+    pub fn global() -> &'static TypeRegistry {
+        &GLOBAL
+    }
+
+    /// Registers `T` with the id of `name` and returns the id.
+    ///
+    /// The id is constructed with [`NameId::from_str_ref`], so `name` isn't
+    /// stored as its debug label.
+    #[track_caller]
+    pub fn register<T: ?Sized + 'static>(&self, name: &str) -> Result<NameId, TypeConflict> {
+        let id = NameId::from_str_ref(name);
+        self.register_id::<T>(id).map(|_| id)
+    }
+
+    /// Registers `T` with `id`, e.g. one declared with [`id!`](crate::id).
+    pub fn register_id<T: ?Sized + 'static>(&self, id: NameId) -> Result<(), TypeConflict> {
+        let type_id = TypeId::of::<T>();
+        self.entries.with(|entries| {
+            let registered_id = entries.ids.get(&type_id).copied();
+            let registered_type = entries.types.get(&id).copied();
+            if registered_id == Some(id) {
+                return Ok(());
+            }
+            if registered_id.is_some() || registered_type.is_some() {
+                return Err(TypeConflict {
+                    id,
+                    registered_id,
+                    registered_type,
+                });
+            }
+            entries.ids.insert(type_id, id);
+            entries.types.insert(id, type_id);
+            Ok(())
+        })
+    }
+
+    /// Returns the id `T` is registered with.
+    pub fn id_of<T: ?Sized + 'static>(&self) -> Option<NameId> {
+        self.id_of_type(TypeId::of::<T>())
+    }
+
+    /// Returns the id the type with `type_id` is registered with.
+    pub fn id_of_type(&self, type_id: TypeId) -> Option<NameId> {
+        self.entries
+            .with(|entries| entries.ids.get(&type_id).copied())
+    }
+
+    /// Returns the type registered with `id`.
+    pub fn type_of(&self, id: NameId) -> Option<TypeId> {
+        self.entries.with(|entries| entries.types.get(&id).copied())
+    }
+
+    /// Returns the number of registered types.
+    pub fn len(&self) -> usize {
+        self.entries.with(|entries| entries.ids.len())
+    }
+
+    /// Returns `true` if no types are registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for TypeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for TypeRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.entries
+            .with(|entries| f.debug_map().entries(entries.types.iter()).finish())
+    }
+}
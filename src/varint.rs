@@ -0,0 +1,93 @@
+//! LEB128 wire encoding of [`NameId`] hash values.
+
+use crate::NameId;
+
+/// Error returned when decoding a malformed varint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarintError {
+    /// Input ended before the last byte of the varint.
+    Truncated,
+    /// Varint is longer than the shortest encoding of its value, or longer
+    /// than [`NameId::VARINT_MAX_LEN`] bytes.
+    Overlong,
+    /// Varint encodes a value that doesn't fit into 64 bits.
+    Overflow,
+}
+
+impl core::fmt::Display for VarintError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VarintError::Truncated => f.write_str("varint is truncated"),
+            VarintError::Overlong => f.write_str("varint encoding is overlong"),
+            VarintError::Overflow => f.write_str("varint value doesn't fit into 64 bits"),
+        }
+    }
+}
+
+impl core::error::Error for VarintError {}
+
+impl NameId {
+    /// Maximum number of bytes written by [`encode_varint`](NameId::encode_varint).
+    pub const VARINT_MAX_LEN: usize = 10;
+
+    /// Returns the number of bytes [`encode_varint`](NameId::encode_varint)
+    /// writes for this id.
+    pub const fn varint_len(&self) -> usize {
+        let bits = 64 - self.value().leading_zeros() as usize;
+        if bits == 0 {
+            1
+        } else {
+            bits.div_ceil(7)
+        }
+    }
+
+    /// Writes the hash value into `buf` as an unsigned LEB128 varint, and
+    /// returns the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`varint_len`](NameId::varint_len).
+    /// Buffers of [`VARINT_MAX_LEN`](NameId::VARINT_MAX_LEN) bytes always fit.
+    pub fn encode_varint(&self, buf: &mut [u8]) -> usize {
+        let len = self.varint_len();
+        assert!(
+            buf.len() >= len,
+            "varint of {} bytes doesn't fit into a buffer of {} bytes",
+            len,
+            buf.len()
+        );
+        let mut value = self.value();
+        for byte in &mut buf[..len - 1] {
+            *byte = (value as u8 & 0x7f) | 0x80;
+            value >>= 7;
+        }
+        buf[len - 1] = value as u8;
+        len
+    }
+
+    /// Reads an unsigned LEB128 varint from the start of `buf`, and returns
+    /// the id along with the number of bytes read.
+    ///
+    /// Only the shortest encoding of a value is accepted, so each id has a
+    /// single valid encoding.
+    pub fn decode_varint(buf: &[u8]) -> Result<(NameId, usize), VarintError> {
+        let mut value = 0u64;
+        for (i, &byte) in buf.iter().enumerate() {
+            if i == Self::VARINT_MAX_LEN {
+                return Err(VarintError::Overlong);
+            }
+            let bits = (byte & 0x7f) as u64;
+            if i == Self::VARINT_MAX_LEN - 1 && bits > 1 {
+                return Err(VarintError::Overflow);
+            }
+            value |= bits << (7 * i);
+            if byte & 0x80 == 0 {
+                if byte == 0 && i > 0 {
+                    return Err(VarintError::Overlong);
+                }
+                return Ok((NameId::from_value(value), i + 1));
+            }
+        }
+        Err(VarintError::Truncated)
+    }
+}
@@ -0,0 +1,207 @@
+//! Newtype wrappers of [`NameId`](crate::NameId) declared with
+//! [`id_wrapper!`](crate::id_wrapper).
+//!
+//! Trait implementations that depend on features of this crate are expanded
+//! by helper macros defined here, so that they follow features enabled for
+//! `name-id` rather than the crate invoking `id_wrapper!`.
+
+/// Constructs an id from `name` with `prefix` prepended to it.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[track_caller]
+pub fn __prefixed_id(prefix: &str, name: &str) -> crate::NameId {
+    let mut joined = alloc::string::String::with_capacity(prefix.len() + name.len());
+    joined.push_str(prefix);
+    joined.push_str(name);
+    crate::from_borrowed_name(&joined)
+}
+
+/// Declares a newtype wrapper of [`NameId`](crate::NameId).
+///
+/// Wrappers of different types can't be compared or mixed with each other,
+/// which prevents passing e.g. a sound id where a texture id is expected:
+///
+/// ```
+/// name_id::id_wrapper! {
+///     /// Id of a texture asset.
+///     pub struct TextureId(prefix = "tex.");
+/// }
+/// name_id::id_wrapper!(pub struct SoundId;);
+///
+/// const WOOD: TextureId = TextureId::new(name_id::id!("tex.wood"));
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(TextureId::named("wood"), WOOD);
+/// ```
+///
+/// The wrapper is `#[repr(transparent)]`, and has:
+/// - `const fn new(NameId)`, `const fn from_raw(u64)`, `const fn id(&self)`
+///   and `const fn value(&self)`,
+/// - `fn named(&str)` which constructs it from a name with the optional
+///   `prefix` prepended (with `alloc` feature), leaking each distinct joined
+///   name once if a registry is enabled,
+/// - `From` conversions to and from `NameId`, and `AsRef<NameId>`,
+/// - `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`
+///   implementations, and `Display` and `Debug` forwarded to `NameId`,
+/// - `serde` implementations forwarded to `NameId` with `serde` feature,
+/// - `bytemuck::Pod`, `bytemuck::Zeroable` and
+///   `bytemuck::TransparentWrapper<NameId>` with `bytemuck` feature, in
+///   configurations where `NameId` implements them.
+///
+/// Attributes (including doc comments) are forwarded to the struct.
+#[macro_export]
+macro_rules! id_wrapper {
+    ($(#[$attr: meta])* $vis: vis struct $name: ident;) => {
+        $crate::id_wrapper!(@struct $(#[$attr])* $vis $name, "");
+    };
+    ($(#[$attr: meta])* $vis: vis struct $name: ident(prefix = $prefix: literal);) => {
+        $crate::id_wrapper!(@struct $(#[$attr])* $vis $name, $prefix);
+    };
+    (@struct $(#[$attr: meta])* $vis: vis $name: ident, $prefix: literal) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($crate::NameId);
+
+        impl $name {
+            /// Prefix prepended to names passed to `named`.
+            pub const PREFIX: &'static str = $prefix;
+
+            /// Wraps `id`.
+            pub const fn new(id: $crate::NameId) -> Self {
+                $name(id)
+            }
+
+            /// Constructs a wrapper of an id with the given hash value.
+            pub const fn from_raw(value: u64) -> Self {
+                $name($crate::NameId::from_value(value))
+            }
+
+            /// Returns the wrapped id.
+            pub const fn id(&self) -> $crate::NameId {
+                self.0
+            }
+
+            /// Returns the hash value of the wrapped id.
+            pub const fn value(&self) -> u64 {
+                self.0.value()
+            }
+        }
+
+        $crate::__id_wrapper_named!($name);
+        $crate::__id_wrapper_serde!($name);
+        $crate::__id_wrapper_bytemuck!($name);
+
+        impl ::core::convert::From<$crate::NameId> for $name {
+            fn from(id: $crate::NameId) -> Self {
+                $name(id)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::NameId {
+            fn from(wrapper: $name) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl ::core::convert::AsRef<$crate::NameId> for $name {
+            fn as_ref(&self) -> &$crate::NameId {
+                &self.0
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __id_wrapper_named {
+    ($name: ident) => {
+        impl $name {
+            /// Constructs a wrapper from `name`, with `PREFIX` prepended to
+            /// it.
+            ///
+            /// With a registry, the first call with each distinct name leaks
+            /// a copy of the joined name, which is registered and used as the
+            /// debug label. Without one, nothing is leaked and no label is
+            /// stored.
+            #[track_caller]
+            pub fn named(name: &str) -> Self {
+                $name($crate::__prefixed_id(Self::PREFIX, name))
+            }
+        }
+    };
+}
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __id_wrapper_named {
+    ($name: ident) => {};
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __id_wrapper_serde {
+    ($name: ident) => {
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S: $crate::__serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::__serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                <$crate::NameId as $crate::__serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map($name)
+            }
+        }
+    };
+}
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __id_wrapper_serde {
+    ($name: ident) => {};
+}
+
+#[cfg(all(
+    feature = "bytemuck",
+    not(all(debug_assertions, feature = "debug_name"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __id_wrapper_bytemuck {
+    ($name: ident) => {
+        // SAFETY: the wrapper is `repr(transparent)` over `NameId`, which
+        // implements these traits.
+        unsafe impl $crate::__bytemuck::Zeroable for $name {}
+        unsafe impl $crate::__bytemuck::Pod for $name {}
+        unsafe impl $crate::__bytemuck::TransparentWrapper<$crate::NameId> for $name {}
+    };
+}
+#[cfg(not(all(
+    feature = "bytemuck",
+    not(all(debug_assertions, feature = "debug_name"))
+)))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __id_wrapper_bytemuck {
+    ($name: ident) => {};
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "adt_const_params")]
+#![feature(adt_const_params)]
+
+use name_id::{id, NameId};
+
+struct Channel<const ID: NameId>;
+
+impl<const ID: NameId> Channel<ID> {
+    fn id(&self) -> NameId {
+        ID
+    }
+}
+
+trait Route {
+    const NAME: &'static str;
+}
+
+impl Route for Channel<{ id!(foo) }> {
+    const NAME: &'static str = "foo";
+}
+
+impl Route for Channel<{ id!(bar) }> {
+    const NAME: &'static str = "bar";
+}
+
+#[test]
+fn const_parameter() {
+    let channel = Channel::<{ id!(foo) }>;
+    assert_eq!(channel.id(), id!(foo));
+    assert_eq!(<Channel<{ id!(foo) }> as Route>::NAME, "foo");
+    assert_eq!(<Channel<{ id!(bar) }> as Route>::NAME, "bar");
+}
+
+#[test]
+fn equality_unchanged() {
+    assert_eq!(id!(foo), NameId::from_value(id!(foo).value()));
+    assert_ne!(id!(foo), id!(bar));
+}
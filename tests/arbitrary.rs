@@ -0,0 +1,32 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use name_id::{id, NameId};
+
+#[test]
+fn raw_value() {
+    let value = 0x0123_4567_89ab_cdefu64;
+    let bytes = value.to_le_bytes();
+    let mut u = Unstructured::new(&bytes);
+    assert_eq!(
+        NameId::arbitrary(&mut u).unwrap(),
+        NameId::from_value(value)
+    );
+    assert!(u.is_empty());
+
+    assert_eq!(NameId::size_hint(0), (8, Some(8)));
+}
+
+#[test]
+fn named() {
+    // exhausted input produces shortest name made of the first character
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(NameId::arbitrary_named(&mut u).unwrap(), id!(a));
+
+    let data: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&data);
+    while !u.is_empty() {
+        let id = NameId::arbitrary_named(&mut u).unwrap();
+        assert_ne!(id, NameId::from_value(0));
+    }
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "arrayvec")]
+
+use arrayvec::ArrayString;
+use name_id::NameId;
+
+fn check<const CAP: usize>(name: &'static str) {
+    let string = ArrayString::<CAP>::from(name).unwrap();
+    assert_eq!(NameId::from(&string).value(), NameId::new(name).value());
+}
+
+#[test]
+fn parity_with_str() {
+    check::<16>("player_spawn");
+    check::<16>("");
+    check::<32>("player_spawn");
+    check::<64>("a name that doesn't fit into 32 bytes");
+}
+
+#[test]
+fn built_in_place() {
+    use core::fmt::Write;
+    let mut name = ArrayString::<32>::new();
+    write!(name, "bone_{}", 7).unwrap();
+    assert_eq!(NameId::from(&name).value(), NameId::new("bone_7").value());
+}
@@ -0,0 +1,64 @@
+#![cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+
+use std::sync::atomic::Ordering;
+
+use name_id::{id, AtomicNameId, NameId};
+
+#[test]
+fn load_store() {
+    let atomic = AtomicNameId::new(id!(main_menu));
+    assert_eq!(atomic.load(Ordering::Acquire), id!(main_menu));
+    atomic.store(id!(settings), Ordering::Release);
+    assert_eq!(atomic.load(Ordering::Acquire), id!(settings));
+    assert_eq!(atomic.swap(id!(credits), Ordering::AcqRel), id!(settings));
+    assert_eq!(atomic.into_inner(), id!(credits));
+}
+
+#[test]
+fn compare_exchange() {
+    let atomic = AtomicNameId::new(id!(main_menu));
+    let exchanged = atomic.compare_exchange(
+        id!(main_menu),
+        id!(settings),
+        Ordering::AcqRel,
+        Ordering::Acquire,
+    );
+    assert_eq!(exchanged, Ok(id!(main_menu)));
+    let failed = atomic.compare_exchange(
+        id!(main_menu),
+        id!(credits),
+        Ordering::AcqRel,
+        Ordering::Acquire,
+    );
+    assert_eq!(failed, Err(id!(settings)));
+    assert_eq!(atomic.load(Ordering::Relaxed), id!(settings));
+
+    let mut current = atomic.load(Ordering::Relaxed);
+    while let Err(actual) =
+        atomic.compare_exchange_weak(current, id!(credits), Ordering::AcqRel, Ordering::Relaxed)
+    {
+        current = actual;
+    }
+    assert_eq!(atomic.load(Ordering::Relaxed), id!(credits));
+}
+
+#[test]
+fn shared_between_threads() {
+    static CURRENT: AtomicNameId = AtomicNameId::new(NameId::from_value(0));
+    std::thread::scope(|scope| {
+        for i in 1..=4 {
+            scope.spawn(move || CURRENT.store(NameId::from_value(i), Ordering::Relaxed));
+        }
+    });
+    assert!((1..=4).contains(&CURRENT.load(Ordering::Relaxed).value()));
+}
+
+#[test]
+fn default_and_debug() {
+    assert_eq!(AtomicNameId::default().into_inner(), NameId::from_value(0));
+    let atomic = AtomicNameId::from(NameId::from_value(42));
+    assert_eq!(
+        format!("{atomic:?}"),
+        format!("{:?}", NameId::from_value(42))
+    );
+}
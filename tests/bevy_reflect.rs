@@ -0,0 +1,55 @@
+#![cfg(feature = "bevy_reflect")]
+
+use bevy_reflect::serde::{ReflectDeserializer, ReflectSerializer};
+use bevy_reflect::{FromReflect, PartialReflect, Reflect, TypePath, TypeRegistry};
+use name_id::{id, NameId};
+use serde::de::DeserializeSeed;
+
+#[derive(Debug, PartialEq, Reflect)]
+struct Spawner {
+    prefab: NameId,
+    count: u32,
+}
+
+fn registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::default();
+    registry.register::<NameId>();
+    registry.register::<Spawner>();
+    registry
+}
+
+#[test]
+fn type_path() {
+    assert_eq!(NameId::type_path(), "name_id::NameId");
+    assert_eq!(NameId::short_type_path(), "NameId");
+}
+
+#[test]
+fn opaque_hooks() {
+    let id = id!(scene_prefab);
+    let reflected: &dyn PartialReflect = &id;
+    assert_eq!(reflected.reflect_partial_eq(&id), Some(true));
+    assert_eq!(reflected.reflect_partial_eq(&id!(scene_other)), Some(false));
+    assert_eq!(reflected.reflect_hash(), reflected.reflect_hash());
+    assert_eq!(format!("{:?}", reflected), format!("{:?}", id));
+    assert_eq!(NameId::from_reflect(reflected), Some(id));
+}
+
+#[test]
+fn scene_round_trip() {
+    let registry = registry();
+    let spawner = Spawner {
+        prefab: id!(scene_prefab),
+        count: 3,
+    };
+
+    let serializer = ReflectSerializer::new(&spawner, &registry);
+    let json = serde_json::to_string(&serializer).unwrap();
+    assert!(json.contains(&id!(scene_prefab).value().to_string()));
+
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let reflected = ReflectDeserializer::new(&registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(Spawner::from_reflect(&*reflected), Some(spawner));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "bincode")]
+
+use bincode::{Decode, Encode};
+use name_id::{id, NameId};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Event {
+    kind: NameId,
+    target: NameId,
+    count: u32,
+}
+
+#[test]
+fn standard_round_trip() {
+    let config = bincode::config::standard();
+    let id = id!(player_spawn);
+    let bytes = bincode::encode_to_vec(id, config).unwrap();
+    // varint encoding of the hash
+    assert_eq!(bytes, bincode::encode_to_vec(id.value(), config).unwrap());
+    let (decoded, read): (NameId, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(decoded, id);
+    assert_eq!(read, bytes.len());
+}
+
+#[test]
+fn legacy_round_trip() {
+    let config = bincode::config::legacy();
+    let id = id!(player_spawn);
+    let bytes = bincode::encode_to_vec(id, config).unwrap();
+    assert_eq!(bytes, id.value().to_le_bytes());
+    let (decoded, _): (NameId, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(decoded, id);
+}
+
+#[test]
+fn derived_struct() {
+    let event = Event {
+        kind: id!(damage),
+        target: NameId::from_value(7),
+        count: 3,
+    };
+    let config = bincode::config::standard();
+    let bytes = bincode::encode_to_vec(&event, config).unwrap();
+    let (decoded, _): (Event, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(decoded, event);
+    let (borrowed, _): (Event, _) = bincode::borrow_decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(borrowed, event);
+}
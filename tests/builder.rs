@@ -0,0 +1,156 @@
+use core::fmt::Write;
+
+use name_id::{BufferTooSmall, NameId, NameIdBuilder};
+
+const NAMES: [&str; 4] = [
+    "",
+    "textures/grass.png",
+    "ünïcødé ✓ names",
+    "a somewhat longer name spanning several hasher blocks",
+];
+
+#[test]
+fn split_str_matches_new() {
+    for name in NAMES {
+        for split in (0..=name.len()).filter(|i| name.is_char_boundary(*i)) {
+            let mut builder = NameIdBuilder::<64>::new();
+            builder.push_str(&name[..split]).unwrap();
+            builder.push_str(&name[split..]).unwrap();
+            assert_eq!(
+                builder.finish(),
+                NameId::new(name),
+                "{name:?} split at {split}"
+            );
+        }
+    }
+}
+
+#[test]
+fn split_bytes_matches_new() {
+    for name in NAMES {
+        let bytes = name.as_bytes();
+        // including splits inside of multi-byte characters
+        for first in 0..=bytes.len() {
+            for second in first..=bytes.len() {
+                let mut builder = NameIdBuilder::<64>::new();
+                builder
+                    .push_bytes(&bytes[..first])
+                    .unwrap()
+                    .push_bytes(&bytes[first..second])
+                    .unwrap()
+                    .push_bytes(&bytes[second..])
+                    .unwrap();
+                assert_eq!(builder.finish(), NameId::new(name));
+            }
+        }
+    }
+}
+
+#[test]
+fn chars_and_write() {
+    let mut builder = NameIdBuilder::<32>::default();
+    for c in "ünï".chars() {
+        builder.push_char(c).unwrap();
+    }
+    let extension = "png";
+    write!(builder, "/{}.{extension}", 42).unwrap();
+    assert_eq!(builder.len(), "ünï/42.png".len());
+    assert_eq!(builder.finish(), NameId::new("ünï/42.png"));
+}
+
+#[test]
+fn overflow() {
+    let mut builder = NameIdBuilder::<4>::new();
+    builder.push_str("abc").unwrap();
+    assert_eq!(builder.push_str("de").err(), Some(BufferTooSmall));
+    assert!(write!(builder, "de").is_err());
+    // failed pushes don't modify the name
+    builder.push_char('d').unwrap();
+    assert_eq!(builder.finish(), NameId::new("abcd"));
+}
+
+#[test]
+fn empty() {
+    let builder = NameIdBuilder::<0>::new();
+    assert!(builder.is_empty());
+    assert_eq!(builder.finish(), NameId::new(""));
+}
+
+#[test]
+fn segments_match_new() {
+    let cases: [&[&str]; 6] = [
+        &[],
+        &[""],
+        &["", "", ""],
+        &["textures/", "grass", ".png"],
+        &["ünï", "", "cødé ✓", " names"],
+        &["a somewhat longer name ", "spanning several hasher blocks"],
+    ];
+    for parts in cases {
+        let concatenated = NameId::from_str_ref(&parts.concat());
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            NameId::from_segments(parts.iter().copied()),
+            concatenated,
+            "{parts:?}"
+        );
+        assert_eq!(
+            NameId::try_from_segments(parts.iter().copied()),
+            Ok(concatenated),
+            "{parts:?}"
+        );
+        for separator in ["", ".", "::", " → ", "✓✓"] {
+            let joined = NameId::from_str_ref(&parts.join(separator));
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                NameId::from_joined(parts.iter().copied(), separator),
+                joined,
+                "{parts:?} joined with {separator:?}"
+            );
+            assert_eq!(
+                NameId::try_from_joined(parts.iter().copied(), separator),
+                Ok(joined),
+                "{parts:?} joined with {separator:?}"
+            );
+        }
+    }
+}
+
+// longer names are only supported with `alloc`
+#[cfg(feature = "alloc")]
+#[test]
+fn segments_longer_than_inline_buffer() {
+    let part = "0123456789abcdef".repeat(10);
+    for count in [1, 2, 3, 17] {
+        let parts = vec![part.as_str(); count];
+        assert_eq!(
+            NameId::from_segments(parts.iter().copied()),
+            NameId::from_str_ref(&parts.concat())
+        );
+        assert_eq!(
+            NameId::from_joined(parts.iter().copied(), "/ü/"),
+            NameId::from_str_ref(&parts.join("/ü/"))
+        );
+    }
+}
+
+#[test]
+fn try_segments_limit() {
+    let name = "ü".repeat(128);
+    assert_eq!(
+        NameId::try_from_segments([name.as_str()]),
+        Ok(NameId::from_str_ref(&name))
+    );
+    assert_eq!(
+        NameId::try_from_segments([name.as_str(), "x"]),
+        Err(BufferTooSmall)
+    );
+    assert_eq!(
+        NameId::try_from_joined(["", ""], &name),
+        Ok(NameId::from_str_ref(&name))
+    );
+    assert_eq!(
+        NameId::try_from_joined(["", "", ""], &name),
+        Err(BufferTooSmall)
+    );
+}
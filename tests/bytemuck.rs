@@ -0,0 +1,27 @@
+#![cfg(all(feature = "bytemuck", not(feature = "fixed_size")))]
+
+use name_id::{id, NameId};
+
+#[test]
+fn cast_slices() {
+    let ids = [id!(vertex_a), id!(vertex_b), id!(vertex_c)];
+
+    let values: &[u64] = bytemuck::cast_slice(&ids);
+    assert_eq!(values, ids.map(|id| id.value()));
+    let bytes: &[u8] = bytemuck::cast_slice(&ids);
+    assert_eq!(bytes.len(), 3 * NameId::SIZE);
+
+    let back: &[NameId] = bytemuck::cast_slice(values);
+    assert_eq!(back, ids);
+}
+
+#[test]
+fn cast_values() {
+    let id = id!(vertex_a);
+    assert_eq!(bytemuck::cast::<NameId, u64>(id), id.value());
+    assert_eq!(bytemuck::cast::<u64, NameId>(id.value()), id);
+    assert_eq!(
+        <NameId as bytemuck::Zeroable>::zeroed(),
+        NameId::from_value(0)
+    );
+}
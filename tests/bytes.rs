@@ -0,0 +1,106 @@
+use name_id::{id, NameId, NonZeroPadding};
+
+#[test]
+fn round_trip() {
+    for id in [
+        id!(player_spawn),
+        NameId::from_value(0),
+        NameId::from_value(u64::MAX),
+        NameId::from_value(1 << 63),
+    ] {
+        let bytes = id.as_bytes();
+        assert_eq!(bytes.len(), NameId::SIZE);
+        assert_eq!(bytes[..8], id.value().to_le_bytes());
+        assert!(bytes[8..].iter().all(|it| *it == 0));
+        assert_eq!(NameId::from_bytes(bytes), Ok(id));
+    }
+}
+
+#[test]
+fn rejects_non_zero_padding() {
+    let mut bytes = id!(player_spawn).as_bytes();
+    if NameId::SIZE > 8 {
+        bytes[NameId::SIZE - 1] = 1;
+        assert_eq!(NameId::from_bytes(bytes), Err(NonZeroPadding));
+    }
+}
+
+#[test]
+fn const_round_trip() {
+    const BYTES: [u8; NameId::SIZE] = id!(player_spawn).as_bytes();
+    const ID: NameId = match NameId::from_bytes(BYTES) {
+        Ok(id) => id,
+        Err(_) => panic!("invalid bytes"),
+    };
+    assert_eq!(ID, id!(player_spawn));
+}
+
+/// Reads every byte of in-memory representation, which Miri reports if any of
+/// them are uninitialized.
+#[cfg(not(all(debug_assertions, feature = "debug_name")))]
+#[test]
+fn memory_is_initialized() {
+    fn memory(id: &NameId) -> [u8; NameId::SIZE] {
+        let mut result = [0; NameId::SIZE];
+        // SAFETY: NameId is SIZE bytes long and only has integer fields
+        // without a debug label.
+        let bytes =
+            unsafe { core::slice::from_raw_parts(id as *const NameId as *const u8, NameId::SIZE) };
+        result.copy_from_slice(bytes);
+        result
+    }
+
+    let ids = [
+        id!(player_spawn),
+        NameId::from_value(42),
+        NameId::new("player_spawn"),
+        NameId::from_bytes(NameId::from_value(7).as_bytes()).unwrap(),
+    ];
+    for id in ids {
+        let copy = id;
+        let memory = memory(&copy);
+        assert_eq!(memory[..8], id.value().to_ne_bytes());
+        // fixed_size padding is always zeroed
+        assert!(memory[8..].iter().all(|it| *it == 0));
+    }
+}
+
+#[test]
+fn ordered_round_trip() {
+    for id in [
+        id!(player_spawn),
+        NameId::from_value(0),
+        NameId::from_value(u64::MAX),
+        NameId::from_value(0x0102),
+    ] {
+        let bytes = id.to_ordered_bytes();
+        assert_eq!(bytes, id.value().to_be_bytes());
+        assert_eq!(NameId::from_ordered_bytes(bytes), id);
+
+        let key = id.ordered_key_prefixed(7);
+        assert_eq!(key[0], 7);
+        assert_eq!(key[1..], bytes);
+    }
+}
+
+#[test]
+fn prefixed_keys_are_grouped() {
+    let ids = [NameId::from_value(0), NameId::from_value(u64::MAX)];
+    let mut keys: Vec<_> = ids
+        .iter()
+        .flat_map(|id| [id.ordered_key_prefixed(2), id.ordered_key_prefixed(1)])
+        .collect();
+    keys.sort();
+    let prefixes: Vec<_> = keys.iter().map(|key| key[0]).collect();
+    assert_eq!(prefixes, [1, 1, 2, 2]);
+    assert_eq!(keys[0][1..], ids[0].to_ordered_bytes());
+    assert_eq!(keys[1][1..], ids[1].to_ordered_bytes());
+}
+
+quickcheck::quickcheck! {
+    fn ordered_bytes_preserve_order(a: u64, b: u64) -> bool {
+        let (a, b) = (NameId::from_value(a), NameId::from_value(b));
+        a.to_ordered_bytes().cmp(&b.to_ordered_bytes()) == a.const_cmp(&b)
+            && a.ordered_key_prefixed(3).cmp(&b.ordered_key_prefixed(3)) == a.const_cmp(&b)
+    }
+}
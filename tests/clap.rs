@@ -0,0 +1,103 @@
+#![cfg(feature = "clap")]
+
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, Command};
+use name_id::clap::NameIdValueParser;
+use name_id::NameId;
+
+const ALL: &[&str] = &["player_spawn", "player_death", "enemy_spawn"];
+
+fn command(parser: NameIdValueParser) -> Command {
+    Command::new("debug").arg(Arg::new("watch-id").long("watch-id").value_parser(parser))
+}
+
+fn parse(parser: NameIdValueParser, value: &str) -> Result<NameId, clap::Error> {
+    let matches = command(parser).try_get_matches_from(["debug", "--watch-id", value])?;
+    Ok(*matches.get_one::<NameId>("watch-id").unwrap())
+}
+
+#[test]
+fn name() {
+    let id = parse(NameIdValueParser::new(), "player_spawn").unwrap();
+    assert_eq!(id, NameId::new("player_spawn"));
+}
+
+#[test]
+fn decimal() {
+    let id = parse(NameIdValueParser::new(), "6699").unwrap();
+    assert_eq!(id, NameId::from_value(6699));
+}
+
+#[test]
+fn hex() {
+    let id = parse(NameIdValueParser::new(), "0x1a2b").unwrap();
+    assert_eq!(id, NameId::from_value(0x1a2b));
+}
+
+#[test]
+fn invalid_hex() {
+    let err = parse(NameIdValueParser::new(), "0x1g").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    assert!(err.to_string().contains("hexadecimal"));
+}
+
+#[test]
+fn value_parser_factory() {
+    let matches = Command::new("debug")
+        .arg(
+            Arg::new("watch-id")
+                .long("watch-id")
+                .value_parser(value_parser!(NameId)),
+        )
+        .try_get_matches_from(["debug", "--watch-id", "player_spawn"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<NameId>("watch-id"),
+        Some(&NameId::new("player_spawn"))
+    );
+}
+
+#[test]
+fn strict_names() {
+    let parser = NameIdValueParser::strict().with_names(ALL);
+    assert_eq!(
+        parse(parser, "enemy_spawn").unwrap(),
+        NameId::new("enemy_spawn")
+    );
+    assert_eq!(parse(parser, "0x1a2b").unwrap(), NameId::from_value(0x1a2b));
+
+    let err = parse(parser, "player_spwan").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    let message = err.to_string();
+    assert!(
+        message.contains("unknown name, did you mean `player_spawn`"),
+        "{message}"
+    );
+    assert!(!message.contains("enemy_spawn"), "{message}");
+}
+
+#[test]
+fn possible_values() {
+    let parser = NameIdValueParser::new().with_names(ALL);
+    let command = command(parser);
+    let arg = command.get_arguments().next().unwrap();
+    let values: Vec<_> = arg
+        .get_possible_values()
+        .iter()
+        .map(|value| value.get_name().to_owned())
+        .collect();
+    assert_eq!(values, ALL);
+    // names outside of the table are still accepted
+    assert_eq!(parse(parser, "door").unwrap(), NameId::new("door"));
+}
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+#[test]
+fn strict_registry() {
+    let registered = NameId::new(String::from("clap_registered_door"));
+    let parser = NameIdValueParser::strict();
+    assert_eq!(parse(parser, "clap_registered_door").unwrap(), registered);
+
+    let err = parse(parser, "clap_registered_dor").unwrap_err();
+    assert!(err.to_string().contains("`clap_registered_door`"), "{err}");
+}
@@ -0,0 +1,62 @@
+//! Generated C++ isn't compiled here, but its algorithm constants and
+//! precomputed values are compared with the Rust implementation.
+#![cfg(all(feature = "portable_hash", not(feature = "dynamic_hasher")))]
+
+use name_id::codegen::write_cpp_header;
+use name_id::NameId;
+
+fn header(names: &[&str]) -> String {
+    let mut header = String::new();
+    write_cpp_header(&mut header, names).unwrap();
+    header
+}
+
+#[test]
+fn algorithm_constants() {
+    let header = header(&[]);
+    for constant in [
+        "MULTIPLE = UINT64_C(0x5851f42d4c957f2d)",
+        "KEY0 = UINT64_C(0x243f6a8885a308d3)",
+        "KEY1 = UINT64_C(0x13198a2e03707344)",
+        "KEY2 = UINT64_C(0xa4093822299f31d0)",
+        "KEY3 = UINT64_C(0x082efa98ec4e6c89)",
+        "ROT = 23;",
+        "constexpr uint64_t name_id_hash(const char* name, size_t len)",
+    ] {
+        assert!(header.contains(constant), "missing {constant:?}");
+    }
+    assert!(header.contains(&format!(
+        "#define NAME_ID_ALGORITHM_FINGERPRINT UINT64_C({:#018x})",
+        name_id::ALGORITHM_FINGERPRINT
+    )));
+}
+
+#[test]
+fn precomputed_values() {
+    let header = header(&["", "a", "player_spawn", "abcdefghijklmnopq"]);
+    for (name, line) in [
+        ("", "#define NAME_ID_ UINT64_C(0xa72fb095a0dc47c9)"),
+        ("a", "#define NAME_ID_A UINT64_C(0xb8cf850d279745b1)"),
+        (
+            "abcdefghijklmnopq",
+            "#define NAME_ID_ABCDEFGHIJKLMNOPQ UINT64_C(0x25e4a83cf1413683)",
+        ),
+    ] {
+        assert!(header.contains(line), "missing value of {name:?}");
+    }
+    assert!(header.contains(&format!(
+        "#define NAME_ID_PLAYER_SPAWN UINT64_C({:#018x})\n\
+         static_assert(name_id_hash(\"player_spawn\", 12) == NAME_ID_PLAYER_SPAWN, \"name-id hash mismatch\");",
+        NameId::new("player_spawn").value()
+    )));
+}
+
+#[test]
+fn escaped_names() {
+    let header = header(&["door \"A\"/ü"]);
+    assert!(header.contains(&format!(
+        "#define NAME_ID_DOOR__A___ UINT64_C({:#018x})",
+        NameId::new("door \"A\"/ü").value()
+    )));
+    assert!(header.contains(r#"name_id_hash("door \"A\"/\303\274", 11)"#));
+}
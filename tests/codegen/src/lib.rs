@@ -0,0 +1,30 @@
+//! Functions whose generated code is inspected by `tests/ir.rs`.
+//!
+//! Each function compares with a different id, so LLVM doesn't merge them.
+
+use name_id::{id, id_value, NameId};
+
+#[no_mangle]
+pub fn eq_param(id: NameId) -> bool {
+    id == id!(jump)
+}
+
+#[no_mangle]
+pub fn eq_param_reversed(id: NameId) -> bool {
+    id!(walk) == id
+}
+
+#[no_mangle]
+pub fn const_eq_param(id: NameId) -> bool {
+    id.const_eq(&id!(run))
+}
+
+#[no_mangle]
+pub fn ne_param(id: NameId) -> bool {
+    id != id!(crawl)
+}
+
+#[no_mangle]
+pub fn match_value(id: NameId) -> bool {
+    matches!(id.value(), id_value!(swim))
+}
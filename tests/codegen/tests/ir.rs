@@ -0,0 +1,86 @@
+// Optimizations are only guaranteed in release builds.
+#![cfg(not(debug_assertions))]
+
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Compiles the library to LLVM IR and returns its contents.
+fn emit_ir() -> String {
+    let target_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("ir");
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--release", "--lib", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .args(["--", "--emit=llvm-ir", "-Ccodegen-units=1"])
+        .status()
+        .expect("can't run cargo");
+    assert!(status.success(), "compiling to LLVM IR failed");
+
+    let deps = target_dir.join("release/deps");
+    let ir = std::fs::read_dir(&deps)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("codegen-") && name.ends_with(".ll")
+        })
+        .expect("LLVM IR file not found");
+    std::fs::read_to_string(ir).unwrap()
+}
+
+/// Returns instructions of function `name` in `ir`.
+fn function_body<'a>(ir: &'a str, name: &str) -> Vec<&'a str> {
+    let start = ir
+        .lines()
+        .position(|line| line.starts_with("define") && line.contains(&format!("@{name}(")))
+        .unwrap_or_else(|| panic!("function `{name}` not found"));
+    ir.lines()
+        .skip(start + 1)
+        .take_while(|line| *line != "}")
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.ends_with(':'))
+        .collect()
+}
+
+/// Checks that function `name` takes the id as an `i64` and compares it with
+/// an immediate value, without loads, stores or calls.
+fn assert_single_compare(ir: &str, name: &str) {
+    let definition = ir
+        .lines()
+        .find(|line| line.starts_with("define") && line.contains(&format!("@{name}(")))
+        .unwrap();
+    assert!(
+        definition.contains("(i64"),
+        "`{name}` doesn't take the id as i64: {definition}"
+    );
+
+    let body = function_body(ir, name);
+    let compares: Vec<_> = body.iter().filter(|it| it.contains("icmp")).collect();
+    assert_eq!(compares.len(), 1, "`{name}` doesn't compare once: {body:#?}");
+    let immediate = compares[0]
+        .rsplit(", ")
+        .next()
+        .is_some_and(|it| it.parse::<i64>().is_ok());
+    assert!(immediate, "`{name}` doesn't compare with an immediate: {body:#?}");
+    for instruction in ["load", "store", "call", "alloca"] {
+        assert!(
+            !body.iter().any(|it| it.split_whitespace().any(|w| w == instruction)),
+            "`{name}` contains `{instruction}`: {body:#?}"
+        );
+    }
+}
+
+#[test]
+fn compares_are_immediate() {
+    let ir = emit_ir();
+    for name in [
+        "eq_param",
+        "eq_param_reversed",
+        "const_eq_param",
+        "ne_param",
+        "match_value",
+    ] {
+        assert_single_compare(&ir, name);
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "collections")]
+
+use name_id::collections::Entry;
+use name_id::{id, IdMap, IdSet, NameId};
+
+#[test]
+fn insert_and_get() {
+    let mut map = IdMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.insert(id!(player), 1), None);
+    assert_eq!(map.insert(id!(enemy), 2), None);
+    assert_eq!(map.insert(id!(player), 3), Some(1));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&id!(player)), Some(&3));
+    assert_eq!(map[&id!(enemy)], 2);
+    assert_eq!(map[id!(enemy)], 2);
+    assert_eq!(map.get(&id!(missing)), None);
+    assert_eq!(map.remove(&id!(enemy)), Some(2));
+    assert!(!map.contains_key(&id!(enemy)));
+}
+
+#[test]
+fn with_capacity() {
+    let map: IdMap<u8> = IdMap::with_capacity(100);
+    assert!(map.capacity() >= 100);
+    let set = IdSet::with_capacity(100);
+    assert!(set.capacity() >= 100);
+}
+
+#[test]
+fn entry() {
+    let mut counts: IdMap<u32> = IdMap::default();
+    for name in ["a", "b", "a", "c", "a"] {
+        *counts.entry(NameId::new(name)).or_default() += 1;
+    }
+    assert_eq!(counts[&id!(a)], 3);
+    assert_eq!(counts[&id!(b)], 1);
+
+    match counts.entry(id!(b)) {
+        Entry::Occupied(entry) => {
+            assert_eq!(entry.remove(), 1);
+        }
+        Entry::Vacant(_) => panic!("entry should be occupied"),
+    }
+    match counts.entry(id!(b)) {
+        Entry::Occupied(_) => panic!("entry should be vacant"),
+        Entry::Vacant(entry) => {
+            entry.insert(10);
+        }
+    }
+    assert_eq!(counts[&id!(b)], 10);
+}
+
+#[test]
+fn collect_ids() {
+    let map: IdMap<u32> = [(id!(a), 1), (id!(b), 2)].into_iter().collect();
+    assert_eq!(map[&id!(a)], 1);
+    let set: IdSet = [id!(a), id!(b), id!(a)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&id!(b)));
+}
+
+#[test]
+fn collect_names() {
+    let name = String::from("dynamic_name");
+    let map: IdMap<u32> = [("a", 1), (name.as_str(), 2)].into_iter().collect();
+    assert_eq!(map[&id!(a)], 1);
+    assert_eq!(map[&id!(dynamic_name)], 2);
+    let set: IdSet = ["a", name.as_str()].into_iter().collect();
+    assert!(set.contains(&id!(a)));
+    assert!(set.contains(&id!(dynamic_name)));
+}
+
+#[test]
+fn iteration_and_extend() {
+    let mut map: IdMap<u32> = IdMap::new();
+    map.extend([(id!(a), 1), (id!(b), 2)]);
+    for (_, value) in &mut map {
+        *value *= 10;
+    }
+    let mut values: Vec<u32> = (&map).into_iter().map(|(_, value)| *value).collect();
+    values.sort();
+    assert_eq!(values, [10, 20]);
+    assert_eq!(map.clone().into_iter().count(), 2);
+
+    let mut set = IdSet::new();
+    set.extend([id!(a), id!(b)]);
+    assert_eq!((&set).into_iter().count(), 2);
+    assert_eq!(set.into_iter().count(), 2);
+}
@@ -0,0 +1,9 @@
+use name_id::{id, NameId};
+
+// "cbd4938f3322cbc5f" and "ce6aa744e6a6c1f41" (in beta) have equal hashes
+#[cfg(not(feature = "renamed"))]
+pub const ALPHA: NameId = id!("cbd4938f3322cbc5f");
+#[cfg(feature = "renamed")]
+pub const ALPHA: NameId = id!("alpha renamed");
+
+pub const SHARED: NameId = id!(shared);
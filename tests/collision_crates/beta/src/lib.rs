@@ -0,0 +1,9 @@
+use name_id::{ids, NameId};
+
+ids! {
+    pub BETA = "ce6aa744e6a6c1f41";
+    // equal names in different crates aren't collisions
+    pub SHARED = "shared";
+}
+
+pub const ALPHA: NameId = alpha::ALPHA;
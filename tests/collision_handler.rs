@@ -0,0 +1,28 @@
+#![cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use name_id::{CollisionInfo, NameId};
+
+static COLLISIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn count_collision(info: CollisionInfo) {
+    assert_eq!(info.previous, "handler_original");
+    assert_eq!(info.name, "handler_other");
+    assert_eq!(info.hash, NameId::new("handler_original").value());
+    COLLISIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[test]
+fn handler_replaces_panic() {
+    name_id::set_collision_handler(count_collision);
+
+    let id = NameId::new("handler_original");
+    name_id::register(id, "handler_other");
+    name_id::register(id, "handler_other");
+    assert_eq!(COLLISIONS.load(Ordering::Relaxed), 2);
+
+    // registering the same name again isn't a collision
+    name_id::register(id, "handler_original");
+    assert_eq!(COLLISIONS.load(Ordering::Relaxed), 2);
+}
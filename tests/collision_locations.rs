@@ -0,0 +1,75 @@
+#![cfg(all(
+    feature = "collision_locations",
+    any(feature = "detect_collisions", feature = "detect_collisions_static")
+))]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use name_id::NameId;
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = panic::catch_unwind(AssertUnwindSafe(f)).expect_err("collision didn't panic");
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+// names with the same 64-bit ahash value, each constructor stores the line it
+// constructs its id on
+#[cfg(feature = "ahash")]
+fn create_first(line: &mut u32) {
+    *line = line!() + 1;
+    let _ = NameId::new("cbd4938f3322cbc5f");
+}
+
+#[cfg(feature = "ahash")]
+fn create_second(line: &mut u32) {
+    *line = line!() + 1;
+    let _ = NameId::new("ce6aa744e6a6c1f41");
+}
+
+#[cfg(feature = "ahash")]
+#[test]
+fn panic_shows_both_constructors() {
+    let (mut first, mut second) = (0, 0);
+    create_first(&mut first);
+    let message = panic_message(|| create_second(&mut second));
+    assert!(
+        message.contains(&format!("{}:{}:", file!(), first)),
+        "{message}"
+    );
+    assert!(
+        message.contains(&format!("{}:{}:", file!(), second)),
+        "{message}"
+    );
+}
+
+fn register_original(id: NameId, line: &mut u32) {
+    *line = line!() + 1;
+    name_id::register(id, "locations_original");
+}
+
+fn register_other(id: NameId, line: &mut u32) {
+    *line = line!() + 1;
+    name_id::register(id, "locations_other");
+}
+
+#[test]
+fn panic_shows_both_registrations() {
+    let id = NameId::from_value(0x10ca_7104);
+    let (mut first, mut second) = (0, 0);
+    register_original(id, &mut first);
+    let message = panic_message(|| register_other(id, &mut second));
+    assert_eq!(
+        message,
+        format!(
+            "hash id collision: locations_original (at {}:{}:5) collides with \
+             locations_other (at {}:{}:5)",
+            file!(),
+            first,
+            file!(),
+            second
+        )
+    );
+}
@@ -0,0 +1,13 @@
+#![cfg(any(feature = "detect_collisions", feature = "detect_collisions_static"))]
+
+use name_id::NameId;
+
+#[test]
+fn forced_collision_respects_profile() {
+    name_id::set_collision_handler(name_id::debug_panic_on_collision);
+
+    let id = NameId::new("policy_original");
+    let result = std::panic::catch_unwind(|| name_id::register(id, "policy_other"));
+    assert_eq!(result.is_err(), cfg!(debug_assertions));
+    assert_eq!(id.resolve(), Some("policy_original"));
+}
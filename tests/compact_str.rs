@@ -0,0 +1,29 @@
+#![cfg(feature = "compact_str")]
+
+use compact_str::CompactString;
+use name_id::NameId;
+
+#[test]
+fn parity_with_str() {
+    for name in ["", "player_spawn", "a name longer than the inline capacity"] {
+        let compact = CompactString::new(name);
+        assert_eq!(NameId::from(&compact).value(), NameId::new(name).value());
+        assert_eq!(NameId::from(compact).value(), NameId::new(name).value());
+    }
+}
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+#[test]
+fn repeated_conversions_dont_leak() {
+    let name = CompactString::new("compact_str_leak_check");
+    let before = name_id::registry_stats().leaked_bytes;
+    let id = NameId::from(&name);
+    assert_eq!(id.resolve(), Some("compact_str_leak_check"));
+    let after_first = name_id::registry_stats().leaked_bytes;
+    assert_eq!(after_first, before + name.len());
+    for _ in 0..100 {
+        assert_eq!(NameId::from(&name), id);
+        assert_eq!(NameId::from(name.clone()), id);
+    }
+    assert_eq!(name_id::registry_stats().leaked_bytes, after_first);
+}
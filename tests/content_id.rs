@@ -0,0 +1,38 @@
+#![cfg(not(feature = "dynamic_hasher"))]
+
+use name_id::{content_id, id, NameId};
+
+const SHADER_ID: NameId = content_id!("tests/fixtures/shaders/blit.wgsl");
+
+#[test]
+fn matches_runtime_hash() {
+    let contents = include_bytes!("fixtures/shaders/blit.wgsl");
+    assert_eq!(SHADER_ID, NameId::from_bytes_content(contents));
+}
+
+#[test]
+fn differs_from_name() {
+    assert_ne!(SHADER_ID, id!("tests/fixtures/shaders/blit.wgsl"));
+    assert_ne!(NameId::from_bytes_content(b"door"), NameId::new("door"));
+}
+
+#[test]
+fn empty_content() {
+    assert_eq!(
+        NameId::from_bytes_content(b""),
+        NameId::from_bytes_content(&[])
+    );
+    assert_ne!(
+        NameId::from_bytes_content(b""),
+        NameId::from_bytes_content(b"\0")
+    );
+}
+
+#[cfg(all(debug_assertions, feature = "debug_name"))]
+#[test]
+fn path_is_label() {
+    assert_eq!(
+        format!("{SHADER_ID}"),
+        "#{tests/fixtures/shaders/blit.wgsl}"
+    );
+}
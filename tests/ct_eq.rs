@@ -0,0 +1,36 @@
+use name_id::{id, NameId};
+
+#[test]
+fn equal_values() {
+    for value in [0, 1, 0x80, 1 << 63, u64::MAX, id!(player_spawn).value()] {
+        let id = NameId::from_value(value);
+        assert!(id.ct_eq(&NameId::from_value(value)));
+    }
+    assert!(id!(player_spawn).ct_eq(&NameId::new("player_spawn")));
+}
+
+#[test]
+fn unequal_values() {
+    let base = NameId::from_value(0x0123_4567_89ab_cdef);
+    // every single differing bit must be detected
+    for bit in 0..64 {
+        let other = NameId::from_value(base.value() ^ (1 << bit));
+        assert!(!base.ct_eq(&other), "bit {bit}");
+        assert!(!other.ct_eq(&base), "bit {bit}");
+    }
+    assert!(!NameId::from_value(0).ct_eq(&NameId::from_value(u64::MAX)));
+    assert!(!id!(player_spawn).ct_eq(&id!(player_death)));
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn subtle_impl() {
+    use subtle::ConstantTimeEq;
+
+    let id = id!(player_spawn);
+    assert!(bool::from(ConstantTimeEq::ct_eq(
+        &id,
+        &NameId::new("player_spawn")
+    )));
+    assert!(!bool::from(ConstantTimeEq::ct_eq(&id, &id!(player_death))));
+}
@@ -0,0 +1,12 @@
+#![cfg(feature = "defmt")]
+
+use name_id::{id, NameId};
+
+fn assert_format<T: defmt::Format>(_: &T) {}
+
+#[test]
+fn implements_format() {
+    assert_format(&id!(radio_state));
+    assert_format(&NameId::from_value(0x2a));
+    assert_format(&[id!(radio_a), id!(radio_b)]);
+}
@@ -0,0 +1,100 @@
+use name_id::{id, FixedDenseIndexAllocator, IndexesExhausted, NameId};
+
+#[test]
+fn fixed_assigns_in_order() {
+    let mut indexes = FixedDenseIndexAllocator::<4>::new();
+    assert!(indexes.is_empty());
+    assert_eq!(indexes.index_of(id!(a)), Ok(0));
+    assert_eq!(indexes.index_of(id!(b)), Ok(1));
+    assert_eq!(indexes.index_of(id!(c)), Ok(2));
+    assert_eq!(indexes.len(), 3);
+    assert_eq!(indexes.get(id!(b)), Some(1));
+    assert_eq!(indexes.get(id!(missing)), None);
+    assert_eq!(indexes.ids(), [id!(a), id!(b), id!(c)]);
+    assert_eq!(
+        indexes.iter().collect::<Vec<_>>(),
+        [(0, id!(a)), (1, id!(b)), (2, id!(c))]
+    );
+}
+
+#[test]
+fn fixed_reuses_known_ids() {
+    let mut indexes = FixedDenseIndexAllocator::<4>::new();
+    indexes.index_of(id!(a)).unwrap();
+    indexes.index_of(id!(b)).unwrap();
+    let before = indexes.clone();
+    assert_eq!(indexes.index_of(id!(a)), Ok(0));
+    assert_eq!(indexes.index_of(id!(b)), Ok(1));
+    assert_eq!(indexes.len(), 2);
+    assert_eq!(indexes.ids(), before.ids());
+    assert_eq!(indexes.index_of(id!(c)), Ok(2));
+}
+
+#[test]
+fn fixed_exhaustion() {
+    let mut indexes = FixedDenseIndexAllocator::<3>::new();
+    // equal hash values modulo capacity probe into the same entries
+    let ids = [0, 3, 6].map(NameId::from_value);
+    for (i, id) in ids.into_iter().enumerate() {
+        assert_eq!(indexes.index_of(id), Ok(i as u32));
+    }
+    let extra = NameId::from_value(9);
+    assert_eq!(indexes.index_of(extra), Err(IndexesExhausted { id: extra }));
+    assert_eq!(indexes.get(extra), None);
+    assert_eq!(indexes.len(), 3);
+    // known ids still resolve when full
+    for (i, id) in ids.into_iter().enumerate() {
+        assert_eq!(indexes.index_of(id), Ok(i as u32));
+    }
+
+    let mut empty = FixedDenseIndexAllocator::<0>::new();
+    assert_eq!(empty.index_of(extra), Err(IndexesExhausted { id: extra }));
+}
+
+#[test]
+fn fixed_reverse_lookup() {
+    let mut indexes = FixedDenseIndexAllocator::<8>::new();
+    let ids: Vec<NameId> = (0..8)
+        .map(|i| NameId::from_value(i * 0x9e37_79b9))
+        .collect();
+    for id in &ids {
+        let index = indexes.index_of(*id).unwrap();
+        assert_eq!(indexes.id_at(index), Some(*id));
+    }
+    assert_eq!(indexes.id_at(8), None);
+    assert_eq!(indexes.id_at(u32::MAX), None);
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+mod growable {
+    use name_id::{id, DenseIndexAllocator, NameId};
+
+    #[test]
+    fn assigns_in_order() {
+        let mut indexes = DenseIndexAllocator::new();
+        assert_eq!(indexes.index_of(id!(a)), Ok(0));
+        assert_eq!(indexes.index_of(id!(b)), Ok(1));
+        assert_eq!(indexes.index_of(id!(a)), Ok(0));
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes.get(id!(b)), Some(1));
+        assert_eq!(indexes.get(id!(c)), None);
+        assert_eq!(
+            (&indexes).into_iter().collect::<Vec<_>>(),
+            [(0, id!(a)), (1, id!(b))]
+        );
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        let mut indexes = DenseIndexAllocator::with_capacity(16);
+        let ids: Vec<NameId> = (0..1000).map(NameId::from_value).collect();
+        for id in &ids {
+            indexes.index_of(*id).unwrap();
+        }
+        for (index, id) in ids.iter().enumerate() {
+            assert_eq!(indexes.id_at(index as u32), Some(*id));
+            assert_eq!(indexes.get(*id), Some(index as u32));
+        }
+        assert_eq!(indexes.id_at(1000), None);
+    }
+}
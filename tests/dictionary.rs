@@ -0,0 +1,81 @@
+#![cfg(feature = "alloc")]
+
+use name_id::{DictionaryError, IdDictionary, NameId};
+
+fn fnv1a(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// hashes only the length, so names of the same length collide
+fn by_length(name: &str) -> u64 {
+    name.len() as u64
+}
+
+const NAMES: [&str; 5] = ["player_spawn", "level_exit", "cellar_door", "alice", "bob"];
+
+#[test]
+fn translates_known_names() {
+    let dictionary = IdDictionary::new(NAMES, fnv1a);
+    assert_eq!(dictionary.len(), NAMES.len());
+    for name in NAMES {
+        let old = fnv1a(name);
+        assert_eq!(dictionary.translate(old), Some(NameId::new(name)));
+        assert_eq!(dictionary.translate_or_keep(old), name);
+    }
+}
+
+#[test]
+fn unknown_legacy_values() {
+    let dictionary = IdDictionary::new(NAMES, fnv1a);
+    let unknown = fnv1a("not_a_known_name");
+    assert_eq!(dictionary.translate(unknown), None);
+    assert_eq!(
+        dictionary.translate_or_keep(unknown),
+        NameId::from_value(unknown)
+    );
+    assert_eq!(IdDictionary::default().translate(0), None);
+}
+
+#[test]
+fn ambiguous_legacy_values_are_dropped() {
+    // "alice" and "level_exit" are the only names of lengths 5 and 10
+    let dictionary = IdDictionary::new(["alice", "level_exit", "bob", "eve"], by_length);
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.translate(5), Some(NameId::new("alice")));
+    assert_eq!(dictionary.translate(10), Some(NameId::new("level_exit")));
+    assert_eq!(dictionary.translate(3), None);
+
+    // repeated names aren't ambiguous
+    let dictionary = IdDictionary::new(["bob", "bob"], by_length);
+    assert_eq!(dictionary.translate(3), Some(NameId::new("bob")));
+}
+
+#[test]
+fn bytes_round_trip() {
+    let dictionary = IdDictionary::new(NAMES, fnv1a);
+    let bytes = dictionary.to_bytes();
+    assert_eq!(bytes.len(), NAMES.len() * 16);
+    let parsed = IdDictionary::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, dictionary);
+    assert!(parsed.iter().zip(dictionary.iter()).all(|(a, b)| a == b));
+    assert!(parsed.iter().is_sorted_by_key(|(legacy, _)| legacy));
+}
+
+#[test]
+fn bytes_errors() {
+    let bytes = IdDictionary::new(NAMES, fnv1a).to_bytes();
+    assert_eq!(
+        IdDictionary::from_bytes(&bytes[..20]),
+        Err(DictionaryError::Truncated)
+    );
+
+    let mut swapped = bytes[16..32].to_vec();
+    swapped.extend_from_slice(&bytes[..16]);
+    assert_eq!(
+        IdDictionary::from_bytes(&swapped),
+        Err(DictionaryError::Unsorted { offset: 16 })
+    );
+    assert!(IdDictionary::from_bytes(&[]).unwrap().is_empty());
+}
@@ -0,0 +1,58 @@
+#![cfg(all(
+    any(feature = "registry", feature = "registry_static"),
+    feature = "alloc",
+    // ids declared anywhere in the binary would be listed as well
+    not(feature = "inventory")
+))]
+
+use name_id::{dump_registry_string, DumpOrder, NameId};
+
+// a single test, as all tests in this file share the registry
+#[test]
+fn dump() {
+    name_id::register(NameId::from_value(0x162e), "player_death");
+    name_id::register(NameId::from_value(0x4d2), "player_spawn");
+    name_id::register(NameId::from_value(u64::MAX), "a\tb");
+    let long: &'static str = "long_".repeat(20).leak();
+    name_id::register(NameId::from_value(0x10), long);
+
+    assert_eq!(
+        dump_registry_string(DumpOrder::ByHash),
+        "\
+HASH                NAME
+0x0000000000000010  long_long_long_long_long_long_long_long_long_long_long_long_l...
+0x00000000000004d2  player_spawn
+0x000000000000162e  player_death
+0xffffffffffffffff  a\\tb
+4 ids
+"
+    );
+    assert_eq!(
+        dump_registry_string(DumpOrder::ByName),
+        "\
+HASH                NAME
+0xffffffffffffffff  a\\tb
+0x0000000000000010  long_long_long_long_long_long_long_long_long_long_long_long_l...
+0x000000000000162e  player_death
+0x00000000000004d2  player_spawn
+4 ids
+"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn includes_name_table() {
+    let table = name_id::NameTable::load_from_text("0000000000000001\ttable_only\n").unwrap();
+    let previous = table.install_global();
+    let dump = dump_registry_string(DumpOrder::ByHash);
+    match previous {
+        Some(previous) => {
+            name_id::NameTable::clone(&previous).install_global();
+        }
+        None => {
+            name_id::NameTable::uninstall_global();
+        }
+    }
+    assert!(dump.contains("0x0000000000000001  table_only\n"), "{dump}");
+}
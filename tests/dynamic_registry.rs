@@ -0,0 +1,102 @@
+#![cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+
+use name_id::{id, DynamicRegistry, NameId};
+
+#[test]
+fn register_resolve_drop() {
+    let registry = DynamicRegistry::new();
+    let guard = registry.register("session player").unwrap();
+    let id = guard.id();
+    // `id!` doesn't record the name in the global registry
+    assert_eq!(id, id!("session player"));
+    assert_eq!(registry.resolve(id).as_deref(), Some("session player"));
+    assert_eq!(guard.name(), "session player");
+    assert_eq!(registry.resolve_with(id, str::len), Some(14));
+    assert_eq!(registry.ref_count(id), 1);
+    assert_eq!(registry.len(), 1);
+
+    drop(guard);
+    assert_eq!(registry.resolve(id), None);
+    assert!(!registry.contains(id));
+    assert_eq!(registry.ref_count(id), 0);
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn last_guard_removes_entry() {
+    let registry = DynamicRegistry::new();
+    let first = registry.register("shared").unwrap();
+    let second = registry.register("shared").unwrap();
+    let third = first.clone();
+    let id = first.id();
+    assert_eq!(registry.ref_count(id), 3);
+    assert_eq!(registry.len(), 1);
+
+    drop(first);
+    drop(third);
+    assert_eq!(registry.resolve(id).as_deref(), Some("shared"));
+    drop(second);
+    assert_eq!(registry.resolve(id), None);
+}
+
+#[test]
+fn reregister_after_drop() {
+    let registry = DynamicRegistry::new();
+    for _ in 0..3 {
+        let guard = registry.register("cycled").unwrap();
+        assert_eq!(registry.ref_count(guard.id()), 1);
+        assert!(registry.contains(guard.id()));
+    }
+    assert!(registry.is_empty());
+}
+
+#[cfg(feature = "ahash")]
+#[test]
+fn collision_is_rejected() {
+    // a genuine 64-bit collision of the default hasher
+    let registry = DynamicRegistry::new();
+    let first = registry.register("cbd4938f3322cbc5f").unwrap();
+    let err = registry.register("ce6aa744e6a6c1f41").unwrap_err();
+    assert_eq!(err.id, first.id());
+    assert_eq!(err.id.value(), 0xc4089912f36545a0);
+    drop(first);
+    assert!(registry.register("ce6aa744e6a6c1f41").is_ok());
+}
+
+#[cfg(feature = "registry")]
+#[test]
+fn global_registry_is_checked() {
+    let registry = DynamicRegistry::new();
+    let global = NameId::new("permanent name");
+    assert_eq!(registry.resolve(global).as_deref(), Some("permanent name"));
+    assert!(registry.is_empty());
+
+    #[cfg(feature = "ahash")]
+    {
+        let _ = NameId::new("cbd4938f3322cbc5f");
+        assert!(registry.register("ce6aa744e6a6c1f41").is_err());
+    }
+}
+
+#[test]
+fn concurrent_guards() {
+    static REGISTRY: DynamicRegistry = DynamicRegistry::new();
+    let names = ["alpha", "beta", "gamma", "delta"];
+    std::thread::scope(|scope| {
+        for thread in 0..8 {
+            scope.spawn(move || {
+                for round in 0..200 {
+                    let name = names[(thread + round) % names.len()];
+                    let guard = REGISTRY.register(name).unwrap();
+                    let copy = guard.clone();
+                    assert_eq!(REGISTRY.resolve(copy.id()).as_deref(), Some(name));
+                    assert!(REGISTRY.ref_count(guard.id()) >= 2);
+                }
+            });
+        }
+    });
+    assert!(REGISTRY.is_empty());
+}
@@ -0,0 +1,73 @@
+use core::ffi::CStr;
+
+use name_id::{id, NameId};
+
+#[test]
+fn str_types() {
+    let id = id!(player_spawn);
+    assert_eq!(id, "player_spawn");
+    assert_eq!(id, *"player_spawn");
+    assert_eq!(id, &"player_spawn");
+    assert_ne!(id, "player_exit");
+    #[cfg(feature = "alloc")]
+    {
+        use std::borrow::Cow;
+        let owned = String::from("player_spawn");
+        assert_eq!(id, owned);
+        assert_eq!(id, &owned);
+        assert_eq!(id, Cow::Borrowed("player_spawn"));
+        assert_eq!(id, Box::<str>::from("player_spawn"));
+        assert_eq!(id, std::rc::Rc::<str>::from("player_spawn"));
+        assert_eq!(id, std::sync::Arc::<str>::from("player_spawn"));
+    }
+}
+
+#[cfg(any(feature = "alloc", not(all(debug_assertions, feature = "debug_name"))))]
+#[test]
+fn c_str_matches_from() {
+    let names: [&CStr; 4] = [c"player_spawn", c"", c"\xff\xfe invalid", c"ünïcødé"];
+    for name in names {
+        let id = NameId::from(name);
+        assert_eq!(id, name);
+        assert_eq!(name, id);
+        assert_ne!(NameId::from(c"other"), name);
+        #[cfg(feature = "alloc")]
+        {
+            let owned = name.to_owned();
+            assert_eq!(id, owned);
+            assert_eq!(owned, id);
+        }
+    }
+}
+
+#[test]
+fn c_str_differs_from_str() {
+    // C strings are hashed with their terminator
+    assert_ne!(id!(player_spawn), c"player_spawn");
+    assert_ne!(c"player_spawn", id!(player_spawn));
+}
+
+#[test]
+fn byte_slices_match_str() {
+    let names = ["player_spawn", "", "ünïcødé ✓"];
+    for name in names {
+        let id = NameId::new(name);
+        let bytes = name.as_bytes();
+        assert_eq!(id, bytes);
+        assert_eq!(id, *bytes);
+        assert_eq!(bytes, id);
+        assert_eq!(*bytes, id);
+        assert_eq!(id == bytes, id == std::str::from_utf8(bytes).unwrap());
+        assert_ne!(id!(other), bytes);
+    }
+}
+
+#[test]
+fn invalid_utf8_bytes_are_unequal() {
+    let invalid: &[u8] = b"player\xffspawn";
+    // lossy decoding of the same bytes
+    let lossy = id!("player\u{FFFD}spawn");
+    assert_ne!(lossy, invalid);
+    assert_ne!(invalid, lossy);
+    assert_ne!(NameId::from_value(0), invalid);
+}
@@ -0,0 +1,53 @@
+#![cfg(all(feature = "ffi", not(feature = "fixed_size")))]
+
+use name_id::{id, NameId};
+
+// declared separately to call the functions through their C ABI
+extern "C" {
+    fn name_id_from_str(ptr: *const u8, len: usize) -> u64;
+    fn name_id_value(id: u64) -> u64;
+    fn name_id_eq(a: u64, b: u64) -> bool;
+    #[cfg(any(feature = "registry", feature = "registry_static"))]
+    fn name_id_resolve(hash: u64, out_ptr: *mut *const u8, out_len: *mut usize) -> bool;
+}
+
+#[test]
+fn from_str() {
+    let name = "engine_asset";
+    let value = unsafe { name_id_from_str(name.as_ptr(), name.len()) };
+    assert_eq!(value, id!(engine_asset).value());
+    assert_eq!(NameId::new(name), NameId::from_value(value));
+
+    assert_eq!(
+        unsafe { name_id_from_str(core::ptr::null(), 0) },
+        id!("").value()
+    );
+    let invalid = [0xffu8, 0xfe];
+    assert_eq!(
+        unsafe { name_id_from_str(invalid.as_ptr(), invalid.len()) },
+        0
+    );
+}
+
+#[test]
+fn value_and_eq() {
+    let id = id!(engine_asset);
+    // NameId is ABI-identical to u64
+    let raw: u64 = unsafe { core::mem::transmute(id) };
+    assert_eq!(unsafe { name_id_value(raw) }, id.value());
+    assert!(unsafe { name_id_eq(raw, id.value()) });
+    assert!(!unsafe { name_id_eq(raw, id!(engine_other).value()) });
+}
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+#[test]
+fn resolve() {
+    let id = NameId::new("engine_registered");
+    let mut ptr = core::ptr::null();
+    let mut len = 0;
+    assert!(unsafe { name_id_resolve(id.value(), &mut ptr, &mut len) });
+    let name = unsafe { core::str::from_utf8(core::slice::from_raw_parts(ptr, len)).unwrap() };
+    assert_eq!(name, "engine_registered");
+
+    assert!(!unsafe { name_id_resolve(id!(engine_missing).value(), &mut ptr, &mut len) });
+}
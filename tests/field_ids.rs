@@ -0,0 +1,71 @@
+use name_id::{id, FieldIds, NameId};
+
+#[allow(dead_code)]
+#[derive(FieldIds)]
+struct Light {
+    color: [f32; 3],
+    #[field_id(rename = "power")]
+    intensity: f32,
+    #[field_id(skip)]
+    dirty: bool,
+    radius: f32,
+}
+
+#[allow(dead_code)]
+#[derive(FieldIds)]
+struct Pair(u32, #[field_id(skip)] u32, u32);
+
+#[allow(dead_code)]
+#[derive(FieldIds)]
+struct Wrapper<T: Clone> {
+    inner: T,
+}
+
+#[allow(dead_code)]
+#[derive(FieldIds)]
+struct Unit;
+
+#[test]
+fn named_fields() {
+    assert_eq!(
+        Light::FIELD_IDS,
+        &[
+            (id!(color), "color"),
+            (id!(power), "power"),
+            (id!(radius), "radius"),
+        ]
+    );
+    assert_eq!(Light::field_count(), 3);
+}
+
+#[test]
+fn rename_and_skip() {
+    assert_eq!(Light::field_id("power"), Some(id!(power)));
+    assert_eq!(Light::field_id("intensity"), None);
+    assert_eq!(Light::field_id("dirty"), None);
+    assert_eq!(Light::field_id("radius"), Some(id!(radius)));
+}
+
+#[test]
+fn tuple_struct() {
+    assert_eq!(Pair::FIELD_IDS, &[(id!("0"), "0"), (id!("2"), "2")]);
+    assert_eq!(Pair::field_id("1"), None);
+    assert_eq!(Pair::field_count(), 2);
+}
+
+#[test]
+fn generic_and_unit() {
+    assert_eq!(Wrapper::<u8>::field_id("inner"), Some(id!(inner)));
+    assert_eq!(Unit::FIELD_IDS, &[]);
+    assert_eq!(Unit::field_count(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn matches_runtime_names() {
+    let tables: [&[(NameId, &str)]; 3] =
+        [Light::FIELD_IDS, Pair::FIELD_IDS, Wrapper::<u8>::FIELD_IDS];
+    for (id, name) in tables.into_iter().flatten() {
+        assert_eq!(*id, NameId::new(name.to_string()));
+    }
+}
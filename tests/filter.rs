@@ -0,0 +1,96 @@
+use name_id::{id, IdFilter, NameId};
+
+// splitmix64, so corpus values are distributed like hashes without relying on
+// a hasher feature
+fn corpus(seed: u64, len: usize) -> impl Iterator<Item = NameId> {
+    let mut state = seed;
+    (0..len).map(move |_| {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        NameId::from_value(z ^ (z >> 31))
+    })
+}
+
+static KNOWN: IdFilter<4> = {
+    let mut filter = IdFilter::new();
+    filter.insert(id!(walk));
+    filter.insert(id!(run));
+    filter
+};
+
+#[test]
+fn const_construction() {
+    assert!(KNOWN.maybe_contains(id!(walk)));
+    assert!(KNOWN.maybe_contains(id!(run)));
+    assert_eq!(IdFilter::<4>::BITS, 256);
+    assert!(IdFilter::<4>::new().is_empty());
+}
+
+#[test]
+fn no_false_negatives() {
+    let mut filter = IdFilter::<128>::new();
+    filter.extend(corpus(1, 1000));
+    for id in corpus(1, 1000) {
+        assert!(filter.maybe_contains(id), "false negative for {:?}", id);
+    }
+}
+
+#[test]
+fn false_positive_rate() {
+    // 1000 ids in 8192 bits with 4 probes: expected rate is about 2.2%
+    let filter: IdFilter<128> = corpus(1, 1000).collect();
+    let tested = 100_000;
+    let positives = corpus(2, tested)
+        .filter(|id| filter.maybe_contains(*id))
+        .count();
+    let rate = positives as f64 / tested as f64;
+    assert!(rate < 0.04, "false positive rate {} is too high", rate);
+    assert!(filter.count_ones() <= 4000);
+}
+
+#[test]
+fn clear() {
+    let mut filter: IdFilter<8> = corpus(3, 50).collect();
+    assert!(!filter.is_empty());
+    filter.clear();
+    assert!(filter.is_empty());
+    assert_eq!(filter, IdFilter::default());
+}
+
+#[test]
+fn union_and_intersection() {
+    let a: IdFilter<64> = corpus(4, 100).collect();
+    let b: IdFilter<64> = corpus(5, 100).collect();
+    let shared: Vec<_> = corpus(6, 20).collect();
+    let mut a_shared = a;
+    a_shared.extend(shared.iter().copied());
+    let mut b_shared = b;
+    b_shared.extend(shared.iter().copied());
+
+    let union = a.union(&b);
+    for id in corpus(4, 100).chain(corpus(5, 100)) {
+        assert!(union.maybe_contains(id));
+    }
+    let mut union_with = a;
+    union_with.union_with(&b);
+    assert_eq!(union_with, union);
+
+    let intersection = a_shared.intersection(&b_shared);
+    for id in &shared {
+        assert!(intersection.maybe_contains(*id));
+    }
+    let mut intersect_with = a_shared;
+    intersect_with.intersect_with(&b_shared);
+    assert_eq!(intersect_with, intersection);
+    assert!(intersection.count_ones() < a_shared.count_ones());
+}
+
+#[test]
+fn custom_probe_count() {
+    let mut filter = IdFilter::<2, 1>::new();
+    filter.insert(id!(single));
+    assert_eq!(filter.count_ones(), 1);
+    assert!(filter.maybe_contains(id!(single)));
+}
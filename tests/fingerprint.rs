@@ -0,0 +1,50 @@
+use name_id::{check_fingerprint, FingerprintMismatch, ALGORITHM, ALGORITHM_FINGERPRINT};
+
+// Fingerprints must only change together with hash values of names. Update the
+// pinned value of a hasher only when its hashes are meant to change.
+// `dynamic_hasher` is pinned in `tests/standalone`, as it rejects `id!` used by
+// dev-dependencies.
+// native `ahash` depends on the target, this is its value on x86_64 without AES
+#[cfg(not(feature = "portable_hash"))]
+const PINNED: u64 = 0x2ac6_5aed_7b78_4ffb;
+#[cfg(feature = "portable_hash")]
+const PINNED: u64 = 0x249a_7ebf_db47_e888;
+
+#[test]
+fn fingerprint_is_pinned() {
+    assert_eq!(
+        ALGORITHM_FINGERPRINT, PINNED,
+        "algorithm fingerprint changed to {:#018x}",
+        ALGORITHM_FINGERPRINT
+    );
+}
+
+#[test]
+fn seed_digest_matches_runtime_hash() {
+    assert_eq!(
+        name_id::NameId::new("name-id algorithm fingerprint probe"),
+        name_id::NameId::from_value(ALGORITHM.seed_digest)
+    );
+    #[cfg(not(feature = "portable_hash"))]
+    assert_eq!(ALGORITHM.hasher, "ahash 0.8");
+    #[cfg(feature = "portable_hash")]
+    assert_eq!(ALGORITHM.hasher, "portable ahash 0.8");
+}
+
+#[test]
+fn check() {
+    assert_eq!(check_fingerprint(ALGORITHM_FINGERPRINT), Ok(()));
+    const { assert!(check_fingerprint(ALGORITHM_FINGERPRINT).is_ok()) };
+
+    let error = check_fingerprint(1).unwrap_err();
+    assert_eq!(
+        error,
+        FingerprintMismatch {
+            stored: 1,
+            expected: ALGORITHM_FINGERPRINT,
+        }
+    );
+    let message = error.to_string();
+    assert!(message.contains("0x0000000000000001"), "{}", message);
+    assert!(message.contains(ALGORITHM.hasher), "{}", message);
+}
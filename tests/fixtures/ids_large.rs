@@ -0,0 +1,168 @@
+// Block of 1024 generated constants (`group_<g>::ENTRY_<i>`), used to check
+// that `ids!` expansion stays fast for large blocks.
+
+name_id::ids! {
+    #[allow(dead_code)]
+    large {
+        group_0 {
+            ENTRY_0; ENTRY_1; ENTRY_2; ENTRY_3; ENTRY_4; ENTRY_5; ENTRY_6; ENTRY_7;
+            ENTRY_8; ENTRY_9; ENTRY_10; ENTRY_11; ENTRY_12; ENTRY_13; ENTRY_14; ENTRY_15;
+            ENTRY_16; ENTRY_17; ENTRY_18; ENTRY_19; ENTRY_20; ENTRY_21; ENTRY_22; ENTRY_23;
+            ENTRY_24; ENTRY_25; ENTRY_26; ENTRY_27; ENTRY_28; ENTRY_29; ENTRY_30; ENTRY_31;
+            ENTRY_32; ENTRY_33; ENTRY_34; ENTRY_35; ENTRY_36; ENTRY_37; ENTRY_38; ENTRY_39;
+            ENTRY_40; ENTRY_41; ENTRY_42; ENTRY_43; ENTRY_44; ENTRY_45; ENTRY_46; ENTRY_47;
+            ENTRY_48; ENTRY_49; ENTRY_50; ENTRY_51; ENTRY_52; ENTRY_53; ENTRY_54; ENTRY_55;
+            ENTRY_56; ENTRY_57; ENTRY_58; ENTRY_59; ENTRY_60; ENTRY_61; ENTRY_62; ENTRY_63;
+        }
+        group_1 {
+            ENTRY_64; ENTRY_65; ENTRY_66; ENTRY_67; ENTRY_68; ENTRY_69; ENTRY_70; ENTRY_71;
+            ENTRY_72; ENTRY_73; ENTRY_74; ENTRY_75; ENTRY_76; ENTRY_77; ENTRY_78; ENTRY_79;
+            ENTRY_80; ENTRY_81; ENTRY_82; ENTRY_83; ENTRY_84; ENTRY_85; ENTRY_86; ENTRY_87;
+            ENTRY_88; ENTRY_89; ENTRY_90; ENTRY_91; ENTRY_92; ENTRY_93; ENTRY_94; ENTRY_95;
+            ENTRY_96; ENTRY_97; ENTRY_98; ENTRY_99; ENTRY_100; ENTRY_101; ENTRY_102; ENTRY_103;
+            ENTRY_104; ENTRY_105; ENTRY_106; ENTRY_107; ENTRY_108; ENTRY_109; ENTRY_110; ENTRY_111;
+            ENTRY_112; ENTRY_113; ENTRY_114; ENTRY_115; ENTRY_116; ENTRY_117; ENTRY_118; ENTRY_119;
+            ENTRY_120; ENTRY_121; ENTRY_122; ENTRY_123; ENTRY_124; ENTRY_125; ENTRY_126; ENTRY_127;
+        }
+        group_2 {
+            ENTRY_128; ENTRY_129; ENTRY_130; ENTRY_131; ENTRY_132; ENTRY_133; ENTRY_134; ENTRY_135;
+            ENTRY_136; ENTRY_137; ENTRY_138; ENTRY_139; ENTRY_140; ENTRY_141; ENTRY_142; ENTRY_143;
+            ENTRY_144; ENTRY_145; ENTRY_146; ENTRY_147; ENTRY_148; ENTRY_149; ENTRY_150; ENTRY_151;
+            ENTRY_152; ENTRY_153; ENTRY_154; ENTRY_155; ENTRY_156; ENTRY_157; ENTRY_158; ENTRY_159;
+            ENTRY_160; ENTRY_161; ENTRY_162; ENTRY_163; ENTRY_164; ENTRY_165; ENTRY_166; ENTRY_167;
+            ENTRY_168; ENTRY_169; ENTRY_170; ENTRY_171; ENTRY_172; ENTRY_173; ENTRY_174; ENTRY_175;
+            ENTRY_176; ENTRY_177; ENTRY_178; ENTRY_179; ENTRY_180; ENTRY_181; ENTRY_182; ENTRY_183;
+            ENTRY_184; ENTRY_185; ENTRY_186; ENTRY_187; ENTRY_188; ENTRY_189; ENTRY_190; ENTRY_191;
+        }
+        group_3 {
+            ENTRY_192; ENTRY_193; ENTRY_194; ENTRY_195; ENTRY_196; ENTRY_197; ENTRY_198; ENTRY_199;
+            ENTRY_200; ENTRY_201; ENTRY_202; ENTRY_203; ENTRY_204; ENTRY_205; ENTRY_206; ENTRY_207;
+            ENTRY_208; ENTRY_209; ENTRY_210; ENTRY_211; ENTRY_212; ENTRY_213; ENTRY_214; ENTRY_215;
+            ENTRY_216; ENTRY_217; ENTRY_218; ENTRY_219; ENTRY_220; ENTRY_221; ENTRY_222; ENTRY_223;
+            ENTRY_224; ENTRY_225; ENTRY_226; ENTRY_227; ENTRY_228; ENTRY_229; ENTRY_230; ENTRY_231;
+            ENTRY_232; ENTRY_233; ENTRY_234; ENTRY_235; ENTRY_236; ENTRY_237; ENTRY_238; ENTRY_239;
+            ENTRY_240; ENTRY_241; ENTRY_242; ENTRY_243; ENTRY_244; ENTRY_245; ENTRY_246; ENTRY_247;
+            ENTRY_248; ENTRY_249; ENTRY_250; ENTRY_251; ENTRY_252; ENTRY_253; ENTRY_254; ENTRY_255;
+        }
+        group_4 {
+            ENTRY_256; ENTRY_257; ENTRY_258; ENTRY_259; ENTRY_260; ENTRY_261; ENTRY_262; ENTRY_263;
+            ENTRY_264; ENTRY_265; ENTRY_266; ENTRY_267; ENTRY_268; ENTRY_269; ENTRY_270; ENTRY_271;
+            ENTRY_272; ENTRY_273; ENTRY_274; ENTRY_275; ENTRY_276; ENTRY_277; ENTRY_278; ENTRY_279;
+            ENTRY_280; ENTRY_281; ENTRY_282; ENTRY_283; ENTRY_284; ENTRY_285; ENTRY_286; ENTRY_287;
+            ENTRY_288; ENTRY_289; ENTRY_290; ENTRY_291; ENTRY_292; ENTRY_293; ENTRY_294; ENTRY_295;
+            ENTRY_296; ENTRY_297; ENTRY_298; ENTRY_299; ENTRY_300; ENTRY_301; ENTRY_302; ENTRY_303;
+            ENTRY_304; ENTRY_305; ENTRY_306; ENTRY_307; ENTRY_308; ENTRY_309; ENTRY_310; ENTRY_311;
+            ENTRY_312; ENTRY_313; ENTRY_314; ENTRY_315; ENTRY_316; ENTRY_317; ENTRY_318; ENTRY_319;
+        }
+        group_5 {
+            ENTRY_320; ENTRY_321; ENTRY_322; ENTRY_323; ENTRY_324; ENTRY_325; ENTRY_326; ENTRY_327;
+            ENTRY_328; ENTRY_329; ENTRY_330; ENTRY_331; ENTRY_332; ENTRY_333; ENTRY_334; ENTRY_335;
+            ENTRY_336; ENTRY_337; ENTRY_338; ENTRY_339; ENTRY_340; ENTRY_341; ENTRY_342; ENTRY_343;
+            ENTRY_344; ENTRY_345; ENTRY_346; ENTRY_347; ENTRY_348; ENTRY_349; ENTRY_350; ENTRY_351;
+            ENTRY_352; ENTRY_353; ENTRY_354; ENTRY_355; ENTRY_356; ENTRY_357; ENTRY_358; ENTRY_359;
+            ENTRY_360; ENTRY_361; ENTRY_362; ENTRY_363; ENTRY_364; ENTRY_365; ENTRY_366; ENTRY_367;
+            ENTRY_368; ENTRY_369; ENTRY_370; ENTRY_371; ENTRY_372; ENTRY_373; ENTRY_374; ENTRY_375;
+            ENTRY_376; ENTRY_377; ENTRY_378; ENTRY_379; ENTRY_380; ENTRY_381; ENTRY_382; ENTRY_383;
+        }
+        group_6 {
+            ENTRY_384; ENTRY_385; ENTRY_386; ENTRY_387; ENTRY_388; ENTRY_389; ENTRY_390; ENTRY_391;
+            ENTRY_392; ENTRY_393; ENTRY_394; ENTRY_395; ENTRY_396; ENTRY_397; ENTRY_398; ENTRY_399;
+            ENTRY_400; ENTRY_401; ENTRY_402; ENTRY_403; ENTRY_404; ENTRY_405; ENTRY_406; ENTRY_407;
+            ENTRY_408; ENTRY_409; ENTRY_410; ENTRY_411; ENTRY_412; ENTRY_413; ENTRY_414; ENTRY_415;
+            ENTRY_416; ENTRY_417; ENTRY_418; ENTRY_419; ENTRY_420; ENTRY_421; ENTRY_422; ENTRY_423;
+            ENTRY_424; ENTRY_425; ENTRY_426; ENTRY_427; ENTRY_428; ENTRY_429; ENTRY_430; ENTRY_431;
+            ENTRY_432; ENTRY_433; ENTRY_434; ENTRY_435; ENTRY_436; ENTRY_437; ENTRY_438; ENTRY_439;
+            ENTRY_440; ENTRY_441; ENTRY_442; ENTRY_443; ENTRY_444; ENTRY_445; ENTRY_446; ENTRY_447;
+        }
+        group_7 {
+            ENTRY_448; ENTRY_449; ENTRY_450; ENTRY_451; ENTRY_452; ENTRY_453; ENTRY_454; ENTRY_455;
+            ENTRY_456; ENTRY_457; ENTRY_458; ENTRY_459; ENTRY_460; ENTRY_461; ENTRY_462; ENTRY_463;
+            ENTRY_464; ENTRY_465; ENTRY_466; ENTRY_467; ENTRY_468; ENTRY_469; ENTRY_470; ENTRY_471;
+            ENTRY_472; ENTRY_473; ENTRY_474; ENTRY_475; ENTRY_476; ENTRY_477; ENTRY_478; ENTRY_479;
+            ENTRY_480; ENTRY_481; ENTRY_482; ENTRY_483; ENTRY_484; ENTRY_485; ENTRY_486; ENTRY_487;
+            ENTRY_488; ENTRY_489; ENTRY_490; ENTRY_491; ENTRY_492; ENTRY_493; ENTRY_494; ENTRY_495;
+            ENTRY_496; ENTRY_497; ENTRY_498; ENTRY_499; ENTRY_500; ENTRY_501; ENTRY_502; ENTRY_503;
+            ENTRY_504; ENTRY_505; ENTRY_506; ENTRY_507; ENTRY_508; ENTRY_509; ENTRY_510; ENTRY_511;
+        }
+        group_8 {
+            ENTRY_512; ENTRY_513; ENTRY_514; ENTRY_515; ENTRY_516; ENTRY_517; ENTRY_518; ENTRY_519;
+            ENTRY_520; ENTRY_521; ENTRY_522; ENTRY_523; ENTRY_524; ENTRY_525; ENTRY_526; ENTRY_527;
+            ENTRY_528; ENTRY_529; ENTRY_530; ENTRY_531; ENTRY_532; ENTRY_533; ENTRY_534; ENTRY_535;
+            ENTRY_536; ENTRY_537; ENTRY_538; ENTRY_539; ENTRY_540; ENTRY_541; ENTRY_542; ENTRY_543;
+            ENTRY_544; ENTRY_545; ENTRY_546; ENTRY_547; ENTRY_548; ENTRY_549; ENTRY_550; ENTRY_551;
+            ENTRY_552; ENTRY_553; ENTRY_554; ENTRY_555; ENTRY_556; ENTRY_557; ENTRY_558; ENTRY_559;
+            ENTRY_560; ENTRY_561; ENTRY_562; ENTRY_563; ENTRY_564; ENTRY_565; ENTRY_566; ENTRY_567;
+            ENTRY_568; ENTRY_569; ENTRY_570; ENTRY_571; ENTRY_572; ENTRY_573; ENTRY_574; ENTRY_575;
+        }
+        group_9 {
+            ENTRY_576; ENTRY_577; ENTRY_578; ENTRY_579; ENTRY_580; ENTRY_581; ENTRY_582; ENTRY_583;
+            ENTRY_584; ENTRY_585; ENTRY_586; ENTRY_587; ENTRY_588; ENTRY_589; ENTRY_590; ENTRY_591;
+            ENTRY_592; ENTRY_593; ENTRY_594; ENTRY_595; ENTRY_596; ENTRY_597; ENTRY_598; ENTRY_599;
+            ENTRY_600; ENTRY_601; ENTRY_602; ENTRY_603; ENTRY_604; ENTRY_605; ENTRY_606; ENTRY_607;
+            ENTRY_608; ENTRY_609; ENTRY_610; ENTRY_611; ENTRY_612; ENTRY_613; ENTRY_614; ENTRY_615;
+            ENTRY_616; ENTRY_617; ENTRY_618; ENTRY_619; ENTRY_620; ENTRY_621; ENTRY_622; ENTRY_623;
+            ENTRY_624; ENTRY_625; ENTRY_626; ENTRY_627; ENTRY_628; ENTRY_629; ENTRY_630; ENTRY_631;
+            ENTRY_632; ENTRY_633; ENTRY_634; ENTRY_635; ENTRY_636; ENTRY_637; ENTRY_638; ENTRY_639;
+        }
+        group_10 {
+            ENTRY_640; ENTRY_641; ENTRY_642; ENTRY_643; ENTRY_644; ENTRY_645; ENTRY_646; ENTRY_647;
+            ENTRY_648; ENTRY_649; ENTRY_650; ENTRY_651; ENTRY_652; ENTRY_653; ENTRY_654; ENTRY_655;
+            ENTRY_656; ENTRY_657; ENTRY_658; ENTRY_659; ENTRY_660; ENTRY_661; ENTRY_662; ENTRY_663;
+            ENTRY_664; ENTRY_665; ENTRY_666; ENTRY_667; ENTRY_668; ENTRY_669; ENTRY_670; ENTRY_671;
+            ENTRY_672; ENTRY_673; ENTRY_674; ENTRY_675; ENTRY_676; ENTRY_677; ENTRY_678; ENTRY_679;
+            ENTRY_680; ENTRY_681; ENTRY_682; ENTRY_683; ENTRY_684; ENTRY_685; ENTRY_686; ENTRY_687;
+            ENTRY_688; ENTRY_689; ENTRY_690; ENTRY_691; ENTRY_692; ENTRY_693; ENTRY_694; ENTRY_695;
+            ENTRY_696; ENTRY_697; ENTRY_698; ENTRY_699; ENTRY_700; ENTRY_701; ENTRY_702; ENTRY_703;
+        }
+        group_11 {
+            ENTRY_704; ENTRY_705; ENTRY_706; ENTRY_707; ENTRY_708; ENTRY_709; ENTRY_710; ENTRY_711;
+            ENTRY_712; ENTRY_713; ENTRY_714; ENTRY_715; ENTRY_716; ENTRY_717; ENTRY_718; ENTRY_719;
+            ENTRY_720; ENTRY_721; ENTRY_722; ENTRY_723; ENTRY_724; ENTRY_725; ENTRY_726; ENTRY_727;
+            ENTRY_728; ENTRY_729; ENTRY_730; ENTRY_731; ENTRY_732; ENTRY_733; ENTRY_734; ENTRY_735;
+            ENTRY_736; ENTRY_737; ENTRY_738; ENTRY_739; ENTRY_740; ENTRY_741; ENTRY_742; ENTRY_743;
+            ENTRY_744; ENTRY_745; ENTRY_746; ENTRY_747; ENTRY_748; ENTRY_749; ENTRY_750; ENTRY_751;
+            ENTRY_752; ENTRY_753; ENTRY_754; ENTRY_755; ENTRY_756; ENTRY_757; ENTRY_758; ENTRY_759;
+            ENTRY_760; ENTRY_761; ENTRY_762; ENTRY_763; ENTRY_764; ENTRY_765; ENTRY_766; ENTRY_767;
+        }
+        group_12 {
+            ENTRY_768; ENTRY_769; ENTRY_770; ENTRY_771; ENTRY_772; ENTRY_773; ENTRY_774; ENTRY_775;
+            ENTRY_776; ENTRY_777; ENTRY_778; ENTRY_779; ENTRY_780; ENTRY_781; ENTRY_782; ENTRY_783;
+            ENTRY_784; ENTRY_785; ENTRY_786; ENTRY_787; ENTRY_788; ENTRY_789; ENTRY_790; ENTRY_791;
+            ENTRY_792; ENTRY_793; ENTRY_794; ENTRY_795; ENTRY_796; ENTRY_797; ENTRY_798; ENTRY_799;
+            ENTRY_800; ENTRY_801; ENTRY_802; ENTRY_803; ENTRY_804; ENTRY_805; ENTRY_806; ENTRY_807;
+            ENTRY_808; ENTRY_809; ENTRY_810; ENTRY_811; ENTRY_812; ENTRY_813; ENTRY_814; ENTRY_815;
+            ENTRY_816; ENTRY_817; ENTRY_818; ENTRY_819; ENTRY_820; ENTRY_821; ENTRY_822; ENTRY_823;
+            ENTRY_824; ENTRY_825; ENTRY_826; ENTRY_827; ENTRY_828; ENTRY_829; ENTRY_830; ENTRY_831;
+        }
+        group_13 {
+            ENTRY_832; ENTRY_833; ENTRY_834; ENTRY_835; ENTRY_836; ENTRY_837; ENTRY_838; ENTRY_839;
+            ENTRY_840; ENTRY_841; ENTRY_842; ENTRY_843; ENTRY_844; ENTRY_845; ENTRY_846; ENTRY_847;
+            ENTRY_848; ENTRY_849; ENTRY_850; ENTRY_851; ENTRY_852; ENTRY_853; ENTRY_854; ENTRY_855;
+            ENTRY_856; ENTRY_857; ENTRY_858; ENTRY_859; ENTRY_860; ENTRY_861; ENTRY_862; ENTRY_863;
+            ENTRY_864; ENTRY_865; ENTRY_866; ENTRY_867; ENTRY_868; ENTRY_869; ENTRY_870; ENTRY_871;
+            ENTRY_872; ENTRY_873; ENTRY_874; ENTRY_875; ENTRY_876; ENTRY_877; ENTRY_878; ENTRY_879;
+            ENTRY_880; ENTRY_881; ENTRY_882; ENTRY_883; ENTRY_884; ENTRY_885; ENTRY_886; ENTRY_887;
+            ENTRY_888; ENTRY_889; ENTRY_890; ENTRY_891; ENTRY_892; ENTRY_893; ENTRY_894; ENTRY_895;
+        }
+        group_14 {
+            ENTRY_896; ENTRY_897; ENTRY_898; ENTRY_899; ENTRY_900; ENTRY_901; ENTRY_902; ENTRY_903;
+            ENTRY_904; ENTRY_905; ENTRY_906; ENTRY_907; ENTRY_908; ENTRY_909; ENTRY_910; ENTRY_911;
+            ENTRY_912; ENTRY_913; ENTRY_914; ENTRY_915; ENTRY_916; ENTRY_917; ENTRY_918; ENTRY_919;
+            ENTRY_920; ENTRY_921; ENTRY_922; ENTRY_923; ENTRY_924; ENTRY_925; ENTRY_926; ENTRY_927;
+            ENTRY_928; ENTRY_929; ENTRY_930; ENTRY_931; ENTRY_932; ENTRY_933; ENTRY_934; ENTRY_935;
+            ENTRY_936; ENTRY_937; ENTRY_938; ENTRY_939; ENTRY_940; ENTRY_941; ENTRY_942; ENTRY_943;
+            ENTRY_944; ENTRY_945; ENTRY_946; ENTRY_947; ENTRY_948; ENTRY_949; ENTRY_950; ENTRY_951;
+            ENTRY_952; ENTRY_953; ENTRY_954; ENTRY_955; ENTRY_956; ENTRY_957; ENTRY_958; ENTRY_959;
+        }
+        group_15 {
+            ENTRY_960; ENTRY_961; ENTRY_962; ENTRY_963; ENTRY_964; ENTRY_965; ENTRY_966; ENTRY_967;
+            ENTRY_968; ENTRY_969; ENTRY_970; ENTRY_971; ENTRY_972; ENTRY_973; ENTRY_974; ENTRY_975;
+            ENTRY_976; ENTRY_977; ENTRY_978; ENTRY_979; ENTRY_980; ENTRY_981; ENTRY_982; ENTRY_983;
+            ENTRY_984; ENTRY_985; ENTRY_986; ENTRY_987; ENTRY_988; ENTRY_989; ENTRY_990; ENTRY_991;
+            ENTRY_992; ENTRY_993; ENTRY_994; ENTRY_995; ENTRY_996; ENTRY_997; ENTRY_998; ENTRY_999;
+            ENTRY_1000; ENTRY_1001; ENTRY_1002; ENTRY_1003; ENTRY_1004; ENTRY_1005; ENTRY_1006; ENTRY_1007;
+            ENTRY_1008; ENTRY_1009; ENTRY_1010; ENTRY_1011; ENTRY_1012; ENTRY_1013; ENTRY_1014; ENTRY_1015;
+            ENTRY_1016; ENTRY_1017; ENTRY_1018; ENTRY_1019; ENTRY_1020; ENTRY_1021; ENTRY_1022; ENTRY_1023;
+        }
+    }
+}
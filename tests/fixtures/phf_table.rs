@@ -0,0 +1,236 @@
+// Tables with the same 300 generated entries (`command_<i>` => i), shared by
+// `tests/phf.rs` and `benches/phf.rs`.
+
+/// Number of entries in [`PHF`] and [`TABLE`].
+const ENTRIES: usize = 300;
+
+static PHF: name_id::NameIdPhf<u32> = name_id::id_phf! {
+    command_0 => 0, command_1 => 1, command_2 => 2, command_3 => 3,
+    command_4 => 4, command_5 => 5, command_6 => 6, command_7 => 7,
+    command_8 => 8, command_9 => 9, command_10 => 10, command_11 => 11,
+    command_12 => 12, command_13 => 13, command_14 => 14, command_15 => 15,
+    command_16 => 16, command_17 => 17, command_18 => 18, command_19 => 19,
+    command_20 => 20, command_21 => 21, command_22 => 22, command_23 => 23,
+    command_24 => 24, command_25 => 25, command_26 => 26, command_27 => 27,
+    command_28 => 28, command_29 => 29, command_30 => 30, command_31 => 31,
+    command_32 => 32, command_33 => 33, command_34 => 34, command_35 => 35,
+    command_36 => 36, command_37 => 37, command_38 => 38, command_39 => 39,
+    command_40 => 40, command_41 => 41, command_42 => 42, command_43 => 43,
+    command_44 => 44, command_45 => 45, command_46 => 46, command_47 => 47,
+    command_48 => 48, command_49 => 49, command_50 => 50, command_51 => 51,
+    command_52 => 52, command_53 => 53, command_54 => 54, command_55 => 55,
+    command_56 => 56, command_57 => 57, command_58 => 58, command_59 => 59,
+    command_60 => 60, command_61 => 61, command_62 => 62, command_63 => 63,
+    command_64 => 64, command_65 => 65, command_66 => 66, command_67 => 67,
+    command_68 => 68, command_69 => 69, command_70 => 70, command_71 => 71,
+    command_72 => 72, command_73 => 73, command_74 => 74, command_75 => 75,
+    command_76 => 76, command_77 => 77, command_78 => 78, command_79 => 79,
+    command_80 => 80, command_81 => 81, command_82 => 82, command_83 => 83,
+    command_84 => 84, command_85 => 85, command_86 => 86, command_87 => 87,
+    command_88 => 88, command_89 => 89, command_90 => 90, command_91 => 91,
+    command_92 => 92, command_93 => 93, command_94 => 94, command_95 => 95,
+    command_96 => 96, command_97 => 97, command_98 => 98, command_99 => 99,
+    command_100 => 100, command_101 => 101, command_102 => 102, command_103 => 103,
+    command_104 => 104, command_105 => 105, command_106 => 106, command_107 => 107,
+    command_108 => 108, command_109 => 109, command_110 => 110, command_111 => 111,
+    command_112 => 112, command_113 => 113, command_114 => 114, command_115 => 115,
+    command_116 => 116, command_117 => 117, command_118 => 118, command_119 => 119,
+    command_120 => 120, command_121 => 121, command_122 => 122, command_123 => 123,
+    command_124 => 124, command_125 => 125, command_126 => 126, command_127 => 127,
+    command_128 => 128, command_129 => 129, command_130 => 130, command_131 => 131,
+    command_132 => 132, command_133 => 133, command_134 => 134, command_135 => 135,
+    command_136 => 136, command_137 => 137, command_138 => 138, command_139 => 139,
+    command_140 => 140, command_141 => 141, command_142 => 142, command_143 => 143,
+    command_144 => 144, command_145 => 145, command_146 => 146, command_147 => 147,
+    command_148 => 148, command_149 => 149, command_150 => 150, command_151 => 151,
+    command_152 => 152, command_153 => 153, command_154 => 154, command_155 => 155,
+    command_156 => 156, command_157 => 157, command_158 => 158, command_159 => 159,
+    command_160 => 160, command_161 => 161, command_162 => 162, command_163 => 163,
+    command_164 => 164, command_165 => 165, command_166 => 166, command_167 => 167,
+    command_168 => 168, command_169 => 169, command_170 => 170, command_171 => 171,
+    command_172 => 172, command_173 => 173, command_174 => 174, command_175 => 175,
+    command_176 => 176, command_177 => 177, command_178 => 178, command_179 => 179,
+    command_180 => 180, command_181 => 181, command_182 => 182, command_183 => 183,
+    command_184 => 184, command_185 => 185, command_186 => 186, command_187 => 187,
+    command_188 => 188, command_189 => 189, command_190 => 190, command_191 => 191,
+    command_192 => 192, command_193 => 193, command_194 => 194, command_195 => 195,
+    command_196 => 196, command_197 => 197, command_198 => 198, command_199 => 199,
+    command_200 => 200, command_201 => 201, command_202 => 202, command_203 => 203,
+    command_204 => 204, command_205 => 205, command_206 => 206, command_207 => 207,
+    command_208 => 208, command_209 => 209, command_210 => 210, command_211 => 211,
+    command_212 => 212, command_213 => 213, command_214 => 214, command_215 => 215,
+    command_216 => 216, command_217 => 217, command_218 => 218, command_219 => 219,
+    command_220 => 220, command_221 => 221, command_222 => 222, command_223 => 223,
+    command_224 => 224, command_225 => 225, command_226 => 226, command_227 => 227,
+    command_228 => 228, command_229 => 229, command_230 => 230, command_231 => 231,
+    command_232 => 232, command_233 => 233, command_234 => 234, command_235 => 235,
+    command_236 => 236, command_237 => 237, command_238 => 238, command_239 => 239,
+    command_240 => 240, command_241 => 241, command_242 => 242, command_243 => 243,
+    command_244 => 244, command_245 => 245, command_246 => 246, command_247 => 247,
+    command_248 => 248, command_249 => 249, command_250 => 250, command_251 => 251,
+    command_252 => 252, command_253 => 253, command_254 => 254, command_255 => 255,
+    command_256 => 256, command_257 => 257, command_258 => 258, command_259 => 259,
+    command_260 => 260, command_261 => 261, command_262 => 262, command_263 => 263,
+    command_264 => 264, command_265 => 265, command_266 => 266, command_267 => 267,
+    command_268 => 268, command_269 => 269, command_270 => 270, command_271 => 271,
+    command_272 => 272, command_273 => 273, command_274 => 274, command_275 => 275,
+    command_276 => 276, command_277 => 277, command_278 => 278, command_279 => 279,
+    command_280 => 280, command_281 => 281, command_282 => 282, command_283 => 283,
+    command_284 => 284, command_285 => 285, command_286 => 286, command_287 => 287,
+    command_288 => 288, command_289 => 289, command_290 => 290, command_291 => 291,
+    command_292 => 292, command_293 => 293, command_294 => 294, command_295 => 295,
+    command_296 => 296, command_297 => 297, command_298 => 298, command_299 => 299,
+};
+
+static TABLE: name_id::NameIdTable<u32, ENTRIES> = name_id::NameIdTable::new([
+    (name_id::id!(command_0), 0), (name_id::id!(command_1), 1),
+    (name_id::id!(command_2), 2), (name_id::id!(command_3), 3),
+    (name_id::id!(command_4), 4), (name_id::id!(command_5), 5),
+    (name_id::id!(command_6), 6), (name_id::id!(command_7), 7),
+    (name_id::id!(command_8), 8), (name_id::id!(command_9), 9),
+    (name_id::id!(command_10), 10), (name_id::id!(command_11), 11),
+    (name_id::id!(command_12), 12), (name_id::id!(command_13), 13),
+    (name_id::id!(command_14), 14), (name_id::id!(command_15), 15),
+    (name_id::id!(command_16), 16), (name_id::id!(command_17), 17),
+    (name_id::id!(command_18), 18), (name_id::id!(command_19), 19),
+    (name_id::id!(command_20), 20), (name_id::id!(command_21), 21),
+    (name_id::id!(command_22), 22), (name_id::id!(command_23), 23),
+    (name_id::id!(command_24), 24), (name_id::id!(command_25), 25),
+    (name_id::id!(command_26), 26), (name_id::id!(command_27), 27),
+    (name_id::id!(command_28), 28), (name_id::id!(command_29), 29),
+    (name_id::id!(command_30), 30), (name_id::id!(command_31), 31),
+    (name_id::id!(command_32), 32), (name_id::id!(command_33), 33),
+    (name_id::id!(command_34), 34), (name_id::id!(command_35), 35),
+    (name_id::id!(command_36), 36), (name_id::id!(command_37), 37),
+    (name_id::id!(command_38), 38), (name_id::id!(command_39), 39),
+    (name_id::id!(command_40), 40), (name_id::id!(command_41), 41),
+    (name_id::id!(command_42), 42), (name_id::id!(command_43), 43),
+    (name_id::id!(command_44), 44), (name_id::id!(command_45), 45),
+    (name_id::id!(command_46), 46), (name_id::id!(command_47), 47),
+    (name_id::id!(command_48), 48), (name_id::id!(command_49), 49),
+    (name_id::id!(command_50), 50), (name_id::id!(command_51), 51),
+    (name_id::id!(command_52), 52), (name_id::id!(command_53), 53),
+    (name_id::id!(command_54), 54), (name_id::id!(command_55), 55),
+    (name_id::id!(command_56), 56), (name_id::id!(command_57), 57),
+    (name_id::id!(command_58), 58), (name_id::id!(command_59), 59),
+    (name_id::id!(command_60), 60), (name_id::id!(command_61), 61),
+    (name_id::id!(command_62), 62), (name_id::id!(command_63), 63),
+    (name_id::id!(command_64), 64), (name_id::id!(command_65), 65),
+    (name_id::id!(command_66), 66), (name_id::id!(command_67), 67),
+    (name_id::id!(command_68), 68), (name_id::id!(command_69), 69),
+    (name_id::id!(command_70), 70), (name_id::id!(command_71), 71),
+    (name_id::id!(command_72), 72), (name_id::id!(command_73), 73),
+    (name_id::id!(command_74), 74), (name_id::id!(command_75), 75),
+    (name_id::id!(command_76), 76), (name_id::id!(command_77), 77),
+    (name_id::id!(command_78), 78), (name_id::id!(command_79), 79),
+    (name_id::id!(command_80), 80), (name_id::id!(command_81), 81),
+    (name_id::id!(command_82), 82), (name_id::id!(command_83), 83),
+    (name_id::id!(command_84), 84), (name_id::id!(command_85), 85),
+    (name_id::id!(command_86), 86), (name_id::id!(command_87), 87),
+    (name_id::id!(command_88), 88), (name_id::id!(command_89), 89),
+    (name_id::id!(command_90), 90), (name_id::id!(command_91), 91),
+    (name_id::id!(command_92), 92), (name_id::id!(command_93), 93),
+    (name_id::id!(command_94), 94), (name_id::id!(command_95), 95),
+    (name_id::id!(command_96), 96), (name_id::id!(command_97), 97),
+    (name_id::id!(command_98), 98), (name_id::id!(command_99), 99),
+    (name_id::id!(command_100), 100), (name_id::id!(command_101), 101),
+    (name_id::id!(command_102), 102), (name_id::id!(command_103), 103),
+    (name_id::id!(command_104), 104), (name_id::id!(command_105), 105),
+    (name_id::id!(command_106), 106), (name_id::id!(command_107), 107),
+    (name_id::id!(command_108), 108), (name_id::id!(command_109), 109),
+    (name_id::id!(command_110), 110), (name_id::id!(command_111), 111),
+    (name_id::id!(command_112), 112), (name_id::id!(command_113), 113),
+    (name_id::id!(command_114), 114), (name_id::id!(command_115), 115),
+    (name_id::id!(command_116), 116), (name_id::id!(command_117), 117),
+    (name_id::id!(command_118), 118), (name_id::id!(command_119), 119),
+    (name_id::id!(command_120), 120), (name_id::id!(command_121), 121),
+    (name_id::id!(command_122), 122), (name_id::id!(command_123), 123),
+    (name_id::id!(command_124), 124), (name_id::id!(command_125), 125),
+    (name_id::id!(command_126), 126), (name_id::id!(command_127), 127),
+    (name_id::id!(command_128), 128), (name_id::id!(command_129), 129),
+    (name_id::id!(command_130), 130), (name_id::id!(command_131), 131),
+    (name_id::id!(command_132), 132), (name_id::id!(command_133), 133),
+    (name_id::id!(command_134), 134), (name_id::id!(command_135), 135),
+    (name_id::id!(command_136), 136), (name_id::id!(command_137), 137),
+    (name_id::id!(command_138), 138), (name_id::id!(command_139), 139),
+    (name_id::id!(command_140), 140), (name_id::id!(command_141), 141),
+    (name_id::id!(command_142), 142), (name_id::id!(command_143), 143),
+    (name_id::id!(command_144), 144), (name_id::id!(command_145), 145),
+    (name_id::id!(command_146), 146), (name_id::id!(command_147), 147),
+    (name_id::id!(command_148), 148), (name_id::id!(command_149), 149),
+    (name_id::id!(command_150), 150), (name_id::id!(command_151), 151),
+    (name_id::id!(command_152), 152), (name_id::id!(command_153), 153),
+    (name_id::id!(command_154), 154), (name_id::id!(command_155), 155),
+    (name_id::id!(command_156), 156), (name_id::id!(command_157), 157),
+    (name_id::id!(command_158), 158), (name_id::id!(command_159), 159),
+    (name_id::id!(command_160), 160), (name_id::id!(command_161), 161),
+    (name_id::id!(command_162), 162), (name_id::id!(command_163), 163),
+    (name_id::id!(command_164), 164), (name_id::id!(command_165), 165),
+    (name_id::id!(command_166), 166), (name_id::id!(command_167), 167),
+    (name_id::id!(command_168), 168), (name_id::id!(command_169), 169),
+    (name_id::id!(command_170), 170), (name_id::id!(command_171), 171),
+    (name_id::id!(command_172), 172), (name_id::id!(command_173), 173),
+    (name_id::id!(command_174), 174), (name_id::id!(command_175), 175),
+    (name_id::id!(command_176), 176), (name_id::id!(command_177), 177),
+    (name_id::id!(command_178), 178), (name_id::id!(command_179), 179),
+    (name_id::id!(command_180), 180), (name_id::id!(command_181), 181),
+    (name_id::id!(command_182), 182), (name_id::id!(command_183), 183),
+    (name_id::id!(command_184), 184), (name_id::id!(command_185), 185),
+    (name_id::id!(command_186), 186), (name_id::id!(command_187), 187),
+    (name_id::id!(command_188), 188), (name_id::id!(command_189), 189),
+    (name_id::id!(command_190), 190), (name_id::id!(command_191), 191),
+    (name_id::id!(command_192), 192), (name_id::id!(command_193), 193),
+    (name_id::id!(command_194), 194), (name_id::id!(command_195), 195),
+    (name_id::id!(command_196), 196), (name_id::id!(command_197), 197),
+    (name_id::id!(command_198), 198), (name_id::id!(command_199), 199),
+    (name_id::id!(command_200), 200), (name_id::id!(command_201), 201),
+    (name_id::id!(command_202), 202), (name_id::id!(command_203), 203),
+    (name_id::id!(command_204), 204), (name_id::id!(command_205), 205),
+    (name_id::id!(command_206), 206), (name_id::id!(command_207), 207),
+    (name_id::id!(command_208), 208), (name_id::id!(command_209), 209),
+    (name_id::id!(command_210), 210), (name_id::id!(command_211), 211),
+    (name_id::id!(command_212), 212), (name_id::id!(command_213), 213),
+    (name_id::id!(command_214), 214), (name_id::id!(command_215), 215),
+    (name_id::id!(command_216), 216), (name_id::id!(command_217), 217),
+    (name_id::id!(command_218), 218), (name_id::id!(command_219), 219),
+    (name_id::id!(command_220), 220), (name_id::id!(command_221), 221),
+    (name_id::id!(command_222), 222), (name_id::id!(command_223), 223),
+    (name_id::id!(command_224), 224), (name_id::id!(command_225), 225),
+    (name_id::id!(command_226), 226), (name_id::id!(command_227), 227),
+    (name_id::id!(command_228), 228), (name_id::id!(command_229), 229),
+    (name_id::id!(command_230), 230), (name_id::id!(command_231), 231),
+    (name_id::id!(command_232), 232), (name_id::id!(command_233), 233),
+    (name_id::id!(command_234), 234), (name_id::id!(command_235), 235),
+    (name_id::id!(command_236), 236), (name_id::id!(command_237), 237),
+    (name_id::id!(command_238), 238), (name_id::id!(command_239), 239),
+    (name_id::id!(command_240), 240), (name_id::id!(command_241), 241),
+    (name_id::id!(command_242), 242), (name_id::id!(command_243), 243),
+    (name_id::id!(command_244), 244), (name_id::id!(command_245), 245),
+    (name_id::id!(command_246), 246), (name_id::id!(command_247), 247),
+    (name_id::id!(command_248), 248), (name_id::id!(command_249), 249),
+    (name_id::id!(command_250), 250), (name_id::id!(command_251), 251),
+    (name_id::id!(command_252), 252), (name_id::id!(command_253), 253),
+    (name_id::id!(command_254), 254), (name_id::id!(command_255), 255),
+    (name_id::id!(command_256), 256), (name_id::id!(command_257), 257),
+    (name_id::id!(command_258), 258), (name_id::id!(command_259), 259),
+    (name_id::id!(command_260), 260), (name_id::id!(command_261), 261),
+    (name_id::id!(command_262), 262), (name_id::id!(command_263), 263),
+    (name_id::id!(command_264), 264), (name_id::id!(command_265), 265),
+    (name_id::id!(command_266), 266), (name_id::id!(command_267), 267),
+    (name_id::id!(command_268), 268), (name_id::id!(command_269), 269),
+    (name_id::id!(command_270), 270), (name_id::id!(command_271), 271),
+    (name_id::id!(command_272), 272), (name_id::id!(command_273), 273),
+    (name_id::id!(command_274), 274), (name_id::id!(command_275), 275),
+    (name_id::id!(command_276), 276), (name_id::id!(command_277), 277),
+    (name_id::id!(command_278), 278), (name_id::id!(command_279), 279),
+    (name_id::id!(command_280), 280), (name_id::id!(command_281), 281),
+    (name_id::id!(command_282), 282), (name_id::id!(command_283), 283),
+    (name_id::id!(command_284), 284), (name_id::id!(command_285), 285),
+    (name_id::id!(command_286), 286), (name_id::id!(command_287), 287),
+    (name_id::id!(command_288), 288), (name_id::id!(command_289), 289),
+    (name_id::id!(command_290), 290), (name_id::id!(command_291), 291),
+    (name_id::id!(command_292), 292), (name_id::id!(command_293), 293),
+    (name_id::id!(command_294), 294), (name_id::id!(command_295), 295),
+    (name_id::id!(command_296), 296), (name_id::id!(command_297), 297),
+    (name_id::id!(command_298), 298), (name_id::id!(command_299), 299),
+]);
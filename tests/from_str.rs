@@ -0,0 +1,53 @@
+use name_id::{NameId, ParseIdError};
+
+#[test]
+fn parses_names() {
+    assert_eq!("player_spawn".parse(), Ok(NameId::new("player_spawn")));
+    assert_eq!("x1a2b".parse(), Ok(NameId::new("x1a2b")));
+    assert_eq!("".parse(), Ok(NameId::new("")));
+}
+
+#[test]
+fn parses_values() {
+    assert_eq!("0x1a2b".parse(), Ok(NameId::from_value(0x1a2b)));
+    assert_eq!("0X1A2B".parse(), Ok(NameId::from_value(0x1a2b)));
+    assert_eq!("6699".parse(), Ok(NameId::from_value(6699)));
+    assert_eq!("NameId(6699)".parse(), Ok(NameId::from_value(6699)));
+    assert_eq!(
+        "0xffffffffffffffff".parse(),
+        Ok(NameId::from_value(u64::MAX))
+    );
+}
+
+#[test]
+fn parses_display_output() {
+    let unnamed = NameId::from_value(0x1a2b);
+    assert_eq!(unnamed.to_string().parse(), Ok(unnamed));
+    assert_eq!("#{42}".parse(), Ok(NameId::new("42")));
+    assert_eq!("#{player_spawn}".parse(), Ok(NameId::new("player_spawn")));
+}
+
+#[test]
+fn rejects_invalid_values() {
+    assert_eq!("0x".parse::<NameId>(), Err(ParseIdError::InvalidHex));
+    assert_eq!("0xabcg".parse::<NameId>(), Err(ParseIdError::InvalidHex));
+    assert_eq!("0x+1".parse::<NameId>(), Err(ParseIdError::InvalidHex));
+    assert_eq!(
+        "0x10000000000000000".parse::<NameId>(),
+        Err(ParseIdError::Overflow)
+    );
+    assert_eq!(
+        "18446744073709551616".parse::<NameId>(),
+        Err(ParseIdError::Overflow)
+    );
+}
+
+#[cfg(all(
+    any(feature = "registry", feature = "registry_static"),
+    feature = "alloc"
+))]
+#[test]
+fn registers_names() {
+    let id: NameId = "parsed_door".parse().unwrap();
+    assert_eq!(id.resolve(), Some("parsed_door"));
+}
@@ -0,0 +1,70 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use name_id::NameId;
+
+/// Counts allocations made by the current thread, as tests run in parallel.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|it| it.set(it.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+fn allocations_in(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn equals_static_conversion() {
+    let buffer = String::from("open door;close door;;ünïcödé");
+    for (name, expected) in buffer.split(';').zip([
+        NameId::new("open door"),
+        NameId::new("close door"),
+        NameId::new(""),
+        NameId::new("ünïcödé"),
+    ]) {
+        assert_eq!(NameId::from_str_ref(name), expected);
+    }
+}
+
+#[cfg(not(any(feature = "detect_collisions", feature = "detect_collisions_static")))]
+#[test]
+fn no_allocations() {
+    let buffer = String::from("from_str_ref.unallocated");
+    let mut id = None;
+    assert_eq!(
+        allocations_in(|| id = Some(NameId::from_str_ref(&buffer))),
+        0
+    );
+    assert_eq!(id, Some(NameId::new("from_str_ref.unallocated")));
+}
+
+#[cfg(feature = "detect_collisions")]
+#[test]
+fn registers_name_once() {
+    let buffer = String::from("from_str_ref.registered");
+    let id = NameId::from_str_ref(&buffer);
+    assert!(name_id::registry_contains(id));
+    assert_eq!(
+        allocations_in(|| {
+            NameId::from_str_ref(&buffer);
+        }),
+        0
+    );
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "godot")]
+
+//! `StringName` and `GString` can't be constructed without a running engine,
+//! so these tests check hashing of their characters through
+//! `NameId::from_chars`, which the conversions delegate to.
+
+use name_id::{id, NameId};
+
+fn chars(name: &str) -> Vec<char> {
+    name.chars().collect()
+}
+
+#[test]
+fn matches_str() {
+    assert_eq!(
+        NameId::from_chars(&chars("player_spawn")),
+        id!(player_spawn)
+    );
+    assert_eq!(NameId::from_chars(&[]), id!(""));
+    assert_eq!(NameId::from_chars(&chars("ünïcødé ✓")), id!("ünïcødé ✓"));
+}
+
+#[test]
+fn long_names() {
+    // longer than names encoded on the stack
+    let name = "node_".repeat(100);
+    assert_eq!(NameId::from_chars(&chars(&name)), NameId::new(name.clone()));
+    let name = "✓".repeat(86);
+    assert_eq!(name.len(), 258);
+    assert_eq!(NameId::from_chars(&chars(&name)), NameId::new(name.clone()));
+}
+
+#[cfg(all(debug_assertions, feature = "debug_name"))]
+#[test]
+fn keeps_label() {
+    let id = NameId::from_chars(&chars("player_spawn"));
+    assert_eq!(id.to_string(), "#{player_spawn}");
+}
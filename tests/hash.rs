@@ -0,0 +1,62 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use name_id::{id, IdentityBuildHasher};
+
+/// Records all calls made by a `Hash` implementation.
+#[derive(Default)]
+struct RecordingHasher {
+    bytes: usize,
+    u64_values: Vec<u64>,
+}
+
+impl Hasher for RecordingHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes += bytes.len();
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.u64_values.push(value);
+    }
+}
+
+#[test]
+fn hash_writes_single_u64() {
+    // IdentityHasher relies on this
+    let id = id!(frame_entity);
+    let mut hasher = RecordingHasher::default();
+    id.hash(&mut hasher);
+    assert_eq!(hasher.bytes, 0);
+    assert_eq!(hasher.u64_values, [id.value()]);
+}
+
+#[test]
+fn identity() {
+    let id = id!(frame_entity);
+    assert_eq!(IdentityBuildHasher.hash_one(id), id.value());
+}
+
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+#[test]
+fn id_hash_map() {
+    let mut map = name_id::IdHashMap::default();
+    let mut set = name_id::IdHashSet::default();
+    for i in 0..100u64 {
+        map.insert(name_id::NameId::from_value(i * 31), i);
+        set.insert(name_id::NameId::from_value(i));
+    }
+    assert_eq!(map.get(&name_id::NameId::from_value(31 * 7)), Some(&7));
+    assert!(set.contains(&name_id::NameId::from_value(99)));
+    assert!(!set.contains(&id!(frame_entity)));
+}
+
+#[cfg(feature = "nohash")]
+#[test]
+fn nohash() {
+    let mut map: nohash_hasher::IntMap<name_id::NameId, u8> = Default::default();
+    map.insert(id!(frame_entity), 1);
+    assert_eq!(map[&id!(frame_entity)], 1);
+}
@@ -0,0 +1,63 @@
+#![cfg(any(feature = "heapless", feature = "hash32"))]
+
+use name_id::{id, NameId};
+
+#[cfg(feature = "heapless")]
+#[test]
+fn fnv_index_map() {
+    let mut map: heapless::FnvIndexMap<NameId, u8, 16> = heapless::FnvIndexMap::new();
+    for i in 0..16u8 {
+        map.insert(NameId::from_value(i as u64 * 7919), i).unwrap();
+    }
+    assert!(map.insert(id!(table_overflow), 0).is_err());
+    assert_eq!(map.get(&NameId::from_value(5 * 7919)), Some(&5));
+    assert_eq!(map.get(&id!(table_missing)), None);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn id_index_map() {
+    let mut map: name_id::IdIndexMap<&str, 16> = name_id::IdIndexMap::default();
+    map.insert(id!(table_a), "a").unwrap();
+    map.insert(id!(table_b), "b").unwrap();
+    assert_eq!(map[&id!(table_a)], "a");
+    assert_eq!(map.get(&id!(table_c)), None);
+}
+
+#[cfg(feature = "hash32")]
+#[test]
+fn hash32_folding() {
+    use hash32::{BuildHasher, Hash, Hasher};
+
+    let id = NameId::from_value(0x1234_5678_0000_ffff);
+    let mut hasher = name_id::IdentityBuildHasher.build_hasher();
+    Hash::hash(&id, &mut hasher);
+    assert_eq!(hasher.finish(), 0x1234_5678 ^ 0x0000_ffff);
+}
+
+#[cfg(feature = "hash32")]
+#[test]
+fn heapless_07_fnv_index_map() {
+    let mut map: heapless07::FnvIndexMap<NameId, u8, 16> = heapless07::FnvIndexMap::new();
+    map.insert(id!(table_a), 1).unwrap();
+    map.insert(id!(table_b), 2).unwrap();
+    assert_eq!(map.get(&id!(table_b)), Some(&2));
+
+    let mut map: heapless07::IndexMap<NameId, u8, name_id::IdentityBuildHasher, 16> =
+        heapless07::IndexMap::default();
+    map.insert(id!(table_a), 1).unwrap();
+    assert_eq!(map.get(&id!(table_a)), Some(&1));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn string_parity() {
+    fn check<const N: usize>(name: &'static str) {
+        let string: heapless::String<N> = name.try_into().unwrap();
+        assert_eq!(NameId::from(&string).value(), NameId::new(name).value());
+    }
+    check::<16>("player_spawn");
+    check::<16>("");
+    check::<32>("player_spawn");
+    check::<64>("a name that doesn't fit into 32 bytes");
+}
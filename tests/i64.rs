@@ -0,0 +1,30 @@
+use name_id::{id, NameId};
+
+#[test]
+fn round_trip_high_bit() {
+    for value in [
+        0,
+        1,
+        i64::MAX as u64,
+        1 << 63,
+        u64::MAX,
+        0xdead_beef_0000_0001 | 1 << 63,
+    ] {
+        let id = NameId::from_value(value);
+        let signed = id.value_i64();
+        assert_eq!(signed, value as i64);
+        assert_eq!(NameId::from_raw_i64(signed), id);
+        assert_eq!(i64::from(id), signed);
+        assert_eq!(NameId::from(signed), id);
+    }
+    assert_eq!(NameId::from_value(1 << 63).value_i64(), i64::MIN);
+    assert_eq!(NameId::from_value(u64::MAX).value_i64(), -1);
+}
+
+#[test]
+fn const_conversions() {
+    const SIGNED: i64 = id!(player_spawn).value_i64();
+    const RESTORED: NameId = NameId::from_raw_i64(SIGNED);
+    assert_eq!(RESTORED, id!(player_spawn));
+    assert_eq!(RESTORED.value(), SIGNED as u64);
+}
@@ -0,0 +1,48 @@
+use name_id::{id, id_seq, NameId};
+
+const BONES: [NameId; 64] = id_seq!("bone_", 0..64);
+
+#[cfg(feature = "alloc")]
+#[test]
+fn matches_runtime_names() {
+    for (i, bone) in BONES.iter().enumerate() {
+        assert_eq!(*bone, NameId::new(format!("bone_{i}")));
+    }
+}
+
+#[test]
+fn inclusive_range() {
+    let ids: [NameId; 3] = id_seq!("slot", 7..=9);
+    assert_eq!(ids, [id!("slot7"), id!("slot8"), id!("slot9")]);
+}
+
+#[test]
+fn single_element() {
+    let ids: [NameId; 1] = id_seq!("only_", 5..=5);
+    assert_eq!(ids, [id!("only_5")]);
+}
+
+#[test]
+fn width_and_suffix() {
+    let ids = id_seq!("lod_", 8..11, width = 3, suffix = "_high");
+    assert_eq!(
+        ids,
+        [
+            id!("lod_008_high"),
+            id!("lod_009_high"),
+            id!("lod_010_high")
+        ]
+    );
+
+    let ids = id_seq!("frame", 99..=100, width = 2);
+    assert_eq!(ids, [id!("frame99"), id!("frame100")]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn width_matches_runtime_format() {
+    let ids = id_seq!("tile_", 0..=20, width = 3, suffix = ".png");
+    for (i, tile) in ids.iter().enumerate() {
+        assert_eq!(*tile, NameId::new(format!("tile_{i:03}.png")));
+    }
+}
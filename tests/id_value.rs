@@ -0,0 +1,42 @@
+use name_id::{id, id_value, NameId};
+
+#[repr(u64)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    PlayerSpawn = id_value!(player_spawn),
+    DoorOpen = id_value!("door open"),
+    Level = id_value!(42),
+}
+
+fn event_of(id: NameId) -> Option<Event> {
+    match id.value() {
+        id_value!(player_spawn) => Some(Event::PlayerSpawn),
+        id_value!("door open") => Some(Event::DoorOpen),
+        id_value!(42) => Some(Event::Level),
+        _ => None,
+    }
+}
+
+#[test]
+fn matches_id_macro() {
+    assert_eq!(id_value!(player_spawn), id!(player_spawn).value());
+    assert_eq!(id_value!("door open"), id!("door open").value());
+    assert_eq!(id_value!(door open), id!("door open").value());
+    assert_eq!(id_value!('c'), id!('c').value());
+    assert_eq!(id_value!(true), id!(true).value());
+}
+
+#[test]
+fn enum_discriminant() {
+    assert_eq!(Event::PlayerSpawn as u64, id!(player_spawn).value());
+    assert_eq!(Event::DoorOpen as u64, id!("door open").value());
+    assert_eq!(Event::Level as u64, id!(42).value());
+}
+
+#[test]
+fn match_over_value() {
+    assert_eq!(event_of(id!(player_spawn)), Some(Event::PlayerSpawn));
+    assert_eq!(event_of(id!("door open")), Some(Event::DoorOpen));
+    assert_eq!(event_of(id!(42)), Some(Event::Level));
+    assert_eq!(event_of(id!(unknown)), None);
+}
@@ -0,0 +1,101 @@
+use name_id::{id, id_wrapper, NameId};
+
+id_wrapper! {
+    /// Id of a texture asset.
+    pub struct TextureId(prefix = "tex.");
+}
+id_wrapper!(
+    pub struct SoundId;
+);
+
+const WOOD: TextureId = TextureId::new(id!("tex.wood"));
+const STEP: SoundId = SoundId::from_raw(id!(step).value());
+
+// Wrappers of different types aren't interchangeable.
+trait Kind {
+    const KIND: &'static str;
+}
+impl Kind for TextureId {
+    const KIND: &'static str = "texture";
+}
+impl Kind for SoundId {
+    const KIND: &'static str = "sound";
+}
+
+fn kind_of<T: Kind>(_: T) -> &'static str {
+    T::KIND
+}
+
+#[test]
+fn distinct_types() {
+    let texture = TextureId::new(id!(same));
+    let sound = SoundId::new(id!(same));
+    assert_eq!(texture.id(), sound.id());
+    assert_eq!(kind_of(texture), "texture");
+    assert_eq!(kind_of(sound), "sound");
+    assert_eq!(
+        core::mem::size_of::<TextureId>(),
+        core::mem::size_of::<NameId>()
+    );
+}
+
+#[test]
+fn accessors_and_conversions() {
+    assert_eq!(WOOD.id(), id!("tex.wood"));
+    assert_eq!(WOOD.value(), id!("tex.wood").value());
+    assert_eq!(STEP.id(), id!(step));
+    assert_eq!(NameId::from(WOOD), id!("tex.wood"));
+    assert_eq!(TextureId::from(id!("tex.wood")), WOOD);
+    assert_eq!(*WOOD.as_ref(), id!("tex.wood"));
+    assert_eq!(TextureId::PREFIX, "tex.");
+    assert_eq!(SoundId::PREFIX, "");
+}
+
+#[test]
+fn ordering_matches_id() {
+    let a = SoundId::from_raw(1);
+    let b = SoundId::from_raw(2);
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), a.id().cmp(&b.id()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn prefix() {
+    assert_eq!(TextureId::named("wood"), WOOD);
+    assert_eq!(TextureId::named("wood").id(), NameId::new("tex.wood"));
+    assert_ne!(TextureId::named("wood").id(), id!(wood));
+    assert_eq!(SoundId::named("step"), STEP);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn formatting_forwarded() {
+    assert_eq!(format!("{}", WOOD), format!("{}", WOOD.id()));
+    assert_eq!(format!("{:?}", STEP), format!("{:?}", STEP.id()));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_forwarded() {
+    let json = serde_json::to_string(&WOOD).unwrap();
+    assert_eq!(json, serde_json::to_string(&WOOD.id()).unwrap());
+    assert_eq!(serde_json::from_str::<TextureId>(&json).unwrap(), WOOD);
+}
+
+#[cfg(all(
+    feature = "bytemuck",
+    not(all(debug_assertions, feature = "debug_name"))
+))]
+#[test]
+fn bytemuck_forwarded() {
+    use bytemuck::TransparentWrapper;
+
+    let ids = [id!(a), id!(b)];
+    let textures: &[TextureId] = TextureId::wrap_slice(&ids);
+    assert_eq!(textures[1].id(), id!(b));
+    assert_eq!(
+        bytemuck::bytes_of(&WOOD),
+        bytemuck::bytes_of(&id!("tex.wood"))
+    );
+}
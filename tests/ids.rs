@@ -0,0 +1,160 @@
+use name_id::{id, ids, NameId};
+
+include!("fixtures/ids_large.rs");
+
+ids! {
+    ROOT;
+    pub(crate) PLAIN = "plain name";
+
+    ui {
+        button {
+            SAVE = "save";
+            CANCEL;
+        }
+        /// Doc comments are forwarded.
+        LABEL;
+    }
+    audio {
+        music {
+            MENU;
+        }
+    }
+    #[name_id(separator = "/")]
+    assets {
+        textures {
+            GRASS;
+        }
+        #[name_id(separator = "::")]
+        sounds {
+            STEP = "step";
+        }
+    }
+    #[allow(dead_code)]
+    unused {
+        pub(super) HIDDEN;
+    }
+}
+
+#[test]
+fn top_level() {
+    assert_eq!(ROOT, id!("root"));
+    assert_eq!(PLAIN, id!("plain name"));
+}
+
+#[test]
+fn nested_paths() {
+    assert_eq!(ui::button::SAVE, id!("ui.button.save"));
+    assert_eq!(ui::LABEL, id!("ui.label"));
+    assert_eq!(audio::music::MENU, id!("audio.music.menu"));
+}
+
+#[test]
+fn implicit_name() {
+    assert_eq!(ui::button::CANCEL, id!("ui.button.cancel"));
+    assert_eq!(unused::HIDDEN, id!("unused.hidden"));
+}
+
+#[test]
+fn separator_override() {
+    assert_eq!(assets::textures::GRASS, id!("assets/textures/grass"));
+    assert_eq!(assets::sounds::STEP, id!("assets/sounds::step"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn matches_runtime_names() {
+    let ids: [(NameId, &str); 3] = [
+        (ui::button::SAVE, "ui.button.save"),
+        (ui::button::CANCEL, "ui.button.cancel"),
+        (audio::music::MENU, "audio.music.menu"),
+    ];
+    for (id, name) in ids {
+        assert_eq!(id, NameId::new(name.to_string()));
+    }
+}
+
+#[test]
+fn large_block() {
+    assert_eq!(large::group_0::ENTRY_0, id!("large.group_0.entry_0"));
+    assert_eq!(large::group_7::ENTRY_500, id!("large.group_7.entry_500"));
+    assert_eq!(
+        large::group_15::ENTRY_1023,
+        id!("large.group_15.entry_1023")
+    );
+}
+
+mod ordered {
+    name_id::ids! {
+        pub protocol {
+            PING;
+            PONG;
+            handshake {
+                HELLO;
+                ACK;
+            }
+        }
+    }
+}
+
+mod reordered {
+    name_id::ids! {
+        pub protocol {
+            handshake {
+                ACK;
+                HELLO;
+            }
+            PONG;
+            PING;
+        }
+    }
+}
+
+mod renamed {
+    name_id::ids! {
+        pub protocol {
+            PING;
+            PONG = "pong_v2";
+            handshake {
+                HELLO;
+                ACK;
+            }
+        }
+    }
+}
+
+#[test]
+fn group_fingerprint_ignores_order() {
+    assert_eq!(
+        ordered::protocol::GROUP_FINGERPRINT,
+        reordered::protocol::GROUP_FINGERPRINT
+    );
+    assert_eq!(
+        ordered::protocol::handshake::GROUP_FINGERPRINT,
+        reordered::protocol::handshake::GROUP_FINGERPRINT
+    );
+}
+
+#[test]
+fn group_fingerprint_changes_on_rename() {
+    assert_ne!(
+        ordered::protocol::GROUP_FINGERPRINT,
+        renamed::protocol::GROUP_FINGERPRINT
+    );
+    // nested groups only cover their own members
+    assert_eq!(
+        ordered::protocol::handshake::GROUP_FINGERPRINT,
+        renamed::protocol::handshake::GROUP_FINGERPRINT
+    );
+    assert_ne!(
+        ordered::protocol::GROUP_FINGERPRINT,
+        ordered::protocol::handshake::GROUP_FINGERPRINT
+    );
+}
+
+#[test]
+fn group_len() {
+    assert_eq!(ordered::protocol::GROUP_LEN, 4);
+    assert_eq!(ordered::protocol::handshake::GROUP_LEN, 2);
+    assert_eq!(ui::GROUP_LEN, 3);
+    assert_eq!(large::GROUP_LEN, 1024);
+}
@@ -0,0 +1,85 @@
+use name_id::{id, ids, NameId};
+
+ids! {
+    #[name_id(from_name)]
+    event {
+        SPAWN;
+        DESPAWN;
+        SAVE = "save";
+        SHORT = "s";
+        EMPTY = "";
+        nested {
+            INNER;
+        }
+    }
+    plain {
+        OTHER;
+    }
+}
+
+#[test]
+fn declared_name() {
+    assert_eq!(event::from_name("spawn"), Some(event::SPAWN));
+    assert_eq!(event::from_name("despawn"), Some(event::DESPAWN));
+    assert_eq!(event::from_name("save"), Some(event::SAVE));
+    assert_eq!(event::from_name("s"), Some(event::SHORT));
+    assert_eq!(event::from_name(""), Some(event::EMPTY));
+}
+
+#[test]
+fn declared_id() {
+    assert_eq!(event::from_id(event::SPAWN), Some("spawn"));
+    assert_eq!(event::from_id(id!("event.save")), Some("save"));
+    assert_eq!(event::from_id(event::EMPTY), Some(""));
+    const NAME: Option<&str> = event::from_id(event::DESPAWN);
+    assert_eq!(NAME, Some("despawn"));
+}
+
+#[test]
+fn undeclared_name() {
+    assert_eq!(event::from_name("sav"), None);
+    assert_eq!(event::from_name("saves"), None);
+    assert_eq!(event::from_name("event.save"), None);
+    assert_eq!(event::from_id(id!(save)), None);
+}
+
+#[test]
+fn case_sensitive() {
+    assert_eq!(event::from_name("SAVE"), None);
+    assert_eq!(event::from_name("Spawn"), None);
+}
+
+#[test]
+fn direct_members_only() {
+    assert_eq!(event::from_name("inner"), None);
+    assert_eq!(event::from_id(event::nested::INNER), None);
+}
+
+#[test]
+fn raw_value_matches_id() {
+    // ids are matched by value, regardless of how they were constructed
+    let id = NameId::from_value(event::SAVE.value());
+    assert_eq!(event::from_id(id), Some("save"));
+}
+
+// "cbd4938f3322cbc5f" and "ce6aa744e6a6c1f41" have equal hashes with ahash.
+#[cfg(feature = "ahash")]
+mod collision {
+    use name_id::{ids, NameId};
+
+    ids! {
+        #[name_id(separator = "", from_name)]
+        c {
+            DECLARED = "bd4938f3322cbc5f";
+        }
+    }
+
+    #[test]
+    fn colliding_name() {
+        let colliding = NameId::new("ce6aa744e6a6c1f41");
+        assert_eq!(colliding, c::DECLARED);
+        assert_eq!(c::from_id(colliding), Some("bd4938f3322cbc5f"));
+        assert_eq!(c::from_name("e6aa744e6a6c1f41"), None);
+        assert_eq!(c::from_name("bd4938f3322cbc5f"), Some(c::DECLARED));
+    }
+}
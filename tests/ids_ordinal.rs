@@ -0,0 +1,52 @@
+#![cfg(not(feature = "dynamic_hasher"))]
+
+use name_id::{id, ids, NameId};
+
+ids! {
+    #[name_id(ordinal)]
+    packet {
+        HELLO;
+        MOVE = "move";
+        nested {
+            INNER;
+        }
+        BYE;
+    }
+    #[name_id(ordinal)]
+    empty {}
+}
+
+const MEMBERS: [NameId; 3] = [packet::HELLO, packet::MOVE, packet::BYE];
+
+#[test]
+fn declaration_order() {
+    assert_eq!(packet::COUNT, 3);
+    for (ordinal, id) in MEMBERS.into_iter().enumerate() {
+        assert_eq!(packet::ordinal(id), Some(ordinal as u16));
+    }
+    const BYE: Option<u16> = packet::ordinal(packet::BYE);
+    assert_eq!(BYE, Some(2));
+}
+
+#[test]
+fn round_trip() {
+    for id in MEMBERS {
+        let ordinal = packet::ordinal(id).unwrap();
+        assert_eq!(packet::from_ordinal(ordinal), Some(id));
+    }
+    for ordinal in 0..packet::COUNT {
+        let id = packet::from_ordinal(ordinal).unwrap();
+        assert_eq!(packet::ordinal(id), Some(ordinal));
+    }
+}
+
+#[test]
+fn unknown() {
+    assert_eq!(packet::ordinal(packet::nested::INNER), None);
+    assert_eq!(packet::ordinal(id!("packet.missing")), None);
+    assert_eq!(packet::from_ordinal(packet::COUNT), None);
+    assert_eq!(packet::from_ordinal(u16::MAX), None);
+    assert_eq!(empty::COUNT, 0);
+    assert_eq!(empty::ordinal(packet::HELLO), None);
+    assert_eq!(empty::from_ordinal(0), None);
+}
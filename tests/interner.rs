@@ -0,0 +1,169 @@
+#![cfg(feature = "alloc")]
+
+use name_id::{id, CollisionPolicy, InternCollision, NameId, NameInterner};
+
+#[test]
+fn intern_and_resolve() {
+    let mut interner = NameInterner::new();
+    assert!(interner.is_empty());
+    let spawn = interner.intern("player_spawn").unwrap();
+    let exit = interner.intern(&String::from("level_exit")).unwrap();
+    assert_eq!(spawn, id!(player_spawn));
+    assert_eq!(exit, id!(level_exit));
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.resolve(spawn), Some("player_spawn"));
+    assert_eq!(interner.resolve(exit), Some("level_exit"));
+    assert_eq!(interner.resolve(id!(missing)), None);
+    assert!(interner.contains(spawn));
+    assert!(!interner.contains(id!(missing)));
+}
+
+#[test]
+fn intern_twice_doesnt_allocate() {
+    let mut interner = NameInterner::new();
+    interner.intern("player_spawn").unwrap();
+    interner.shrink_to_fit();
+    let capacity = interner.capacity();
+    assert_eq!(interner.intern("player_spawn"), Ok(id!(player_spawn)));
+    assert_eq!(interner.len(), 1);
+    assert_eq!(interner.capacity(), capacity);
+}
+
+#[test]
+fn collision_is_an_error() {
+    let mut interner = NameInterner::new();
+    let id = NameId::from_value(42);
+    interner.insert(id, "first").unwrap();
+    // same name is accepted again
+    assert_eq!(interner.insert(id, "first"), Ok(()));
+    assert_eq!(interner.insert(id, "second"), Err(InternCollision { id }));
+    assert_eq!(interner.resolve(id), Some("first"));
+
+    // hashing a name stored under a colliding id
+    let hashed = id!(player_spawn);
+    interner.insert(hashed, "not_player_spawn").unwrap();
+    assert_eq!(
+        interner.intern("player_spawn"),
+        Err(InternCollision { id: hashed })
+    );
+    assert_eq!(interner.len(), 2);
+}
+
+#[test]
+fn rehash_on_collision() {
+    let mut interner =
+        NameInterner::new().on_collision(CollisionPolicy::Rehash { max_attempts: 3 });
+    let hashed = id!(player_spawn);
+    interner.insert(hashed, "not_player_spawn").unwrap();
+
+    let rehashed = interner.intern("player_spawn").unwrap();
+    assert_ne!(rehashed, hashed);
+    assert_eq!(rehashed, NameId::new_keyed(1, "player_spawn"));
+    assert_eq!(interner.resolve(rehashed), Some("player_spawn"));
+    assert_eq!(interner.resolve(hashed), Some("not_player_spawn"));
+    // interning again finds the rehashed id
+    assert_eq!(interner.intern("player_spawn"), Ok(rehashed));
+    assert_eq!(interner.len(), 2);
+}
+
+#[test]
+fn rehash_gives_up_after_max_attempts() {
+    let mut interner =
+        NameInterner::new().on_collision(CollisionPolicy::Rehash { max_attempts: 2 });
+    let hashed = id!(door);
+    interner.insert(hashed, "a").unwrap();
+    interner.insert(NameId::new_keyed(1, "door"), "b").unwrap();
+    interner.insert(NameId::new_keyed(2, "door"), "c").unwrap();
+    assert_eq!(interner.intern("door"), Err(InternCollision { id: hashed }));
+    assert_eq!(
+        NameInterner::new().collision_policy(),
+        CollisionPolicy::Error
+    );
+}
+
+#[cfg(feature = "ahash")]
+#[test]
+fn rehash_genuine_collision() {
+    // a genuine 64-bit collision of the default hasher
+    let (a, b) = ("cbd4938f3322cbc5f", "ce6aa744e6a6c1f41");
+    let mut interner = NameInterner::new();
+    let first = interner.intern(a).unwrap();
+    assert!(interner.intern(b).is_err());
+
+    let mut interner = interner.on_collision(CollisionPolicy::Rehash { max_attempts: 1 });
+    let second = interner.intern(b).unwrap();
+    assert_ne!(first, second);
+    assert_eq!(first, id!("cbd4938f3322cbc5f"));
+    // not declared with `id!`, which `paranoid_startup` would report
+    assert_ne!(second, NameId::new(b));
+    assert_eq!(interner.resolve(first), Some(a));
+    assert_eq!(interner.resolve(second), Some(b));
+}
+
+#[test]
+fn iteration_is_sorted_by_value() {
+    let mut interner = NameInterner::new();
+    for name in ["c", "a", "b", "a"] {
+        interner.intern(name).unwrap();
+    }
+    let entries: Vec<(NameId, &str)> = interner.iter().collect();
+    assert_eq!(entries.len(), 3);
+    assert!(entries.windows(2).all(|w| w[0].0.value() < w[1].0.value()));
+    for (id, name) in &interner {
+        assert_eq!(id, name);
+    }
+    assert_eq!(interner.iter().len(), 3);
+}
+
+#[test]
+fn capacity_and_shrink() {
+    let mut interner = NameInterner::with_capacity(8, 64);
+    let (names, bytes) = interner.capacity();
+    assert!(names >= 8 && bytes >= 64);
+    interner.intern("a").unwrap();
+    interner.shrink_to_fit();
+    assert_eq!(interner.capacity(), (1, 1));
+
+    interner.reserve(10, 100);
+    let (names, bytes) = interner.capacity();
+    assert!(names >= 11 && bytes >= 101);
+    interner.clear();
+    assert!(interner.is_empty());
+    assert_eq!(interner.capacity(), (names, bytes));
+    assert_eq!(interner.resolve(id!(a)), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn shared_interner() {
+    use name_id::SharedInterner;
+
+    static INTERNER: SharedInterner = SharedInterner::new();
+
+    let threads: Vec<_> = (0..4)
+        .map(|t| {
+            std::thread::spawn(move || {
+                for i in 0..100 {
+                    INTERNER.intern(&format!("name_{}", (t + i) % 150)).unwrap();
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(INTERNER.len(), 103);
+    assert_eq!(
+        INTERNER.resolve(NameId::new("name_42")).as_deref(),
+        Some("name_42")
+    );
+    assert!(INTERNER.contains(NameId::new("name_102")));
+    assert_eq!(
+        INTERNER.insert(NameId::new("name_1"), "other"),
+        Err(InternCollision {
+            id: NameId::new("name_1")
+        })
+    );
+    let longest = INTERNER.with(|interner| interner.iter().map(|(_, name)| name.len()).max());
+    assert_eq!(longest, Some("name_100".len()));
+}
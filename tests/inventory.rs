@@ -0,0 +1,61 @@
+#![cfg(feature = "inventory")]
+
+use name_id::{declared_ids, find_declared, id, NameId};
+
+const LOCAL: NameId = id!(inventory_local);
+
+fn declared(name: &str) -> usize {
+    declared_ids()
+        .filter(|(declared, _)| *declared == name)
+        .count()
+}
+
+#[test]
+fn local_ids() {
+    assert_eq!(find_declared(LOCAL), Some("inventory_local"));
+    assert_eq!(
+        find_declared(id!("inventory local expression")),
+        Some("inventory local expression")
+    );
+    assert!(declared_ids().any(|entry| entry == ("inventory_local", LOCAL)));
+}
+
+#[test]
+fn ids_of_other_crates() {
+    assert_eq!(
+        find_declared(inventory_dep::DEPENDENCY_ONLY),
+        Some("inventory_dependency_only")
+    );
+    assert_eq!(
+        find_declared(inventory_dep::in_function()),
+        Some("inventory dependency function")
+    );
+    assert_eq!(declared("inventory_dependency_only"), 1);
+}
+
+#[test]
+fn duplicates_across_crates() {
+    let local = id!(inventory_shared);
+    assert_eq!(local, inventory_dep::SHARED);
+    assert_eq!(declared("inventory_shared"), 2);
+    assert_eq!(find_declared(local), Some("inventory_shared"));
+
+    #[cfg(feature = "alloc")]
+    {
+        let unique = name_id::declared_ids_dedup();
+        assert_eq!(
+            unique
+                .iter()
+                .filter(|(name, _)| *name == "inventory_shared")
+                .count(),
+            1
+        );
+        assert!(unique.windows(2).all(|w| w[0].1.value() <= w[1].1.value()));
+        assert!(unique.len() < declared_ids().len());
+    }
+}
+
+#[test]
+fn undeclared_ids() {
+    assert_eq!(find_declared(NameId::from_value(1234)), None);
+}
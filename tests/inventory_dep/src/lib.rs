@@ -0,0 +1,15 @@
+//! Ids declared in a crate separate from `name-id` tests, used to check that
+//! `inventory` feature collects ids across crates.
+
+use name_id::{id, NameId};
+
+/// Declared only by this crate.
+pub const DEPENDENCY_ONLY: NameId = id!(inventory_dependency_only);
+
+/// Also declared by `name-id` inventory tests.
+pub const SHARED: NameId = id!(inventory_shared);
+
+/// Returns an id declared in a function body.
+pub fn in_function() -> NameId {
+    id!("inventory dependency function")
+}
@@ -0,0 +1,56 @@
+use name_id::{id, id_value, NameId};
+
+#[test]
+fn macro_matches_runtime() {
+    assert_eq!(id!(key = 0; player), NameId::new_keyed(0, "player"));
+    assert_eq!(id!(key = 1; player), NameId::new_keyed(1, "player"));
+    assert_eq!(
+        id!(key = 0xDEADBEEF; "item.sword"),
+        NameId::new_keyed(0xDEADBEEF, "item.sword")
+    );
+    assert_eq!(
+        id!(key = 0xffff_ffff_ffff_ffff; can have spaces),
+        NameId::new_keyed(u64::MAX, "can have spaces")
+    );
+    assert_eq!(
+        id_value!(key = 0xDEADBEEF; "item.sword"),
+        NameId::new_keyed(0xDEADBEEF, "item.sword").value()
+    );
+}
+
+#[test]
+fn unkeyed_names_are_unaffected() {
+    // `key` is only an option when followed by `=`
+    assert_eq!(id!(key), NameId::new("key"));
+    assert_eq!(id!(key value), NameId::new("key value"));
+}
+
+#[test]
+fn keys_separate_hash_spaces() {
+    let names = [
+        "",
+        "a",
+        "player",
+        "item.sword",
+        "a somewhat longer name spanning blocks",
+    ];
+    let keys = [0, 1, 2, 0xDEADBEEF, 1 << 63, u64::MAX];
+    let mut seen = std::collections::HashSet::new();
+    for name in names {
+        assert!(seen.insert(NameId::new(name).value()));
+        for key in keys {
+            assert!(
+                seen.insert(NameId::new_keyed(key, name).value()),
+                "{name:?} with key {key:#x} collides"
+            );
+        }
+    }
+}
+
+#[test]
+fn many_keys_differ() {
+    let ids: std::collections::HashSet<u64> = (0..4096)
+        .map(|key| NameId::new_keyed(key, "engine.core").value())
+        .collect();
+    assert_eq!(ids.len(), 4096);
+}
@@ -0,0 +1,54 @@
+use core::mem::{align_of, size_of};
+
+use name_id::{id, NameId};
+
+const LABELED: bool = cfg!(all(debug_assertions, feature = "debug_name"));
+
+#[test]
+fn transparent() {
+    assert_eq!(
+        NameId::IS_TRANSPARENT_U64,
+        !LABELED && !cfg!(feature = "fixed_size")
+    );
+}
+
+#[test]
+fn size() {
+    if NameId::IS_TRANSPARENT_U64 {
+        assert_eq!(NameId::SIZE, size_of::<u64>());
+    } else {
+        assert_eq!(NameId::SIZE, size_of::<u64>() + size_of::<&'static str>());
+    }
+    assert_eq!(NameId::SIZE, size_of::<NameId>());
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn documented_sizes() {
+    let expected = match (LABELED, cfg!(feature = "fixed_size")) {
+        (false, false) => 8,
+        (false, true) => 24,
+        (true, _) => 24,
+    };
+    assert_eq!(NameId::SIZE, expected);
+}
+
+#[test]
+fn alignment() {
+    // padding is a byte array, so it doesn't affect alignment
+    let align = if LABELED {
+        align_of::<u64>().max(align_of::<&'static str>())
+    } else {
+        align_of::<u64>()
+    };
+    assert_eq!(align_of::<NameId>(), align);
+}
+
+#[test]
+fn value_is_first() {
+    let id = id!(player_spawn);
+    // SAFETY: value is the first field of both repr(transparent) and repr(C)
+    // layouts.
+    let value = unsafe { *(&id as *const NameId as *const u64) };
+    assert_eq!(value, id.value());
+}
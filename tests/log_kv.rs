@@ -0,0 +1,103 @@
+#![cfg(feature = "log-kv")]
+
+use std::sync::Mutex;
+
+use log::kv::{self, Key, ToKey, ToValue, Value, VisitSource, VisitValue};
+use log::{Level, Log, Metadata, Record};
+use name_id::{id, NameId};
+
+#[derive(Debug, PartialEq)]
+enum Observed {
+    U64(u64),
+    Str(String),
+    Other,
+}
+
+struct Observe(Option<Observed>);
+
+impl<'v> VisitValue<'v> for Observe {
+    fn visit_any(&mut self, _: Value) -> Result<(), kv::Error> {
+        self.0 = Some(Observed::Other);
+        Ok(())
+    }
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), kv::Error> {
+        self.0 = Some(Observed::U64(value));
+        Ok(())
+    }
+
+    fn visit_str(&mut self, value: &str) -> Result<(), kv::Error> {
+        self.0 = Some(Observed::Str(value.to_string()));
+        Ok(())
+    }
+}
+
+fn observe(value: Value) -> Observed {
+    let mut observe = Observe(None);
+    value.visit(&mut observe).unwrap();
+    observe.0.unwrap()
+}
+
+#[derive(Default)]
+struct CapturingLogger(Mutex<Vec<(String, Observed)>>);
+
+impl<'kvs> VisitSource<'kvs> for &CapturingLogger {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        let mut pairs = self.0.lock().unwrap();
+        pairs.push((key.as_str().to_string(), observe(value)));
+        Ok(())
+    }
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        record.key_values().visit(&mut &*self).unwrap();
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn value_is_u64() {
+    let id = id!(service_request);
+    assert_eq!(observe(id.to_value()), Observed::U64(id.value()));
+    assert_eq!(id.to_value().to_u64(), Some(id.value()));
+}
+
+#[test]
+fn logged_pairs() {
+    let logger = CapturingLogger::default();
+    let id = id!(service_request);
+    let kv = id.kv_pair();
+    logger.log(
+        &Record::builder()
+            .level(Level::Info)
+            .args(format_args!("request"))
+            .key_values(&kv)
+            .build(),
+    );
+
+    let pairs = logger.0.into_inner().unwrap();
+    assert_eq!(pairs[0], ("id".to_string(), Observed::U64(id.value())));
+    #[cfg(all(debug_assertions, feature = "debug_name"))]
+    assert_eq!(
+        pairs[1],
+        (
+            "id.name".to_string(),
+            Observed::Str("service_request".to_string())
+        )
+    );
+    #[cfg(not(all(debug_assertions, feature = "debug_name")))]
+    assert_eq!(pairs.len(), 1);
+}
+
+#[test]
+fn key() {
+    #[cfg(all(debug_assertions, feature = "debug_name"))]
+    assert_eq!(id!(service_request).to_key().as_str(), "service_request");
+    assert_eq!(NameId::from_value(7).to_key().as_str(), "<unnamed NameId>");
+}
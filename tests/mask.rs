@@ -0,0 +1,112 @@
+use name_id::{id, IdMask, MaskUniverse, NameId, NameIdTable, NotInUniverse};
+
+static EVENTS: NameIdTable<(), 4> = NameIdTable::new([
+    (id!(spawn), ()),
+    (id!(despawn), ()),
+    (id!(damage), ()),
+    (id!(heal), ()),
+]);
+
+fn sorted_universe(len: u64) -> Vec<NameId> {
+    (0..len).map(|i| NameId::from_value(i * 7 + 3)).collect()
+}
+
+#[test]
+fn set_and_contains() {
+    let mut mask = IdMask::<1>::new();
+    assert!(mask.is_empty());
+    assert_eq!(mask.set(&EVENTS, id!(spawn)), Ok(true));
+    assert_eq!(mask.set(&EVENTS, id!(spawn)), Ok(false));
+    assert!(mask.contains(&EVENTS, id!(spawn)));
+    assert!(!mask.contains(&EVENTS, id!(heal)));
+    assert_eq!(mask.len(), 1);
+
+    assert_eq!(mask.unset(&EVENTS, id!(spawn)), Ok(true));
+    assert_eq!(mask.unset(&EVENTS, id!(spawn)), Ok(false));
+    assert!(mask.is_empty());
+}
+
+#[test]
+fn out_of_universe() {
+    let mut mask = IdMask::<1>::new();
+    let unknown = id!(unknown);
+    assert_eq!(
+        mask.set(&EVENTS, unknown),
+        Err(NotInUniverse { id: unknown })
+    );
+    assert_eq!(
+        mask.unset(&EVENTS, unknown),
+        Err(NotInUniverse { id: unknown })
+    );
+    assert!(!mask.contains(&EVENTS, unknown));
+    assert!(mask.is_empty());
+    assert!(IdMask::<1>::from_ids(&EVENTS, [id!(spawn), unknown]).is_err());
+}
+
+#[test]
+fn subset_and_intersection() {
+    let handled = IdMask::<1>::from_ids(&EVENTS, [id!(spawn), id!(damage), id!(heal)]).unwrap();
+    let fired = IdMask::<1>::from_ids(&EVENTS, [id!(damage), id!(heal)]).unwrap();
+    let other = IdMask::<1>::from_ids(&EVENTS, [id!(despawn)]).unwrap();
+    let empty = IdMask::<1>::new();
+
+    assert!(fired.is_subset(&handled));
+    assert!(handled.is_superset(&fired));
+    assert!(!handled.is_subset(&fired));
+    assert!(handled.is_subset(&handled));
+    assert!(empty.is_subset(&fired));
+
+    assert!(fired.intersects(&handled));
+    assert!(!other.intersects(&handled));
+    assert!(!empty.intersects(&empty));
+
+    assert_eq!(handled.intersection(&fired), fired);
+    assert_eq!(handled.union(&other).len(), 4);
+    assert_eq!(
+        handled.difference(&fired),
+        IdMask::from_ids(&EVENTS, [id!(spawn)]).unwrap()
+    );
+}
+
+#[test]
+fn multi_word_slice_universe() {
+    let universe = sorted_universe(150);
+    let mut mask = IdMask::<3>::new();
+    for id in universe.iter().step_by(2) {
+        assert_eq!(mask.set(universe.as_slice(), *id), Ok(true));
+    }
+    assert_eq!(mask.len(), 75);
+    assert!(mask.contains(universe.as_slice(), universe[148]));
+    assert!(!mask.contains(universe.as_slice(), universe[149]));
+    assert!(!mask.contains(universe.as_slice(), NameId::from_value(4)));
+
+    let ids: Vec<_> = mask.iter(universe.as_slice()).collect();
+    let expected: Vec<_> = universe.iter().copied().step_by(2).collect();
+    assert_eq!(ids, expected);
+
+    let last = IdMask::<3>::from_ids(universe.as_slice(), [universe[149]]).unwrap();
+    assert_eq!(last.words(), &[0, 0, 1 << (149 - 128)]);
+    assert!(!last.intersects(&mask));
+    assert!(last.is_subset(&mask.union(&last)));
+}
+
+#[test]
+fn array_universe() {
+    static IDS: [NameId; 3] = [
+        NameId::from_value(1),
+        NameId::from_value(5),
+        NameId::from_value(9),
+    ];
+    assert_eq!(MaskUniverse::len(&IDS), 3);
+    assert_eq!(IDS.index_of(NameId::from_value(9)), Some(2));
+    let mask = IdMask::<1>::from_ids(&IDS, [NameId::from_value(5)]).unwrap();
+    assert_eq!(mask.words(), &[0b10]);
+}
+
+#[test]
+#[should_panic(expected = "doesn't fit")]
+fn universe_too_large() {
+    let universe = sorted_universe(65);
+    let mut mask = IdMask::<1>::new();
+    let _ = mask.set(universe.as_slice(), universe[0]);
+}
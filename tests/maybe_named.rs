@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use name_id::{MaybeNamed, NameId};
+
+#[test]
+fn unnamed() {
+    let id = NameId::from_value(0x2a);
+    let resolved = MaybeNamed::resolve(id);
+    assert!(matches!(resolved, MaybeNamed::Unnamed(_)));
+    assert_eq!(resolved.id(), id);
+    assert_eq!(resolved.name(), None);
+    assert_eq!(resolved.to_string(), "0x000000000000002a");
+}
+
+#[test]
+fn named() {
+    let id = NameId::from_value(0x2a);
+    let named = MaybeNamed::Named(id, "door");
+    assert_eq!(named.id(), id);
+    assert_eq!(named.name(), Some("door"));
+    assert_eq!(named.to_string(), "door");
+}
+
+#[cfg(all(debug_assertions, feature = "debug_name"))]
+#[test]
+fn resolves_label() {
+    let resolved = MaybeNamed::resolve(name_id::id!(wine_cellar));
+    assert_eq!(resolved.name(), Some("wine_cellar"));
+}
+
+#[cfg(all(
+    any(feature = "registry", feature = "registry_static"),
+    feature = "alloc"
+))]
+#[test]
+fn resolves_registered_name() {
+    let id = NameId::new(String::from("maybe_named_attic"));
+    // strips the debug label so only the registry can provide the name
+    let resolved = MaybeNamed::resolve(NameId::from_value(id.value()));
+    assert_eq!(resolved.name(), Some("maybe_named_attic"));
+    assert_eq!(resolved.to_string(), "maybe_named_attic");
+}
+
+#[test]
+fn equality_ignores_name() {
+    let id = NameId::from_value(0x2a);
+    let named = MaybeNamed::Named(id, "door");
+    let unnamed = MaybeNamed::Unnamed(id);
+    assert_eq!(named, unnamed);
+    assert_eq!(named, id);
+    assert_eq!(id, unnamed);
+    assert_ne!(named, NameId::from_value(0x2b));
+    assert_ne!(named, MaybeNamed::Named(NameId::from_value(0x2b), "door"));
+
+    let set: HashSet<MaybeNamed> = [named, unnamed].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_eq!(NameId::from(named), id);
+}
@@ -0,0 +1,83 @@
+use name_id::{id, method_ids, NameId};
+
+#[allow(dead_code)]
+struct Door {
+    open: bool,
+}
+
+#[allow(dead_code)]
+#[method_ids]
+impl Door {
+    pub const LOCKED: bool = false;
+
+    pub fn new() -> Self {
+        Door { open: false }
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+    }
+
+    #[method_ids(rename = "close")]
+    pub fn shut(&mut self) {
+        self.open = false;
+    }
+
+    #[method_ids(skip)]
+    pub fn reset(&mut self) {
+        self.shut();
+    }
+
+    pub(crate) fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+}
+
+#[test]
+fn table_contents() {
+    assert_eq!(
+        Door::METHOD_IDS,
+        &[
+            (id!(new), "new"),
+            (id!(open), "open"),
+            (id!(close), "close")
+        ]
+    );
+}
+
+#[test]
+fn lookup() {
+    assert_eq!(Door::method_id("open"), Some(id!(open)));
+    assert_eq!(Door::method_id("close"), Some(id!(close)));
+    assert_eq!(Door::method_id("shut"), None);
+    assert_eq!(Door::method_id("reset"), None);
+}
+
+#[test]
+fn private_functions_excluded() {
+    assert_eq!(Door::method_id("toggle"), None);
+    assert_eq!(Door::method_id("is_open"), None);
+}
+
+#[test]
+fn methods_unchanged() {
+    let mut door = Door::new();
+    door.open();
+    door.toggle();
+    assert!(!door.is_open());
+    door.toggle();
+    door.reset();
+    assert!(!door.is_open());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn matches_runtime_names() {
+    for (id, name) in Door::METHOD_IDS {
+        assert_eq!(*id, NameId::new(name.to_string()));
+    }
+}
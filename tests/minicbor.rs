@@ -0,0 +1,59 @@
+#![cfg(feature = "minicbor")]
+
+use name_id::{id, NameId};
+
+fn encode(id: NameId, buffer: &mut [u8]) -> &[u8] {
+    let len = minicbor::len(id);
+    minicbor::encode(id, &mut buffer[..]).expect("buffer too small");
+    &buffer[..len]
+}
+
+#[test]
+fn round_trip() {
+    let mut buffer = [0u8; 16];
+    for id in [
+        id!(device_status),
+        NameId::from_value(0),
+        NameId::from_value(u64::MAX),
+    ] {
+        let bytes = encode(id, &mut buffer);
+        assert_eq!(minicbor::decode::<NameId>(bytes).unwrap(), id);
+    }
+}
+
+#[test]
+fn exact_bytes() {
+    let mut buffer = [0u8; 16];
+    assert_eq!(
+        encode(NameId::from_value(0x1234), &mut buffer),
+        [0x19, 0x12, 0x34]
+    );
+
+    let id = id!(device_status);
+    let bytes = encode(id, &mut buffer);
+    assert_eq!(bytes[0], 0x1b);
+    assert_eq!(bytes[1..], id.value().to_be_bytes());
+}
+
+#[test]
+fn rejects_other_types() {
+    // negative integer -1
+    assert!(minicbor::decode::<NameId>(&[0x20]).is_err());
+    #[cfg(not(feature = "minicbor_names"))]
+    assert!(minicbor::decode::<NameId>(&[0x63, b'a', b'b', b'c']).is_err());
+}
+
+#[cfg(feature = "minicbor_names")]
+#[test]
+fn decodes_names() {
+    let mut buffer = [0u8; 32];
+    let len = {
+        let mut encoder = minicbor::Encoder::new(&mut buffer[..]);
+        encoder.str("device_status").unwrap();
+        minicbor::len("device_status")
+    };
+    assert_eq!(
+        minicbor::decode::<NameId>(&buffer[..len]).unwrap(),
+        id!(device_status)
+    );
+}
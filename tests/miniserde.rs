@@ -0,0 +1,68 @@
+#![cfg(feature = "miniserde")]
+
+use miniserde::{json, Deserialize, Serialize};
+use name_id::{id, NameId};
+
+#[test]
+fn small_values_are_numbers() {
+    for value in [0, 1234, 1 << 53] {
+        let id = NameId::from_value(value);
+        let text = json::to_string(&id);
+        assert_eq!(text, value.to_string());
+        assert_eq!(json::from_str::<NameId>(&text).unwrap(), id);
+    }
+}
+
+#[test]
+fn large_values_are_strings() {
+    for value in [(1 << 53) + 1, u64::MAX] {
+        let id = NameId::from_value(value);
+        let text = json::to_string(&id);
+        assert_eq!(text, format!("\"{}\"", value));
+        assert_eq!(json::from_str::<NameId>(&text).unwrap(), id);
+    }
+}
+
+#[test]
+fn accepts_both_forms() {
+    assert_eq!(
+        json::from_str::<NameId>("\"42\"").unwrap(),
+        NameId::from_value(42)
+    );
+    assert_eq!(
+        json::from_str::<NameId>("18446744073709551615").unwrap(),
+        NameId::from_value(u64::MAX)
+    );
+    assert_eq!(
+        json::from_str::<NameId>("\"player_spawn\"").unwrap(),
+        id!(player_spawn)
+    );
+    assert!(json::from_str::<NameId>("-1").is_err());
+    assert!(json::from_str::<NameId>("\"18446744073709551616\"").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Trigger {
+    event: NameId,
+    target: NameId,
+}
+
+#[test]
+fn struct_round_trip() {
+    let trigger = Trigger {
+        event: NameId::from_value(7),
+        target: NameId::from_value(u64::MAX),
+    };
+    let text = json::to_string(&trigger);
+    assert_eq!(text, r#"{"event":7,"target":"18446744073709551615"}"#);
+    assert_eq!(json::from_str::<Trigger>(&text).unwrap(), trigger);
+
+    let trigger = Trigger {
+        event: id!(door_opened),
+        target: id!(cellar),
+    };
+    assert_eq!(
+        json::from_str::<Trigger>(&json::to_string(&trigger)).unwrap(),
+        trigger
+    );
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "hashbrown")]
+
+use std::hash::BuildHasher;
+
+use hashbrown::hash_map::EntryRef;
+use hashbrown::HashMap;
+use name_id::{id, NameBuildHasher, NameId};
+
+fn map() -> HashMap<NameId, u32, NameBuildHasher> {
+    let mut map = HashMap::with_hasher(NameBuildHasher);
+    map.insert(id!(player_spawn), 1);
+    map.insert(id!(level_exit), 2);
+    map
+}
+
+#[test]
+fn names_hash_like_ids() {
+    for (name, id) in [
+        ("player_spawn", id!(player_spawn)),
+        ("", id!("")),
+        ("ünïcødé ✓", id!("ünïcødé ✓")),
+    ] {
+        assert_eq!(NameBuildHasher.hash_one(name), id.value());
+        assert_eq!(NameBuildHasher.hash_one(String::from(name)), id.value());
+        assert_eq!(NameBuildHasher.hash_one(id), id.value());
+    }
+}
+
+#[test]
+fn get() {
+    let map = map();
+    for name in ["player_spawn", "level_exit", "missing"] {
+        assert_eq!(map.get(name), map.get(&NameId::new(name)));
+        #[cfg(feature = "alloc")]
+        assert_eq!(map.get(&String::from(name)), map.get(&NameId::new(name)));
+    }
+    assert_eq!(map.get("player_spawn"), Some(&1));
+    assert_eq!(map.get("missing"), None);
+}
+
+#[test]
+fn contains_key() {
+    let map = map();
+    for name in ["player_spawn", "level_exit", "missing"] {
+        assert_eq!(map.contains_key(name), map.contains_key(&NameId::new(name)));
+    }
+    assert!(map.contains_key("level_exit"));
+    assert!(!map.contains_key("missing"));
+}
+
+#[test]
+fn entry_ref() {
+    let mut by_name = map();
+    let mut by_id = map();
+    for name in ["player_spawn", "missing", "missing"] {
+        *by_name.entry_ref(name).or_insert(0) += 10;
+        *by_id.entry(NameId::new(name)).or_insert(0) += 10;
+    }
+    assert_eq!(by_name, by_id);
+    assert_eq!(by_name.get("missing"), Some(&20));
+
+    match by_name.entry_ref("level_exit") {
+        EntryRef::Occupied(entry) => assert_eq!(entry.remove(), 2),
+        EntryRef::Vacant(_) => panic!("entry should be occupied"),
+    }
+    assert!(!by_name.contains_key("level_exit"));
+}
+
+#[test]
+fn other_values_are_hashed() {
+    // not a single write_u64, so the value isn't passed through
+    assert_ne!(NameBuildHasher.hash_one((1u64, 2u64)), 1);
+    assert_ne!(NameBuildHasher.hash_one(1u32), 1);
+    assert_eq!(NameBuildHasher.hash_one(7u64), 7);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+use name_id::{NameId, NameTable, SnapshotError};
+
+const A: NameId = NameId::from_value(1);
+const B: NameId = NameId::from_value(2);
+const C: NameId = NameId::from_value(3);
+
+fn binary(entries: &[(u64, &str)]) -> Vec<u8> {
+    let mut result = Vec::new();
+    for (value, name) in entries {
+        result.extend_from_slice(&value.to_le_bytes());
+        result.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        result.extend_from_slice(name.as_bytes());
+    }
+    result
+}
+
+#[test]
+fn load_and_resolve() {
+    let table = NameTable::load_from_bytes(&binary(&[(2, "bob"), (1, "alice")])).unwrap();
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.resolve(A), Some("alice"));
+    assert_eq!(table.resolve(B), Some("bob"));
+    assert_eq!(table.resolve(C), None);
+    assert_eq!(table.iter().collect::<Vec<_>>(), [(A, "alice"), (B, "bob")]);
+
+    let text =
+        NameTable::load_from_text("0000000000000002\tbob\n0000000000000001\talice\n").unwrap();
+    assert_eq!(text, table);
+
+    let escaped = NameTable::load_from_text("0000000000000003\ttab\\there\n").unwrap();
+    assert_eq!(escaped.resolve(C), Some("tab\there"));
+}
+
+#[test]
+fn load_errors() {
+    assert_eq!(
+        NameTable::load_from_bytes(&[0; 9]),
+        Err(SnapshotError::Truncated { offset: 0 })
+    );
+    assert_eq!(
+        NameTable::load_from_text("0000000000000001\tok\nbroken\n"),
+        Err(SnapshotError::InvalidLine { line: 2 })
+    );
+    assert!(NameTable::load_from_bytes(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn merge_precedence() {
+    let mut table =
+        NameTable::load_from_text("0000000000000001\talice\n0000000000000002\tbob\n").unwrap();
+    let update =
+        NameTable::load_from_text("0000000000000002\trobert\n0000000000000003\tcarol\n").unwrap();
+    table.merge(update);
+    assert_eq!(
+        table.iter().collect::<Vec<_>>(),
+        [(A, "alice"), (B, "robert"), (C, "carol")]
+    );
+
+    table.merge(NameTable::new());
+    assert_eq!(table.len(), 3);
+
+    table.insert(A, "alicia");
+    table.insert(NameId::from_value(0), "zero");
+    assert_eq!(table.resolve(A), Some("alicia"));
+    assert_eq!(table.iter().next(), Some((NameId::from_value(0), "zero")));
+}
+
+#[cfg(any(feature = "std", feature = "spin"))]
+#[test]
+fn global_install_and_swap() {
+    let id = NameId::from_value(0x5eed);
+    assert!(NameTable::global().is_none());
+    assert_eq!(id.to_string(), "NameId(24301)");
+
+    let mut first = NameTable::new();
+    first.insert(id, "first");
+    assert!(first.install_global().is_none());
+    assert_eq!(id.to_string(), "#{first}");
+
+    // references to the old table stay valid after a swap
+    let held = NameTable::global().unwrap();
+    let mut second = NameTable::new();
+    second.insert(id, "second");
+    let previous = second.install_global().unwrap();
+    assert_eq!(id.to_string(), "#{second}");
+    assert_eq!(held.resolve(id), Some("first"));
+    assert_eq!(previous.resolve(id), Some("first"));
+    assert_eq!(NameId::from_value(1).to_string(), "NameId(1)");
+
+    assert!(NameTable::uninstall_global().is_some());
+    assert_eq!(id.to_string(), "NameId(24301)");
+}
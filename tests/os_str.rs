@@ -0,0 +1,70 @@
+#![cfg(feature = "std")]
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use name_id::{NameId, NonUtf8Name};
+
+#[test]
+fn utf8_parity() {
+    let expected = NameId::new("assets/door.png").value();
+    assert_eq!(
+        NameId::from(OsStr::new("assets/door.png")).value(),
+        expected
+    );
+    assert_eq!(
+        NameId::from(OsString::from("assets/door.png")).value(),
+        expected
+    );
+    assert_eq!(NameId::from(Path::new("assets/door.png")).value(), expected);
+    assert_eq!(
+        NameId::from(PathBuf::from("assets/door.png")).value(),
+        expected
+    );
+    assert_eq!(
+        NameId::try_from_path(Path::new("assets/door.png")).map(|id| id.value()),
+        Ok(expected)
+    );
+}
+
+#[test]
+fn separators_arent_normalized() {
+    let unix = NameId::from(Path::new("assets/door.png"));
+    let windows = NameId::from(Path::new("assets\\door.png"));
+    assert_ne!(unix, windows);
+    assert_eq!(windows.value(), NameId::new("assets\\door.png").value());
+    assert_ne!(
+        NameId::from(Path::new("./assets/door.png")),
+        NameId::from(Path::new("assets/door.png"))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_non_utf8() {
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = OsStr::from_bytes(b"door\xff.png");
+    assert_eq!(NameId::try_from_os_str(name), Err(NonUtf8Name));
+    assert_eq!(NameId::try_from_path(Path::new(name)), Err(NonUtf8Name));
+    let lossy = NameId::new("door\u{fffd}.png").value();
+    assert_eq!(NameId::from(name).value(), lossy);
+    assert_eq!(NameId::from(name.to_os_string()).value(), lossy);
+    assert_eq!(NameId::from(PathBuf::from(name)).value(), lossy);
+    // all invalid sequences are replaced the same way
+    let other = OsStr::from_bytes(b"door\xfe.png");
+    assert_eq!(NameId::from(other), NameId::from(name));
+}
+
+#[cfg(windows)]
+#[test]
+fn windows_non_utf8() {
+    use std::os::windows::ffi::OsStringExt;
+
+    // unpaired surrogate
+    let name = OsString::from_wide(&[0x64, 0x6f, 0x6f, 0x72, 0xd800]);
+    assert_eq!(NameId::try_from_os_str(&name), Err(NonUtf8Name));
+    let lossy = NameId::new("door\u{fffd}").value();
+    assert_eq!(NameId::from(name.as_os_str()).value(), lossy);
+    assert_eq!(NameId::from(PathBuf::from(name)).value(), lossy);
+}
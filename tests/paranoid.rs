@@ -0,0 +1,73 @@
+// Staged problems would make `paranoid_startup` panic before tests run.
+#![cfg(all(
+    feature = "paranoid",
+    not(all(feature = "paranoid_startup", debug_assertions))
+))]
+
+use name_id::{__linkme, id, verify_declared, Collision, DeclarationError, NameId, __DECLARED_IDS};
+
+// Entries `id!` would add if it was compiled with a different hasher or in
+// crates that weren't checked for collisions together.
+
+#[__linkme::distributed_slice(__DECLARED_IDS)]
+#[linkme(crate = __linkme)]
+static MISMATCH: (&str, NameId) = ("paranoid.mismatch", NameId::from_value(1));
+
+#[cfg(feature = "ahash")]
+mod colliding {
+    use super::*;
+
+    // a genuine 64-bit collision of the default hasher
+    pub const A: &str = "cbd4938f3322cbc5f";
+    pub const B: &str = "ce6aa744e6a6c1f41";
+    pub const HASH: u64 = 0xc4089912f36545a0;
+
+    #[__linkme::distributed_slice(__DECLARED_IDS)]
+    #[linkme(crate = __linkme)]
+    static FIRST: (&str, NameId) = (A, NameId::from_value(HASH));
+
+    #[__linkme::distributed_slice(__DECLARED_IDS)]
+    #[linkme(crate = __linkme)]
+    static SECOND: (&str, NameId) = (B, NameId::from_value(HASH));
+
+    #[__linkme::distributed_slice(__DECLARED_IDS)]
+    #[linkme(crate = __linkme)]
+    static SECOND_AGAIN: (&str, NameId) = (B, NameId::from_value(HASH));
+}
+
+fn mismatch() -> DeclarationError {
+    DeclarationError::Mismatch {
+        name: "paranoid.mismatch",
+        declared: NameId::from_value(1),
+        runtime: NameId::new("paranoid.mismatch").value(),
+    }
+}
+
+#[test]
+fn reports_staged_problems() {
+    // valid and keyed declarations aren't reported
+    let _ = (id!("paranoid.valid"), id!(key = 7; "paranoid.keyed"));
+    let errors = verify_declared().unwrap_err();
+
+    #[cfg(feature = "ahash")]
+    assert_eq!(
+        errors,
+        [
+            mismatch(),
+            DeclarationError::Collision(Collision {
+                first: colliding::A,
+                second: colliding::B,
+                hash: colliding::HASH,
+            }),
+        ]
+    );
+    #[cfg(not(feature = "ahash"))]
+    assert_eq!(errors, [mismatch()]);
+}
+
+#[test]
+fn display() {
+    let message = mismatch().to_string();
+    assert!(message
+        .starts_with("\"paranoid.mismatch\" was declared with hash value 0x0000000000000001"));
+}
@@ -0,0 +1,80 @@
+use name_id::{id, id_phf, NameId, NameIdPhf};
+
+include!("fixtures/phf_table.rs");
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generated_hits() {
+    assert_eq!(PHF.len(), ENTRIES);
+    for i in 0..ENTRIES as u32 {
+        let id = NameId::new(format!("command_{}", i));
+        assert_eq!(PHF.get(id), Some(&i));
+        assert!(PHF.contains_key(id));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generated_misses() {
+    for i in ENTRIES..ENTRIES * 2 {
+        assert_eq!(PHF.get(NameId::new(format!("command_{}", i))), None);
+    }
+}
+
+#[test]
+fn value_misses() {
+    assert_eq!(PHF.get(NameId::from_value(0)), None);
+    assert_eq!(PHF.get(NameId::from_value(u64::MAX)), None);
+    assert!(!PHF.contains_key(id!(missing)));
+}
+
+#[test]
+fn matches_binary_search_table() {
+    for (id, value) in &TABLE {
+        assert_eq!(PHF.get(*id), Some(value));
+    }
+    let mut phf_ids: Vec<u64> = PHF.iter().map(|(id, _)| id.value()).collect();
+    phf_ids.sort();
+    let table_ids: Vec<u64> = TABLE.iter().map(|(id, _)| id.value()).collect();
+    assert_eq!(phf_ids, table_ids);
+}
+
+#[test]
+fn key_forms() {
+    static KEYS: NameIdPhf<&str> = id_phf! {
+        ident => "ident",
+        "string key" => "string key",
+        42 => "42",
+        'c' => "c",
+    };
+    assert_eq!(KEYS.get(id!(ident)), Some(&"ident"));
+    assert_eq!(KEYS.get(id!("string key")), Some(&"string key"));
+    assert_eq!(KEYS.get(id!(42)), Some(&"42"));
+    assert_eq!(KEYS.get(id!('c')), Some(&"c"));
+    assert_eq!(KEYS.get(id!(string)), None);
+}
+
+#[test]
+fn small_tables() {
+    static EMPTY: NameIdPhf<u8> = id_phf! {};
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.get(id!(a)), None);
+
+    static ONE: NameIdPhf<u8> = id_phf! { a => 1 };
+    assert_eq!(ONE.get(id!(a)), Some(&1));
+    assert_eq!(ONE.get(id!(b)), None);
+
+    const TWO: NameIdPhf<u8> = id_phf! { a => 1, b => 2, };
+    const B: Option<&u8> = TWO.get(id!(b));
+    assert_eq!(B, Some(&2));
+}
+
+#[test]
+fn values_arent_required_to_be_copy() {
+    static NAMES: NameIdPhf<Option<String>> = id_phf! {
+        empty => Some(String::new()),
+        none => None,
+    };
+    assert_eq!(NAMES.get(id!(empty)), Some(&Some(String::new())));
+    assert_eq!(NAMES.get(id!(none)), Some(&None));
+}
@@ -0,0 +1,75 @@
+//! Hash values with `portable_hash` feature must be the same on all targets.
+//!
+//! Values were generated on x86_64. `tests/standalone` runs this test
+//! under Miri for aarch64, i686 (32-bit multiply, as on wasm32) and s390x
+//! (big-endian) targets.
+#![cfg(feature = "portable_hash")]
+
+use name_id::{id, NameId};
+
+/// Covers all input length branches of the hasher.
+const GOLDEN: [(&str, u64); 19] = [
+    ("", 0xa72fb095a0dc47c9),
+    ("a", 0xb8cf850d279745b1),
+    ("ab", 0xb7198b0dc0124e65),
+    ("abc", 0xb3b173f6b6b37a60),
+    ("abcd", 0x2405ce96ea2209ec),
+    ("abcde", 0x251b395709a77367),
+    ("abcdefg", 0x92b10a7226eeaf49),
+    ("abcdefgh", 0x85317923b476b845),
+    ("abcdefghi", 0xb3e1425cc0475029),
+    ("abcdefghijkl", 0x31e50252d0d44609),
+    ("abcdefghijklmno", 0x3100eb55aa43c37b),
+    ("abcdefghijklmnop", 0x3de19c7f21a3c6f4),
+    ("abcdefghijklmnopq", 0x25e4a83cf1413683),
+    ("abcdefghijklmnopqrstuvwx", 0x6dcb7f42aa06e7d9),
+    ("abcdefghijklmnopqrstuvwxyz01234", 0xb35c390f2f0868eb),
+    ("abcdefghijklmnopqrstuvwxyz012345", 0x09a64ba029824d6e),
+    ("abcdefghijklmnopqrstuvwxyz0123456", 0x433de57dd68af8a3),
+    (
+        "abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKL",
+        0x3cc764a089a1ed06,
+    ),
+    ("ünïcødé ✓", 0x4558abf9e39e1b54),
+];
+
+#[test]
+fn runtime_values() {
+    for (name, expected) in GOLDEN {
+        assert_eq!(NameId::new(name).value(), expected, "hash of {name:?}");
+    }
+}
+
+#[test]
+fn macro_values() {
+    let ids = [
+        id!(""),
+        id!("a"),
+        id!("abcdefgh"),
+        id!("abcdefghijklmnopq"),
+        id!("ünïcødé ✓"),
+    ];
+    let expected = [
+        GOLDEN[0].1,
+        GOLDEN[1].1,
+        GOLDEN[7].1,
+        GOLDEN[12].1,
+        GOLDEN[18].1,
+    ];
+    for (id, expected) in ids.iter().zip(expected) {
+        assert_eq!(id.value(), expected);
+    }
+}
+
+#[test]
+fn byte_values() {
+    // slices are hashed with a length prefix, which must not depend on the
+    // pointer width
+    let id = NameId::from(&b"raw\xffbytes"[..]);
+    assert_eq!(id.value(), 0xde694fb2c02bbf5c);
+}
+
+#[test]
+fn self_test() {
+    name_id::self_test().unwrap();
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "proptest")]
+
+use name_id::proptest::{any_id, named_id, named_id_with};
+use name_id::NameId;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn eq_matches_name((name, id) in named_id()) {
+        prop_assert_eq!(id, name.as_str());
+        prop_assert_eq!(id, NameId::new(name.clone()));
+    }
+
+    #[test]
+    fn custom_names((name, id) in named_id_with("[A-Z]{1,4}")) {
+        prop_assert!(name.chars().all(|c| c.is_ascii_uppercase()));
+        prop_assert_eq!(id, name);
+    }
+
+    #[test]
+    fn raw_values(id in any_id(), other in any::<NameId>()) {
+        prop_assert_eq!(id == other, id.value() == other.value());
+        prop_assert_eq!(NameId::from_value(id.value()), id);
+    }
+}
+
+#[test]
+fn shrinks_to_zero() {
+    use proptest::test_runner::{Config, TestError, TestRunner};
+
+    let mut runner = TestRunner::new(Config::default());
+    let result = runner.run(&any_id(), |id| {
+        prop_assert!(id.value() < 1000);
+        Ok(())
+    });
+    match result {
+        Err(TestError::Fail(_, id)) => assert_eq!(id.value(), 1000),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
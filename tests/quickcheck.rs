@@ -0,0 +1,53 @@
+#![cfg(feature = "quickcheck")]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use name_id::quickcheck::NamedId;
+use name_id::NameId;
+use quickcheck::{quickcheck, Arbitrary, Gen};
+
+fn hash_of(id: NameId) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    hasher.finish()
+}
+
+quickcheck! {
+    fn eq_matches_name(named: NamedId) -> bool {
+        let NamedId(name, id) = named;
+        id == name && id == NameId::new(name.clone())
+    }
+
+    fn hash_consistent_with_eq(a: NameId, b: NamedId) -> bool {
+        let a_copy = NameId::from_value(a.value());
+        (a == a_copy && hash_of(a) == hash_of(a_copy))
+            && (a != b.1 || hash_of(a) == hash_of(b.1))
+    }
+
+    fn ordering_transitive(a: NameId, b: NameId, c: NamedId) -> bool {
+        let c = c.1;
+        !(a <= b && b <= c) || a <= c
+    }
+}
+
+#[test]
+fn raw_value_shrinks_to_zero() {
+    let id = NameId::from_value(1234);
+    assert!(id.shrink().any(|shrunk| shrunk.value() == 0));
+    assert!(id.shrink().all(|shrunk| shrunk.value() < id.value()));
+}
+
+#[test]
+fn named_shrink_preserves_invariant() {
+    let mut g = Gen::new(16);
+    for _ in 0..32 {
+        let named = NamedId::arbitrary(&mut g);
+        assert!(!named.0.is_empty());
+        for NamedId(name, id) in named.shrink() {
+            assert!(!name.is_empty());
+            assert!(name.len() <= named.0.len());
+            assert_eq!(id, name);
+        }
+    }
+}
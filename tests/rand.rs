@@ -0,0 +1,80 @@
+#![cfg(feature = "rand")]
+
+use name_id::rand::{NameIdSampler, SamplerError};
+use name_id::{id, NameId};
+use rand::distributions::{Distribution, Standard};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const NAMES: &[&str] = &["load_a", "load_b", "load_c", "load_d"];
+
+#[test]
+fn standard_is_deterministic() {
+    let first: Vec<NameId> = StdRng::seed_from_u64(7)
+        .sample_iter(Standard)
+        .take(64)
+        .collect();
+    let second: Vec<NameId> = StdRng::seed_from_u64(7)
+        .sample_iter(Standard)
+        .take(64)
+        .collect();
+    assert_eq!(first, second);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let values: Vec<u64> = (0..64).map(|_| rng.gen()).collect();
+    assert_eq!(
+        first.iter().map(|id| id.value()).collect::<Vec<_>>(),
+        values
+    );
+}
+
+#[test]
+fn sampler_hashes_names() {
+    for sampler in [
+        NameIdSampler::uniform(NAMES).unwrap(),
+        NameIdSampler::zipf(NAMES, 1.2).unwrap(),
+    ] {
+        let mut names = StdRng::seed_from_u64(3);
+        let mut ids = StdRng::seed_from_u64(3);
+        for _ in 0..256 {
+            let name = sampler.sample_name(&mut names);
+            let id = sampler.sample(&mut ids);
+            assert!(NAMES.contains(&name));
+            assert_eq!(id, name);
+        }
+    }
+
+    let sampler = NameIdSampler::uniform(&["load_a"]).unwrap();
+    assert_eq!(sampler.sample(&mut StdRng::seed_from_u64(0)), id!(load_a));
+}
+
+#[test]
+fn zipf_prefers_first_names() {
+    let sampler = NameIdSampler::zipf(NAMES, 2.0).unwrap();
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut counts = [0usize; 4];
+    for _ in 0..10_000 {
+        let name = sampler.sample_name(&mut rng);
+        counts[NAMES.iter().position(|it| *it == name).unwrap()] += 1;
+    }
+    assert!(
+        counts.windows(2).all(|pair| pair[0] > pair[1]),
+        "{counts:?}"
+    );
+}
+
+#[test]
+fn invalid_samplers() {
+    assert_eq!(
+        NameIdSampler::uniform(&[]).unwrap_err(),
+        SamplerError::NoNames
+    );
+    assert_eq!(
+        NameIdSampler::zipf(&[], 1.0).unwrap_err(),
+        SamplerError::NoNames
+    );
+    assert_eq!(
+        NameIdSampler::zipf(NAMES, -1.0).unwrap_err(),
+        SamplerError::InvalidExponent
+    );
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "registry")]
+
+use std::thread;
+
+use name_id::NameId;
+
+#[test]
+fn concurrent_registration() {
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            thread::spawn(move || {
+                for i in 0..1000 {
+                    let name = format!("concurrent_{t}_{i}");
+                    let id = NameId::new(name.clone());
+                    assert_eq!(id, name);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("registration thread panicked");
+    }
+}
+
+#[test]
+fn sequential_registration() {
+    for i in 0..1000 {
+        let name = format!("sequential_{i}");
+        let first = NameId::new(name.clone());
+        let second = NameId::new(name);
+        assert_eq!(first, second);
+    }
+}
+
+#[cfg(feature = "detect_collisions")]
+#[test]
+fn collision_doesnt_poison_registry() {
+    let id = NameId::new("collision_original");
+    let result = std::panic::catch_unwind(|| name_id::register(id, "collision_other"));
+    assert!(result.is_err());
+
+    // registry must remain usable after a collision panic
+    name_id::register(id, "collision_original");
+    let _ = NameId::new("collision_after");
+}
+
+#[test]
+fn iteration() {
+    let names = ["iter_alpha", "iter_beta", "iter_gamma", "iter_delta"];
+    let ids: Vec<_> = names.iter().map(|name| NameId::new(*name)).collect();
+
+    for id in &ids {
+        assert!(name_id::registry_contains(*id));
+    }
+    // macro constructed ids bypass the registry
+    assert!(!name_id::registry_contains(name_id::id!(iter_unregistered)));
+    assert!(name_id::registry_len() >= names.len());
+
+    let entries: Vec<_> = name_id::registry_iter().collect();
+    assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    for (name, id) in names.iter().zip(&ids) {
+        assert!(entries.contains(&(id.value(), *name)));
+    }
+}
+
+#[test]
+fn resolve() {
+    let id = NameId::new("resolve_found".to_string());
+    assert_eq!(id.resolve(), Some("resolve_found"));
+    assert_eq!(name_id::id!(resolve_missing).resolve(), None);
+}
+
+#[test]
+fn display_fallback() {
+    let id = name_id::id!(display_registered);
+    name_id::register(id, "display_registered");
+    assert_eq!(id.to_string(), "#{display_registered}");
+
+    #[cfg(not(all(debug_assertions, feature = "debug_name")))]
+    {
+        let missing = name_id::id!(display_missing);
+        assert_eq!(missing.to_string(), format!("NameId({})", missing.value()));
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(any(feature = "registry", feature = "registry_static"))]
+
+use name_id::NameId;
+
+const NAMES: [&str; 6] = [
+    "ui.button.save",
+    "ui.button.cancel",
+    "ui.label.title",
+    "ui.buttons",
+    "audio.music.theme",
+    "audio.sfx.click",
+];
+
+fn register_names() {
+    for name in NAMES {
+        name_id::register(NameId::new(name), name);
+    }
+}
+
+fn find(prefix: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    name_id::registry_find_prefix(prefix, |name, id| {
+        assert_eq!(id, NameId::new(name));
+        found.push(name);
+    });
+    found.sort_unstable();
+    found
+}
+
+#[test]
+fn hierarchical_prefixes() {
+    register_names();
+    assert_eq!(
+        find("ui.but"),
+        ["ui.button.cancel", "ui.button.save", "ui.buttons"]
+    );
+    assert_eq!(find("ui.button."), ["ui.button.cancel", "ui.button.save"]);
+    assert_eq!(find("audio.sfx"), ["audio.sfx.click"]);
+    assert_eq!(find("ui.label.title"), ["ui.label.title"]);
+}
+
+#[test]
+fn empty_prefix_matches_all() {
+    register_names();
+    let all = find("");
+    for name in NAMES {
+        assert!(all.contains(&name));
+    }
+}
+
+#[test]
+fn no_matches() {
+    register_names();
+    assert!(find("video.").is_empty());
+    assert!(find("ui.button.save.extra").is_empty());
+}
+
+#[test]
+fn callback_can_access_registry() {
+    register_names();
+    let mut count = 0;
+    name_id::registry_find_prefix("audio.", |_, id| {
+        // registry lock isn't held while the callback runs
+        assert!(name_id::registry_contains(id));
+        count += 1;
+    });
+    assert!(count >= 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sorted_vec() {
+    register_names();
+    let found = name_id::registry_find_prefix_vec("ui.");
+    let names: Vec<_> = found.iter().map(|(name, _)| *name).collect();
+    assert_eq!(
+        names,
+        [
+            "ui.button.cancel",
+            "ui.button.save",
+            "ui.buttons",
+            "ui.label.title"
+        ]
+    );
+    for (name, id) in found {
+        assert_eq!(id, name);
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(all(feature = "detect_collisions_static", not(feature = "alloc")))]
+
+use std::sync::Mutex;
+
+use name_id::{CollisionInfo, CollisionKind, NameId, REGISTRY_CAPACITY};
+
+static REPORTED: Mutex<Vec<CollisionInfo>> = Mutex::new(Vec::new());
+
+fn record(info: CollisionInfo) {
+    REPORTED.lock().unwrap().push(info);
+}
+
+fn leak(name: String) -> &'static str {
+    Box::leak(name.into_boxed_str())
+}
+
+#[test]
+fn static_registry() {
+    name_id::set_collision_handler(record);
+
+    // normal operation
+    let first = NameId::new("static_first");
+    assert_eq!(first.resolve(), Some("static_first"));
+    let entries: Vec<_> = name_id::registry_iter().collect();
+    assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+    // detected collision
+    name_id::register(first, "static_other");
+    {
+        let reported = REPORTED.lock().unwrap();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].kind, CollisionKind::Collision);
+        assert_eq!(reported[0].previous, "static_first");
+        assert_eq!(reported[0].name, "static_other");
+    }
+
+    // capacity exhaustion
+    let mut i = 0;
+    while name_id::registry_len() < REGISTRY_CAPACITY {
+        let _ = NameId::new(leak(format!("static_fill_{i}")));
+        i += 1;
+    }
+    let overflow = NameId::new("static_overflow");
+    assert_eq!(overflow.resolve(), None);
+    assert_eq!(first.resolve(), Some("static_first"));
+
+    let reported = REPORTED.lock().unwrap();
+    assert_eq!(reported.len(), 2);
+    assert_eq!(reported[1].kind, CollisionKind::RegistryFull);
+    assert_eq!(reported[1].name, "static_overflow");
+
+    let entries: Vec<_> = name_id::registry_iter().collect();
+    assert_eq!(entries.len(), REGISTRY_CAPACITY);
+    assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "registry")]
+
+use name_id::NameId;
+
+#[test]
+fn counters_follow_constructions() {
+    let before = name_id::registry_stats();
+
+    let name = String::from("stats_owned");
+    let id = NameId::new(name.clone());
+    let _ = NameId::new("stats_static");
+    assert_eq!(id.resolve(), Some("stats_owned"));
+
+    let after = name_id::registry_stats();
+    assert_eq!(after.entries, before.entries + 2);
+    assert_eq!(after.leaked_bytes, before.leaked_bytes + name.len());
+    // two registrations and one resolve
+    assert_eq!(after.lookups, before.lookups + 3);
+
+    #[cfg(feature = "testing")]
+    {
+        name_id::registry_clear();
+        assert_eq!(name_id::registry_stats().entries, 0);
+        assert_eq!(id.resolve(), None);
+    }
+}
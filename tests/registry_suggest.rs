@@ -0,0 +1,60 @@
+#![cfg(all(
+    any(feature = "registry", feature = "registry_static"),
+    feature = "alloc"
+))]
+
+use name_id::NameId;
+
+const NAMES: [&str; 6] = [
+    "player_spawn",
+    "player_speed",
+    "player_health",
+    "enemy_spawn",
+    "level_exit",
+    "level_entry",
+];
+
+fn register_names() {
+    for name in NAMES {
+        let _ = NameId::new(name);
+    }
+}
+
+fn suggest(input: &str, max: usize) -> Vec<&'static str> {
+    name_id::registry_suggest(input, max).collect()
+}
+
+#[test]
+fn typo_suggests_intended_name_first() {
+    register_names();
+    // transposition, substitution, insertion and deletion
+    assert_eq!(suggest("player_spwan", 1), ["player_spawn"]);
+    assert_eq!(suggest("player_spaen", 1), ["player_spawn"]);
+    assert_eq!(suggest("player_spawnn", 1), ["player_spawn"]);
+    assert_eq!(suggest("level_exi", 1), ["level_exit"]);
+    assert_eq!(suggest("player_spawn", 3)[0], "player_spawn");
+}
+
+#[test]
+fn prefix_matches_and_limits() {
+    register_names();
+    let found = suggest("player_", 10);
+    assert_eq!(found.len(), 3);
+    assert!(found.iter().all(|name| name.starts_with("player_")));
+    assert_eq!(suggest("player_", 2).len(), 2);
+    assert!(suggest("completely_unrelated", 10).is_empty());
+    assert!(suggest("player_spwan", 0).is_empty());
+}
+
+#[test]
+fn near_miss_ids() {
+    register_names();
+    let value = NameId::new("level_exit").value();
+    let near =
+        |value| name_id::registry_suggest_for_id(NameId::from_value(value)).collect::<Vec<_>>();
+    assert!(near(value ^ 0b1001).contains(&"level_exit"));
+    assert!(near(value as u32 as u64).contains(&"level_exit"));
+    assert!(near(value as f64 as u64).contains(&"level_exit"));
+    assert!(!near(!value).contains(&"level_exit"));
+    assert_eq!(near(value)[0], "level_exit");
+}
@@ -0,0 +1,78 @@
+use name_id::{NameId, Resolved};
+
+#[test]
+fn unknown_names_are_hex() {
+    let id = NameId::from_value(0x1234);
+    assert_eq!(format!("{:?}", Resolved(&id)), "0x0000000000001234");
+    let ids = [id, NameId::from_value(u64::MAX)];
+    assert_eq!(
+        format!("{:?}", Resolved(&ids[..])),
+        "[0x0000000000001234, 0xffffffffffffffff]"
+    );
+    let pairs = [(id, "door")];
+    assert_eq!(
+        format!("{:#?}", Resolved(&pairs[..])),
+        "{\n    0x0000000000001234: \"door\",\n}"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn btree_map() {
+    let map: std::collections::BTreeMap<_, _> = [
+        (NameId::from_value(2), vec![1, 2]),
+        (NameId::from_value(1), vec![]),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        format!("{:?}", Resolved(&map)),
+        "{0x0000000000000001: [], 0x0000000000000002: [1, 2]}"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map() {
+    let map: std::collections::HashMap<_, _> = [(NameId::from_value(7), 'x')].into_iter().collect();
+    assert_eq!(format!("{:?}", Resolved(&map)), "{0x0000000000000007: 'x'}");
+}
+
+#[cfg(feature = "collections")]
+#[test]
+fn id_map() {
+    let map: name_id::IdMap<_> = [(NameId::from_value(7), 1)].into_iter().collect();
+    assert_eq!(format!("{:?}", Resolved(&map)), "{0x0000000000000007: 1}");
+}
+
+#[cfg(any(
+    all(debug_assertions, feature = "debug_name"),
+    feature = "registry",
+    feature = "registry_static"
+))]
+#[test]
+fn known_names() {
+    let cellar = NameId::new("resolved_cellar");
+    let attic = NameId::new("resolved_attic");
+    let unknown = NameId::from_value(0x1234);
+    assert_eq!(format!("{:?}", Resolved(&cellar)), "resolved_cellar");
+    assert_eq!(
+        format!("{:?}", Resolved(&[cellar, unknown][..])),
+        "[resolved_cellar, 0x0000000000001234]"
+    );
+    assert_eq!(
+        format!("{:#?}", Resolved(&[(attic, 1), (unknown, 2)][..])),
+        "{\n    resolved_attic: 1,\n    0x0000000000001234: 2,\n}"
+    );
+    #[cfg(feature = "alloc")]
+    {
+        let map: std::collections::BTreeMap<_, _> =
+            [(cellar, true), (unknown, false)].into_iter().collect();
+        let formatted = format!("{:?}", Resolved(&map));
+        assert!(formatted.contains("resolved_cellar: true"), "{formatted}");
+        assert!(
+            formatted.contains("0x0000000000001234: false"),
+            "{formatted}"
+        );
+    }
+}
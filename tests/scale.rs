@@ -0,0 +1,36 @@
+#![cfg(feature = "scale")]
+
+use name_id::{id, NameId};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::{TypeDef, TypeInfo};
+
+#[test]
+fn round_trip() {
+    let id = id!(pallet_storage_key);
+    let bytes = id.encode();
+    assert_eq!(bytes, id.value().to_le_bytes());
+    assert_eq!(NameId::decode(&mut &bytes[..]).unwrap(), id);
+
+    // values that would be short in compact encoding are still 8 bytes long
+    assert_eq!(NameId::from_value(1).encode().len(), 8);
+    assert!(NameId::decode(&mut &[0u8; 7][..]).is_err());
+}
+
+#[test]
+fn encoded_len() {
+    assert_eq!(NameId::max_encoded_len(), 8);
+    assert_eq!(id!(any).encoded_size(), NameId::max_encoded_len());
+}
+
+#[test]
+fn type_info() {
+    let info = NameId::type_info();
+    assert_eq!(info.path.segments.last().copied(), Some("NameId"));
+    match info.type_def {
+        TypeDef::Composite(composite) => {
+            assert_eq!(composite.fields.len(), 1);
+            assert_eq!(composite.fields[0].type_name, Some("u64"));
+        }
+        other => panic!("unexpected type definition: {:?}", other),
+    }
+}
@@ -0,0 +1,79 @@
+use name_id::{scan_collisions_in, Collision};
+
+#[cfg(feature = "ahash")]
+mod colliding {
+    use super::*;
+
+    // a genuine 64-bit collision of the default hasher
+    const A: &str = "cbd4938f3322cbc5f";
+    const B: &str = "ce6aa744e6a6c1f41";
+    const HASH: u64 = 0xc4089912f36545a0;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reports_collision() {
+        let collisions = name_id::scan_collisions([B, "unrelated", A]).unwrap_err();
+        assert_eq!(
+            collisions,
+            [Collision {
+                first: A,
+                second: B,
+                hash: HASH
+            }]
+        );
+        assert!(collisions[0].to_string().contains("0xc4089912f36545a0"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn duplicates_of_colliding_names() {
+        let collisions = name_id::scan_collisions([A, B, A, "x", B, A]).unwrap_err();
+        assert_eq!(collisions.len(), 1);
+    }
+
+    #[test]
+    fn streaming() {
+        let mut names = [A, "x", B, "y", A];
+        let mut found = [None; 4];
+        let mut count = 0;
+        scan_collisions_in(&mut names, |collision| {
+            found[count] = Some(collision);
+            count += 1;
+        });
+        assert_eq!(count, 1);
+        assert_eq!(
+            found[0],
+            Some(Collision {
+                first: A,
+                second: B,
+                hash: HASH
+            })
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn duplicates_are_not_collisions() {
+    assert_eq!(name_id::scan_collisions(["a", "b", "a", "a", ""]), Ok(()));
+    assert_eq!(name_id::scan_collisions(std::iter::empty()), Ok(()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn large_clean_list() {
+    let names: Vec<String> = (0..200_000)
+        .map(|i| format!("content.{}.item_{i}", i % 97))
+        .collect();
+    let start = std::time::Instant::now();
+    assert_eq!(
+        name_id::scan_collisions(names.iter().map(String::as_str)),
+        Ok(())
+    );
+    eprintln!("scanned {} names in {:?}", names.len(), start.elapsed());
+
+    let mut refs: Vec<&str> = names.iter().map(String::as_str).collect();
+    let mut count = 0;
+    scan_collisions_in(&mut refs[..10_000], |_| count += 1);
+    assert_eq!(count, 0);
+}
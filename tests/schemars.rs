@@ -0,0 +1,68 @@
+#![cfg(feature = "schemars")]
+
+use jsonschema_valid::schemas::Draft;
+use name_id::{id, NameId};
+use schemars::JsonSchema;
+use serde_json::json;
+
+#[derive(serde::Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Config {
+    spawn: NameId,
+    exits: Vec<NameId>,
+}
+
+fn schema() -> serde_json::Value {
+    schemars::generate::SchemaSettings::draft07()
+        .into_generator()
+        .into_root_schema_for::<Config>()
+        .to_value()
+}
+
+fn is_valid(schema: &serde_json::Value, document: &serde_json::Value) -> bool {
+    let config = jsonschema_valid::Config::from_schema(schema, Some(Draft::Draft7)).unwrap();
+    assert!(config.validate_schema().is_ok());
+    let valid = config.validate(document).is_ok();
+    valid
+}
+
+#[test]
+fn accepts_values_and_names() {
+    let schema = schema();
+    let documents = [
+        json!({ "spawn": 42, "exits": [] }),
+        json!({ "spawn": u64::MAX, "exits": [0] }),
+        json!({ "spawn": "player_spawn", "exits": ["exit_north", 7] }),
+    ];
+    for document in documents {
+        assert!(is_valid(&schema, &document), "{document}");
+        // anything valid per schema must be accepted by the deserializer
+        serde_json::from_value::<Config>(document).unwrap();
+    }
+
+    let config: Config =
+        serde_json::from_value(json!({ "spawn": "player_spawn", "exits": [] })).unwrap();
+    assert_eq!(config.spawn, id!(player_spawn));
+}
+
+#[test]
+fn rejects_other_types() {
+    let schema = schema();
+    let documents = [
+        json!({ "spawn": -1, "exits": [] }),
+        json!({ "spawn": 1.5, "exits": [] }),
+        json!({ "spawn": null, "exits": [] }),
+        json!({ "spawn": ["player_spawn"], "exits": [] }),
+    ];
+    for document in documents {
+        assert!(!is_valid(&schema, &document), "{document}");
+        assert!(serde_json::from_value::<Config>(document).is_err());
+    }
+}
+
+#[test]
+fn metadata() {
+    let schema = schemars::schema_for!(NameId);
+    assert_eq!(schema.get("title"), Some(&json!("NameId")));
+    assert!(schema.get("description").is_some());
+}
@@ -0,0 +1,9 @@
+#[test]
+fn runtime_matches_macro() {
+    name_id::self_test().unwrap();
+}
+
+#[test]
+fn verify_in_dependent_crate() {
+    name_id::verify!();
+}
@@ -0,0 +1,286 @@
+#![cfg(feature = "serde")]
+
+use name_id::{id, NameId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    spawn: NameId,
+    exit: NameId,
+}
+
+#[test]
+fn json_round_trip() {
+    let config = Config {
+        spawn: id!(player_spawn),
+        exit: id!(level_exit),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            r#"{{"spawn":{},"exit":{}}}"#,
+            config.spawn.value(),
+            config.exit.value()
+        )
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}
+
+#[test]
+fn json_accepts_names() {
+    let config: Config =
+        serde_json::from_str(r#"{"spawn":"player_spawn","exit":"level_exit"}"#).unwrap();
+    assert_eq!(config.spawn, id!(player_spawn));
+    assert_eq!(config.exit, "level_exit");
+    assert!(serde_json::from_str::<NameId>("-1").is_err());
+}
+
+#[test]
+fn postcard_round_trip() {
+    let config = Config {
+        spawn: id!(player_spawn),
+        exit: id!(level_exit),
+    };
+    let bytes = postcard::to_allocvec(&config).unwrap();
+    assert_eq!(postcard::from_bytes::<Config>(&bytes).unwrap(), config);
+}
+
+#[test]
+fn toml_round_trip() {
+    // TOML integers are signed, so only values up to i64::MAX can be stored
+    let config = Config {
+        spawn: NameId::from_value(1234),
+        exit: NameId::from_value(i64::MAX as u64),
+    };
+    let text = toml::to_string(&config).unwrap();
+    assert_eq!(toml::from_str::<Config>(&text).unwrap(), config);
+
+    let config: Config = toml::from_str("spawn = \"player_spawn\"\nexit = 42\n").unwrap();
+    assert_eq!(config.spawn, id!(player_spawn));
+    assert_eq!(config.exit, NameId::from_value(42));
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Save {
+    #[serde(with = "name_id::serde_key")]
+    scores: std::collections::HashMap<NameId, u32>,
+    #[serde(with = "name_id::serde_key")]
+    labels: std::collections::BTreeMap<NameId, String>,
+}
+
+#[test]
+fn json_map_keys_round_trip() {
+    let save = Save {
+        scores: [(id!(alice), 10), (id!(bob), 20)].into_iter().collect(),
+        labels: [(NameId::from_value(1234), "raw".to_string())]
+            .into_iter()
+            .collect(),
+    };
+    let json = serde_json::to_string(&save).unwrap();
+    assert!(json.contains(r#""0x00000000000004d2":"raw""#));
+    assert_eq!(serde_json::from_str::<Save>(&json).unwrap(), save);
+}
+
+#[test]
+fn json_map_name_keys() {
+    let save: Save = serde_json::from_str(
+        r#"{"scores":{"alice":10,"0x00000000000004d2":5},"labels":{"1234":"decimal"}}"#,
+    )
+    .unwrap();
+    assert_eq!(save.scores[&id!(alice)], 10);
+    assert_eq!(save.scores[&NameId::from_value(1234)], 5);
+    assert_eq!(save.labels[&NameId::from_value(1234)], "decimal");
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NamedSave {
+    #[serde(with = "name_id::serde_named_keys")]
+    spawns: std::collections::HashMap<NameId, u32>,
+    #[serde(with = "name_id::serde_named_keys")]
+    doors: std::collections::BTreeMap<NameId, bool>,
+}
+
+#[cfg(any(
+    all(debug_assertions, feature = "debug_name"),
+    feature = "registry",
+    feature = "registry_static"
+))]
+#[test]
+fn json_named_keys_round_trip() {
+    let spawn = id!(player_spawn);
+    let door = id!(cellar_door);
+    #[cfg(any(feature = "registry", feature = "registry_static"))]
+    {
+        name_id::register(spawn, "player_spawn");
+        name_id::register(door, "cellar_door");
+    }
+    let save = NamedSave {
+        spawns: [(spawn, 1)].into_iter().collect(),
+        doors: [(door, true)].into_iter().collect(),
+    };
+    let json = serde_json::to_string(&save).unwrap();
+    assert_eq!(
+        json,
+        r#"{"spawns":{"player_spawn":1},"doors":{"cellar_door":true}}"#
+    );
+    assert_eq!(serde_json::from_str::<NamedSave>(&json).unwrap(), save);
+}
+
+#[test]
+fn json_named_keys_hash_fallback() {
+    let save = NamedSave {
+        spawns: [(NameId::from_value(1234), 2)].into_iter().collect(),
+        doors: [(NameId::from_value(u64::MAX), false)]
+            .into_iter()
+            .collect(),
+    };
+    let json = serde_json::to_string(&save).unwrap();
+    assert_eq!(
+        json,
+        r#"{"spawns":{"0x00000000000004d2":2},"doors":{"0xffffffffffffffff":false}}"#
+    );
+    assert_eq!(serde_json::from_str::<NamedSave>(&json).unwrap(), save);
+}
+
+#[test]
+fn json_named_keys_mixed() {
+    let save: NamedSave = serde_json::from_str(
+        r#"{"spawns":{"player_spawn":1,"0x00000000000004d2":2,"99":3},"doors":{}}"#,
+    )
+    .unwrap();
+    assert_eq!(save.spawns.len(), 3);
+    assert_eq!(save.spawns[&id!(player_spawn)], 1);
+    assert_eq!(save.spawns[&NameId::from_value(1234)], 2);
+    assert_eq!(save.spawns[&NameId::from_value(99)], 3);
+
+    let json = serde_json::to_string(&save).unwrap();
+    assert!(json.contains(r#""0x00000000000004d2":2"#));
+    assert!(json.contains(r#""0x0000000000000063":3"#));
+    assert_eq!(serde_json::from_str::<NamedSave>(&json).unwrap(), save);
+}
+
+#[derive(Debug, Deserialize)]
+struct Triggers {
+    trigger: Vec<Trigger>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Trigger {
+    #[serde(with = "name_id::flexible")]
+    event: NameId,
+}
+
+fn check_flexible_fixture(triggers: Triggers) {
+    let events: Vec<_> = triggers.trigger.iter().map(|it| it.event).collect();
+    let raw = NameId::from_value(1234);
+    assert_eq!(events[..3], [raw, raw, raw]);
+    assert_eq!(events[3], id!(player_spawn));
+    // numeric strings without a prefix are names
+    assert_eq!(events[4], id!("1234"));
+    assert_ne!(events[4], raw);
+}
+
+#[test]
+fn flexible_fixtures() {
+    check_flexible_fixture(toml::from_str(include_str!("fixtures/flexible.toml")).unwrap());
+    check_flexible_fixture(serde_json::from_str(include_str!("fixtures/flexible.json")).unwrap());
+}
+
+#[test]
+fn flexible_errors() {
+    let error = serde_json::from_str::<Trigger>(r#"{"event":-5}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("non-negative integer"), "{}", error);
+    let error = serde_json::from_str::<Trigger>(r#"{"event":"0xnothex"}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("\"0x\" (hex)"), "{}", error);
+    assert!(serde_json::from_str::<Trigger>(r#"{"event":1.5}"#).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Door {
+    #[serde(with = "name_id::serde_str")]
+    leads_to: NameId,
+}
+
+#[cfg(all(debug_assertions, feature = "debug_name"))]
+#[test]
+fn serde_str_label() {
+    let door = Door {
+        leads_to: id!(wine_cellar),
+    };
+    let json = serde_json::to_string(&door).unwrap();
+    assert_eq!(json, r#"{"leads_to":"wine_cellar"}"#);
+    assert_eq!(serde_json::from_str::<Door>(&json).unwrap(), door);
+}
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+#[test]
+fn serde_str_registry() {
+    // strips the debug label so only the registry can provide the name
+    let leads_to = NameId::from_value(id!(attic).value());
+    name_id::register(leads_to, "attic");
+    let door = Door { leads_to };
+    let json = serde_json::to_string(&door).unwrap();
+    assert_eq!(json, r#"{"leads_to":"attic"}"#);
+    assert_eq!(serde_json::from_str::<Door>(&json).unwrap(), door);
+
+    let text = toml::to_string(&door).unwrap();
+    assert_eq!(text, "leads_to = \"attic\"\n");
+    assert_eq!(toml::from_str::<Door>(&text).unwrap(), door);
+}
+
+#[test]
+fn serde_str_unknown() {
+    let door = Door {
+        leads_to: NameId::from_value(1234),
+    };
+    let json = serde_json::to_string(&door).unwrap();
+    assert_eq!(json, r##"{"leads_to":"#00000000000004d2"}"##);
+    assert_eq!(serde_json::from_str::<Door>(&json).unwrap(), door);
+
+    let door = Door {
+        leads_to: NameId::from_value(u64::MAX),
+    };
+    let text = toml::to_string(&door).unwrap();
+    assert_eq!(text, "leads_to = \"#ffffffffffffffff\"\n");
+    assert_eq!(toml::from_str::<Door>(&text).unwrap(), door);
+}
+
+#[test]
+fn serde_str_parsing() {
+    let door: Door = toml::from_str("leads_to = \"garden\"\n").unwrap();
+    assert_eq!(door.leads_to, id!(garden));
+    // digits are names, only `#` marks hash values
+    let door: Door = serde_json::from_str(r#"{"leads_to":"1234"}"#).unwrap();
+    assert_eq!(door.leads_to, id!("1234"));
+    assert!(serde_json::from_str::<Door>(r##"{"leads_to":"#nothex"}"##).is_err());
+    assert!(serde_json::from_str::<Door>(r#"{"leads_to":1234}"#).is_err());
+}
+
+#[test]
+fn maybe_named() {
+    use name_id::MaybeNamed;
+
+    let id = NameId::from_value(0x2a);
+    let named = MaybeNamed::Named(id, "door");
+    assert_eq!(serde_json::to_string(&named).unwrap(), r#""door""#);
+    assert_eq!(
+        serde_json::to_string(&MaybeNamed::Unnamed(id)).unwrap(),
+        "42"
+    );
+    assert_eq!(serde_json::from_str::<MaybeNamed>("42").unwrap(), id);
+    assert_eq!(
+        serde_json::from_str::<MaybeNamed>(r#""door""#).unwrap(),
+        NameId::new("door")
+    );
+
+    // binary formats always store the hash value
+    let bytes = postcard::to_allocvec(&named).unwrap();
+    assert_eq!(bytes, postcard::to_allocvec(&id).unwrap());
+    let decoded: MaybeNamed = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, id);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "smol_str")]
+
+use name_id::NameId;
+use smol_str::SmolStr;
+
+#[test]
+fn parity_with_str() {
+    for name in ["", "player_spawn", "a name longer than the inline capacity"] {
+        let smol = SmolStr::new(name);
+        assert_eq!(NameId::from(&smol).value(), NameId::new(name).value());
+        assert_eq!(NameId::from(smol).value(), NameId::new(name).value());
+    }
+}
+
+#[cfg(any(feature = "registry", feature = "registry_static"))]
+#[test]
+fn repeated_conversions_dont_leak() {
+    let name = SmolStr::new("smol_str_leak_check");
+    let before = name_id::registry_stats().leaked_bytes;
+    let id = NameId::from(&name);
+    assert_eq!(id.resolve(), Some("smol_str_leak_check"));
+    let after_first = name_id::registry_stats().leaked_bytes;
+    assert_eq!(after_first, before + name.len());
+    for _ in 0..100 {
+        assert_eq!(NameId::from(&name), id);
+        assert_eq!(NameId::from(name.clone()), id);
+    }
+    assert_eq!(name_id::registry_stats().leaked_bytes, after_first);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "registry")]
+
+use name_id::{NameId, RegistrySnapshot, SnapshotError};
+
+const NAMES: [&str; 4] = [
+    "snapshot_plain",
+    "snapshot\twith\ttabs",
+    "snapshot\nwith\r\nnewlines",
+    "snapshot\\with\\backslashes",
+];
+
+fn register_names() -> Vec<NameId> {
+    NAMES.iter().map(|name| NameId::new(*name)).collect()
+}
+
+#[test]
+fn text_round_trip() {
+    let ids = register_names();
+    let mut text = String::new();
+    name_id::registry_snapshot(&mut text).unwrap();
+    assert_eq!(text.lines().count(), name_id::registry_len());
+    assert!(text.contains(&format!(
+        "{:016x}\tsnapshot\\twith\\ttabs\n",
+        ids[1].value()
+    )));
+
+    let snapshot = RegistrySnapshot::parse_text(&text).unwrap();
+    for (id, name) in ids.iter().zip(NAMES) {
+        assert_eq!(snapshot.resolve(id.value()), Some(name));
+    }
+    assert_eq!(
+        snapshot.resolve(name_id::id!(snapshot_missing).value()),
+        None
+    );
+}
+
+#[test]
+fn binary_round_trip() {
+    let ids = register_names();
+    let bytes = name_id::registry_snapshot_bytes();
+    let snapshot = RegistrySnapshot::parse(&bytes).unwrap();
+    assert_eq!(snapshot.len(), name_id::registry_len());
+    for (id, name) in ids.iter().zip(NAMES) {
+        assert_eq!(snapshot.resolve(id.value()), Some(name));
+    }
+}
+
+#[test]
+fn malformed_input() {
+    assert_eq!(
+        RegistrySnapshot::parse(&[0; 8]),
+        Err(SnapshotError::Truncated { offset: 0 })
+    );
+    assert_eq!(
+        RegistrySnapshot::parse_text("0000000000000001\tok\nnot a hash\n"),
+        Err(SnapshotError::InvalidLine { line: 2 })
+    );
+    assert_eq!(
+        RegistrySnapshot::parse_text("0000000000000001\tbad\\x\n"),
+        Err(SnapshotError::InvalidEscape { line: 1 })
+    );
+}
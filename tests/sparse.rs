@@ -0,0 +1,122 @@
+#![cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+
+use name_id::{id, NameId, SparseIdMap};
+
+fn ids(n: u64) -> Vec<NameId> {
+    (0..n).map(NameId::from_value).collect()
+}
+
+/// Checks that every id maps to the slot holding it, and that slots are
+/// contiguous.
+fn check_invariants<V>(map: &SparseIdMap<V>) {
+    assert_eq!(map.ids().len(), map.values().len());
+    assert_eq!(map.len(), map.values().len());
+    for (slot, id) in map.ids().iter().enumerate() {
+        assert_eq!(map.slot(*id), Some(slot));
+    }
+}
+
+#[test]
+fn insert_and_get() {
+    let mut map = SparseIdMap::new();
+    assert_eq!(map.insert(id!(a), 1), None);
+    assert_eq!(map.insert(id!(b), 2), None);
+    assert_eq!(map.insert(id!(a), 3), Some(1));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(id!(a)), Some(&3));
+    assert_eq!(map.get(id!(missing)), None);
+    *map.get_mut(id!(b)).unwrap() += 10;
+    assert_eq!(map.values(), [3, 12]);
+    assert_eq!(map.ids(), [id!(a), id!(b)]);
+    check_invariants(&map);
+}
+
+#[test]
+fn remove_swaps_last_value_in() {
+    let ids = ids(4);
+    let mut map: SparseIdMap<u64> = ids.iter().map(|id| (*id, id.value())).collect();
+    assert_eq!(map.remove(ids[1]), Some(1));
+    assert_eq!(map.values(), [0, 3, 2]);
+    assert_eq!(map.ids(), [ids[0], ids[3], ids[2]]);
+    check_invariants(&map);
+
+    // removing the last value doesn't move anything
+    assert_eq!(map.remove(ids[2]), Some(2));
+    assert_eq!(map.values(), [0, 3]);
+    check_invariants(&map);
+
+    assert_eq!(map.remove(ids[2]), None);
+    assert_eq!(map.remove(ids[0]), Some(0));
+    assert_eq!(map.remove(ids[3]), Some(3));
+    assert!(map.is_empty());
+    check_invariants(&map);
+}
+
+#[test]
+fn remove_while_walking_slots() {
+    let ids = ids(100);
+    let mut map: SparseIdMap<u64> = ids.iter().map(|id| (*id, id.value())).collect();
+    let mut slot = 0;
+    while slot < map.len() {
+        let id = map.ids()[slot];
+        if map.values()[slot].is_multiple_of(3) {
+            // revisit the slot, it now holds the previously last value
+            map.remove(id);
+        } else {
+            slot += 1;
+        }
+        check_invariants(&map);
+    }
+    assert_eq!(map.len(), 66);
+    assert!(map.values().iter().all(|value| !value.is_multiple_of(3)));
+    for id in ids {
+        assert_eq!(map.contains_key(id), !id.value().is_multiple_of(3));
+    }
+}
+
+#[test]
+fn retain() {
+    let ids = ids(50);
+    let mut map: SparseIdMap<u64> = ids.iter().map(|id| (*id, id.value())).collect();
+    let mut visited = 0;
+    map.retain(|id, value| {
+        visited += 1;
+        *value *= 2;
+        id.value().is_multiple_of(2)
+    });
+    assert_eq!(visited, 50);
+    assert_eq!(map.len(), 25);
+    for (id, value) in &map {
+        assert_eq!(*value, id.value() * 2);
+    }
+    check_invariants(&map);
+}
+
+#[test]
+fn iteration_order() {
+    let ids = ids(5);
+    let mut map: SparseIdMap<u64> = ids.iter().map(|id| (*id, id.value())).collect();
+    let order: Vec<NameId> = map.iter().map(|(id, _)| id).collect();
+    assert_eq!(order, ids);
+    for (id, value) in map.iter_mut() {
+        *value += id.value();
+    }
+    assert_eq!(map.values(), [0, 2, 4, 6, 8]);
+    for value in map.values_mut() {
+        *value += 1;
+    }
+    assert_eq!(map.values(), [1, 3, 5, 7, 9]);
+    assert_eq!(map.iter().len(), 5);
+}
+
+#[test]
+fn clear_and_reuse() {
+    let mut map = SparseIdMap::with_capacity(4);
+    map.extend([(id!(a), 'a'), (id!(b), 'b')]);
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get(id!(a)), None);
+    map.insert(id!(b), 'c');
+    assert_eq!(map.slot(id!(b)), Some(0));
+    check_invariants(&map);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "speedy")]
+
+use name_id::{id, NameId};
+use speedy::{BigEndian, LittleEndian, Readable, Writable};
+
+#[test]
+fn little_endian() {
+    let id = id!(snapshot_entry);
+    let bytes = id.write_to_vec_with_ctx(LittleEndian::default()).unwrap();
+    assert_eq!(bytes, id.value().to_le_bytes());
+    assert_eq!(
+        NameId::read_from_buffer_with_ctx(LittleEndian::default(), &bytes).unwrap(),
+        id
+    );
+}
+
+#[test]
+fn big_endian() {
+    let id = id!(snapshot_entry);
+    let bytes = id.write_to_vec_with_ctx(BigEndian::default()).unwrap();
+    assert_eq!(bytes, id.value().to_be_bytes());
+    assert_eq!(
+        NameId::read_from_buffer_with_ctx(BigEndian::default(), &bytes).unwrap(),
+        id
+    );
+}
+
+#[derive(Debug, PartialEq, Readable, Writable)]
+struct Entry {
+    tag: u8,
+    id: NameId,
+    children: Vec<NameId>,
+}
+
+#[test]
+fn derived_struct() {
+    let entry = Entry {
+        tag: 7,
+        id: id!(snapshot_root),
+        children: vec![id!(snapshot_left), id!(snapshot_right)],
+    };
+    let bytes = entry.write_to_vec().unwrap();
+    // tag, id, children length as u32 and children
+    assert_eq!(bytes.len(), 1 + 8 + 4 + 2 * 8);
+    assert_eq!(Entry::read_from_buffer(&bytes).unwrap(), entry);
+}
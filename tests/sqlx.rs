@@ -0,0 +1,83 @@
+#![cfg(feature = "sqlx")]
+//! Runs against an in-memory SQLite database. Set `NAME_ID_SQLX_TEST=1` to
+//! enable it.
+
+use name_id::{id, NameId};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Row, SqlitePool};
+
+fn enabled() -> bool {
+    std::env::var_os("NAME_ID_SQLX_TEST").is_some()
+}
+
+async fn pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE items (id BIGINT PRIMARY KEY NOT NULL, label TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    pool
+}
+
+#[derive(Debug, PartialEq, sqlx::FromRow)]
+struct Item {
+    id: NameId,
+    label: String,
+}
+
+#[tokio::test]
+async fn round_trip() {
+    if !enabled() {
+        return;
+    }
+    let pool = pool().await;
+    let values = [
+        NameId::from_value(0),
+        NameId::from_value(1),
+        NameId::from_value(i64::MAX as u64),
+        NameId::from_value(1 << 63),
+        NameId::from_value(u64::MAX),
+        id!(sqlx_item),
+    ];
+    for (i, id) in values.iter().enumerate() {
+        sqlx::query("INSERT INTO items (id, label) VALUES (?, ?)")
+            .bind(id)
+            .bind(format!("item {i}"))
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    for (i, id) in values.iter().enumerate() {
+        let item: Item = sqlx::query_as("SELECT id, label FROM items WHERE id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(item.id, *id);
+        assert_eq!(item.label, format!("item {i}"));
+    }
+}
+
+#[tokio::test]
+async fn top_bit_is_stored_as_negative() {
+    if !enabled() {
+        return;
+    }
+    let pool = pool().await;
+    let id = NameId::from_value(u64::MAX);
+    sqlx::query("INSERT INTO items (id, label) VALUES (?, 'max')")
+        .bind(id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let row = sqlx::query("SELECT id FROM items")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<i64, _>("id"), -1);
+    assert_eq!(row.get::<NameId, _>("id"), id);
+}
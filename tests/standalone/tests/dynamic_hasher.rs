@@ -0,0 +1,127 @@
+#![cfg(feature = "dynamic_hasher")]
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+use name_id::{InstallHasherError, NameId};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn test_hasher(bytes: &[u8]) -> u64 {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+    expected(bytes)
+}
+
+fn expected(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(7u64, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(*byte as u64)
+    })
+}
+
+/// Bytes written by `Hash` implementation of `str`.
+fn str_hash(name: &str) -> u64 {
+    let mut bytes = name.as_bytes().to_vec();
+    bytes.push(0xff);
+    expected(&bytes)
+}
+
+/// Bytes written by `Hash` implementation of `[u8]`.
+fn slice_hash(data: &[u8]) -> u64 {
+    let mut bytes = (data.len() as u64).to_le_bytes().to_vec();
+    bytes.extend_from_slice(data);
+    expected(&bytes)
+}
+
+/// All tests share the process-global hasher, so it's installed before
+/// anything is hashed.
+fn setup() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| name_id::install_hasher(test_hasher).unwrap());
+}
+
+#[test]
+fn from_impls_use_installed_hasher() {
+    setup();
+    let before = CALLS.load(Ordering::Relaxed);
+    let name = "player.spawn";
+    let value = str_hash(name);
+    assert_eq!(NameId::new(name).value(), value);
+    assert_eq!(NameId::from(name.to_string()).value(), value);
+    assert_eq!(NameId::from(&name.to_string()).value(), value);
+    assert_eq!(NameId::from(Cow::Borrowed(name)).value(), value);
+    assert_eq!(NameId::from(&Cow::Borrowed(name)).value(), value);
+
+    let bytes = b"raw\xffbytes";
+    assert_eq!(NameId::from(&bytes[..]).value(), slice_hash(bytes));
+    assert_eq!(NameId::from(bytes.to_vec()).value(), slice_hash(bytes));
+    assert_eq!(NameId::from(&bytes.to_vec()).value(), slice_hash(bytes));
+
+    let c_name = CString::new(name).unwrap();
+    let c_value = slice_hash(c_name.as_bytes_with_nul());
+    assert_eq!(NameId::from(c_name.as_c_str()).value(), c_value);
+    assert_eq!(NameId::from(&c_name).value(), c_value);
+    assert_eq!(NameId::from(c_name).value(), c_value);
+
+    assert!(CALLS.load(Ordering::Relaxed) >= before + 11);
+}
+
+#[test]
+fn eq_impls_use_installed_hasher() {
+    setup();
+    let id = NameId::from_value(str_hash("door.open"));
+    let owned = String::from("door.open");
+    assert_eq!(id, "door.open");
+    assert_eq!(id, *"door.open");
+    assert_eq!(id, owned);
+    assert_eq!(id, &owned);
+    assert_eq!(id, Cow::Borrowed("door.open"));
+    assert_eq!(id, Box::<str>::from("door.open"));
+    assert_eq!(id, b"door.open"[..]);
+    assert_eq!(id, &b"door.open"[..]);
+    assert_ne!(id, "door.close");
+
+    let c_name: &CStr = c"door.open";
+    let c_id = NameId::from_value(slice_hash(c_name.to_bytes_with_nul()));
+    assert_eq!(c_id, c_name);
+    assert_eq!(c_id, c_name.to_owned());
+}
+
+#[test]
+fn install_after_use_fails() {
+    setup();
+    let _ = NameId::new("used");
+    assert_eq!(
+        name_id::install_hasher(test_hasher),
+        Err(InstallHasherError::AlreadyUsed)
+    );
+}
+
+#[test]
+fn builtin_hashers() {
+    let fnv1a = name_id::builtin_hasher("fnv1a").unwrap();
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    assert!(name_id::builtin_hasher(name_id::DEFAULT_HASHER).is_some());
+    assert!(name_id::builtin_hasher("unknown").is_none());
+    for (name, hasher) in name_id::BUILTIN_HASHERS {
+        let found = name_id::builtin_hasher(name).unwrap();
+        assert_eq!(found(b"probe"), hasher(b"probe"));
+    }
+}
+
+#[test]
+fn fingerprint_is_pinned() {
+    // doesn't describe the installed hasher, so it only changes with hashing
+    // conventions
+    assert_eq!(name_id::ALGORITHM.hasher, "dynamic");
+    assert_eq!(name_id::ALGORITHM.seed_digest, 0);
+    assert_eq!(name_id::ALGORITHM_FINGERPRINT, 0xfcb8_6ac9_3f69_c0c2);
+}
+
+#[test]
+fn macros_are_rejected() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,2 @@
+#[path = "../../portable_hash.rs"]
+mod portable_hash;
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = name_id::id!(player);
+}
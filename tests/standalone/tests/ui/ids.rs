@@ -0,0 +1,5 @@
+name_id::ids! {
+    PLAYER;
+}
+
+fn main() {}
@@ -0,0 +1,74 @@
+use name_id::{id, NameId, NameIdTable};
+
+static SPEEDS: NameIdTable<u32, 4> = NameIdTable::new([
+    (id!(walk), 10),
+    (id!(run), 25),
+    (id!(crawl), 3),
+    (id!(swim), 7),
+]);
+
+const RUN_SPEED: u32 = match SPEEDS.const_get(id!(run)) {
+    Some(speed) => *speed,
+    None => panic!("missing run speed"),
+};
+
+#[test]
+fn lookup_hits() {
+    assert_eq!(SPEEDS.get(id!(walk)), Some(&10));
+    assert_eq!(SPEEDS.get(id!(run)), Some(&25));
+    assert_eq!(SPEEDS.get(id!(crawl)), Some(&3));
+    assert_eq!(SPEEDS.const_get(id!(swim)), Some(&7));
+    assert!(SPEEDS.contains_key(id!(swim)));
+}
+
+#[test]
+fn lookup_misses() {
+    assert_eq!(SPEEDS.get(id!(fly)), None);
+    assert_eq!(SPEEDS.const_get(id!(fly)), None);
+    assert_eq!(SPEEDS.get(NameId::from_value(0)), None);
+    assert_eq!(SPEEDS.get(NameId::from_value(u64::MAX)), None);
+    assert!(!SPEEDS.contains_key(id!(fly)));
+}
+
+#[test]
+fn static_table_in_const_context() {
+    assert_eq!(RUN_SPEED, 25);
+    const { assert!(!SPEEDS.const_contains_key(id!(fly))) };
+    const LEN: usize = SPEEDS.len();
+    assert_eq!(LEN, 4);
+}
+
+#[test]
+fn entries_are_sorted() {
+    let values: Vec<u64> = SPEEDS.iter().map(|(id, _)| id.value()).collect();
+    assert!(values.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(SPEEDS.entries().len(), 4);
+}
+
+#[test]
+fn large_table() {
+    let entries: [(NameId, u64); 300] =
+        core::array::from_fn(|i| (NameId::from_value((i as u64 * 7919) % 1009), i as u64));
+    let table = NameIdTable::new(entries);
+    for (id, i) in entries {
+        assert_eq!(table.get(id), Some(&i));
+        assert_eq!(table.const_get(id), Some(&i));
+    }
+    assert_eq!(table.get(NameId::from_value(1009)), None);
+    assert_eq!((&table).into_iter().count(), 300);
+}
+
+#[test]
+fn empty_table() {
+    const EMPTY: NameIdTable<(), 0> = NameIdTable::new([]);
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.get(id!(walk)), None);
+    assert_eq!(EMPTY.const_get(id!(walk)), None);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "NameIdTable contains duplicate ids")]
+fn duplicates_are_detected() {
+    NameIdTable::new([(id!(walk), 1), (id!(run), 2), (id!(walk), 3)]);
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use name_id::{id, NameId};
+use tracing::field::{Empty, Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Recorded {
+    U64(u64),
+    Str(String),
+    Debug(String),
+}
+
+#[derive(Clone, Default)]
+struct MockSubscriber {
+    fields: Arc<Mutex<Vec<(String, Recorded)>>>,
+}
+
+impl MockSubscriber {
+    fn get(&self, name: &str) -> Option<Recorded> {
+        let fields = self.fields.lock().unwrap();
+        fields
+            .iter()
+            .rev()
+            .find(|(it, _)| it == name)
+            .map(|(_, value)| value.clone())
+    }
+}
+
+impl Visit for MockSubscriber {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        let mut fields = self.fields.lock().unwrap();
+        fields.push((field.name().to_string(), Recorded::U64(value)));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let mut fields = self.fields.lock().unwrap();
+        fields.push((field.name().to_string(), Recorded::Str(value.to_string())));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let mut fields = self.fields.lock().unwrap();
+        fields.push((
+            field.name().to_string(),
+            Recorded::Debug(format!("{value:?}")),
+        ));
+    }
+}
+
+impl Subscriber for MockSubscriber {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        span.record(&mut self.clone());
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn as_value() {
+    let subscriber = MockSubscriber::default();
+    let id = id!(player_model);
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        tracing::info!(asset = id.as_value(), "loading");
+    });
+    assert_eq!(subscriber.get("asset"), Some(Recorded::U64(id.value())));
+}
+
+#[test]
+fn record_named() {
+    let subscriber = MockSubscriber::default();
+    let id = id!(player_model);
+    let unnamed = NameId::from_value(42);
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        let span = tracing::info_span!("load", asset = Empty, asset.name = Empty);
+        name_id::tracing::record_named(&span, "asset", id);
+        let other = tracing::info_span!("load", other = Empty, other.name = Empty);
+        name_id::tracing::record_named(&other, "other", unnamed);
+    });
+
+    assert_eq!(subscriber.get("asset"), Some(Recorded::U64(id.value())));
+    #[cfg(all(debug_assertions, feature = "debug_name"))]
+    assert_eq!(
+        subscriber.get("asset.name"),
+        Some(Recorded::Str("player_model".to_string()))
+    );
+    #[cfg(not(all(debug_assertions, feature = "debug_name")))]
+    assert_eq!(subscriber.get("asset.name"), None);
+
+    assert_eq!(subscriber.get("other"), Some(Recorded::U64(42)));
+    assert_eq!(subscriber.get("other.name"), None);
+}
+
+#[cfg(feature = "registry")]
+#[test]
+fn record_registered_name() {
+    let subscriber = MockSubscriber::default();
+    let id = NameId::from_value(NameId::new("tracing_registered".to_string()).value());
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        let span = tracing::info_span!("load", asset = Empty, asset.name = Empty);
+        name_id::tracing::record_named(&span, "asset", id);
+    });
+    assert_eq!(
+        subscriber.get("asset.name"),
+        Some(Recorded::Str("tracing_registered".to_string()))
+    );
+}
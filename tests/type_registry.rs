@@ -0,0 +1,111 @@
+#![cfg(all(
+    feature = "alloc",
+    any(feature = "std", feature = "critical-section", feature = "spin")
+))]
+
+use std::any::TypeId;
+
+use name_id::{id, NameId, TypeConflict, TypeRegistry};
+
+struct Position;
+struct Velocity;
+struct Health;
+struct Unregistered;
+
+fn registry() -> TypeRegistry {
+    let types = TypeRegistry::new();
+    types.register::<Position>("component.position").unwrap();
+    types.register::<Velocity>("component.velocity").unwrap();
+    types
+        .register_id::<Health>(id!("component.health"))
+        .unwrap();
+    types
+}
+
+#[test]
+fn lookups() {
+    let types = registry();
+    assert_eq!(types.len(), 3);
+    assert_eq!(types.id_of::<Position>(), Some(id!("component.position")));
+    assert_eq!(types.id_of::<Health>(), Some(id!("component.health")));
+    assert_eq!(types.id_of::<Unregistered>(), None);
+    assert_eq!(
+        types.id_of_type(TypeId::of::<Velocity>()),
+        Some(id!("component.velocity"))
+    );
+    assert_eq!(
+        types.type_of(id!("component.velocity")),
+        Some(TypeId::of::<Velocity>())
+    );
+    assert_eq!(
+        types.type_of(id!("component.health")),
+        Some(TypeId::of::<Health>())
+    );
+    assert_eq!(types.type_of(id!("component.mana")), None);
+}
+
+#[test]
+fn same_pair_again() {
+    let types = registry();
+    let position = NameId::new("component.position");
+    assert_eq!(
+        types.register::<Position>("component.position"),
+        Ok(position)
+    );
+    assert_eq!(types.register_id::<Position>(position), Ok(()));
+    assert_eq!(types.len(), 3);
+}
+
+#[test]
+fn conflicts() {
+    let types = registry();
+    let position = id!("component.position");
+    let velocity = id!("component.velocity");
+
+    // type registered with a different id
+    assert_eq!(
+        types.register::<Position>("component.place"),
+        Err(TypeConflict {
+            id: id!("component.place"),
+            registered_id: Some(position),
+            registered_type: None,
+        })
+    );
+    // id registered for a different type
+    assert_eq!(
+        types.register_id::<Unregistered>(position),
+        Err(TypeConflict {
+            id: position,
+            registered_id: None,
+            registered_type: Some(TypeId::of::<Position>()),
+        })
+    );
+    // both
+    assert_eq!(
+        types.register_id::<Position>(velocity),
+        Err(TypeConflict {
+            id: velocity,
+            registered_id: Some(position),
+            registered_type: Some(TypeId::of::<Velocity>()),
+        })
+    );
+
+    assert_eq!(types.len(), 3);
+    assert_eq!(types.id_of::<Position>(), Some(position));
+    assert_eq!(types.type_of(position), Some(TypeId::of::<Position>()));
+    assert_eq!(types.id_of::<Unregistered>(), None);
+}
+
+#[test]
+fn global() {
+    struct GlobalOnly;
+    let id = TypeRegistry::global()
+        .register::<GlobalOnly>("type_registry.global_only")
+        .unwrap();
+    assert_eq!(TypeRegistry::global().id_of::<GlobalOnly>(), Some(id));
+    assert_eq!(
+        TypeRegistry::global().type_of(id),
+        Some(TypeId::of::<GlobalOnly>())
+    );
+    assert!(TypeRegistry::new().is_empty());
+}
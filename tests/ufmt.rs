@@ -0,0 +1,66 @@
+#![cfg(feature = "ufmt")]
+
+use heapless::String;
+use name_id::{id, NameId};
+use ufmt::uwrite;
+
+fn display(id: NameId) -> String<64> {
+    let mut out = String::new();
+    uwrite!(out, "{}", id).unwrap();
+    out
+}
+
+fn debug(id: NameId) -> String<64> {
+    let mut out = String::new();
+    uwrite!(out, "{:?}", id).unwrap();
+    out
+}
+
+#[test]
+fn numeric() {
+    let id = NameId::from_value(42);
+    assert_eq!(display(id), "NameId(42)");
+    assert_eq!(debug(id), "NameId(42)");
+}
+
+#[test]
+fn matches_core_fmt() {
+    let id = id!(boot_stage);
+    assert_eq!(display(id).as_str(), id.to_string());
+    assert_eq!(debug(id).as_str(), format!("{:?}", id));
+}
+
+#[cfg(all(debug_assertions, feature = "debug_name"))]
+#[test]
+fn label() {
+    let id = id!(boot_stage);
+    assert_eq!(display(id), "#{boot_stage}");
+    assert_eq!(debug(id), "NameId(boot_stage)");
+}
+
+#[cfg(not(any(
+    all(debug_assertions, feature = "debug_name"),
+    feature = "registry",
+    feature = "registry_static"
+)))]
+#[test]
+fn no_label() {
+    let id = id!(boot_stage);
+    let expected = format!("NameId({})", id.value());
+    assert_eq!(display(id).as_str(), expected);
+    assert_eq!(debug(id).as_str(), expected);
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+#[test]
+fn global_name_table() {
+    use name_id::NameTable;
+
+    let id = NameId::from_value(0x5eed);
+    let mut table = NameTable::new();
+    table.insert(id, "boot_stage_table");
+    table.install_global();
+    assert_eq!(display(id), "#{boot_stage_table}");
+    assert_eq!(display(id).as_str(), id.to_string());
+    NameTable::uninstall_global();
+}
@@ -0,0 +1,8 @@
+// Collisions in these tests are specific to the hasher.
+#![cfg(all(feature = "ahash", not(feature = "dynamic_hasher")))]
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,3 @@
+const MISSING: name_id::NameId = name_id::content_id!("tests/fixtures/missing.bin");
+
+fn main() {}
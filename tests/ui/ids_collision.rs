@@ -0,0 +1,7 @@
+// Names below are distinct, but have equal hashes with ahash.
+name_id::ids! {
+    FIRST = "cbd4938f3322cbc5f";
+    SECOND = "ce6aa744e6a6c1f41";
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+name_id::ids! {
+    ui {
+        SAVE;
+        CANCEL = "cancel";
+        save_again = "save";
+    }
+}
+
+fn main() {
+    let _ = (ui::SAVE, ui::CANCEL);
+}
@@ -0,0 +1,11 @@
+name_id::verify_ids_in!("../fixtures/keys.txt"; "menu.title", "menu.titel");
+
+name_id::ids! {
+    #[name_id(verify = "../fixtures/keys.txt")]
+    hud {
+        HEALTH;
+        HELTH;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,97 @@
+use name_id::{id, NameId, VarintError};
+
+const BOUNDARIES: [(u64, usize); 12] = [
+    (0, 1),
+    (1, 1),
+    (0x7f, 1),
+    (0x80, 2),
+    (0x3fff, 2),
+    (0x4000, 3),
+    (u32::MAX as u64, 5),
+    (1 << 56, 9),
+    ((1 << 63) - 1, 9),
+    (1 << 63, 10),
+    (u64::MAX - 1, 10),
+    (u64::MAX, 10),
+];
+
+#[test]
+fn round_trip_boundaries() {
+    for (value, len) in BOUNDARIES {
+        let id = NameId::from_value(value);
+        let mut buf = [0xaa; NameId::VARINT_MAX_LEN];
+        assert_eq!(id.varint_len(), len, "{:#x}", value);
+        assert_eq!(id.encode_varint(&mut buf), len, "{:#x}", value);
+        assert_eq!(NameId::decode_varint(&buf[..len]), Ok((id, len)));
+        // trailing bytes are left for the caller
+        assert_eq!(NameId::decode_varint(&buf), Ok((id, len)));
+    }
+}
+
+#[test]
+fn round_trip_hashes() {
+    for id in [id!(player_spawn), id!(varint), id!("")] {
+        let mut buf = [0; NameId::VARINT_MAX_LEN];
+        let len = id.encode_varint(&mut buf);
+        assert_eq!(NameId::decode_varint(&buf[..len]), Ok((id, len)));
+    }
+}
+
+#[test]
+fn known_encodings() {
+    let mut buf = [0; NameId::VARINT_MAX_LEN];
+    let len = NameId::from_value(300).encode_varint(&mut buf);
+    assert_eq!(&buf[..len], [0xac, 0x02]);
+    let len = NameId::from_value(u64::MAX).encode_varint(&mut buf);
+    assert_eq!(
+        &buf[..len],
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+    );
+}
+
+#[test]
+fn exact_buffer() {
+    let mut buf = [0; 2];
+    assert_eq!(NameId::from_value(300).encode_varint(&mut buf), 2);
+}
+
+#[test]
+#[should_panic]
+fn short_buffer() {
+    let mut buf = [0; 1];
+    NameId::from_value(300).encode_varint(&mut buf);
+}
+
+#[test]
+fn truncated() {
+    assert_eq!(NameId::decode_varint(&[]), Err(VarintError::Truncated));
+    assert_eq!(NameId::decode_varint(&[0x80]), Err(VarintError::Truncated));
+    assert_eq!(
+        NameId::decode_varint(&[0xff; 9]),
+        Err(VarintError::Truncated)
+    );
+}
+
+#[test]
+fn overlong() {
+    // 11 bytes
+    let mut eleven = [0x80; 11];
+    eleven[10] = 0x00;
+    assert_eq!(NameId::decode_varint(&eleven), Err(VarintError::Overlong));
+    // redundant zero continuation bytes
+    assert_eq!(
+        NameId::decode_varint(&[0x80, 0x00]),
+        Err(VarintError::Overlong)
+    );
+    assert_eq!(
+        NameId::decode_varint(&[0x81, 0x80, 0x00]),
+        Err(VarintError::Overlong)
+    );
+}
+
+#[test]
+fn overflow() {
+    let mut buf = [0xff; NameId::VARINT_MAX_LEN];
+    buf[9] = 0x02;
+    assert_eq!(NameId::decode_varint(&buf), Err(VarintError::Overflow));
+}
@@ -0,0 +1,25 @@
+#![cfg(not(feature = "dynamic_hasher"))]
+
+use name_id::{id, ids, verify_ids_in};
+
+verify_ids_in!("fixtures/keys.txt"; "menu.title", "menu.subtitle", "menu.quit");
+
+ids! {
+    #[name_id(verify = "fixtures/keys.txt")]
+    menu {
+        TITLE;
+        SUBTITLE;
+    }
+    #[name_id(verify = "fixtures/keys.txt")]
+    hud {
+        HEALTH;
+        AMMO = "ammo";
+    }
+}
+
+#[test]
+fn listed_names() {
+    verify_ids_in!("fixtures/keys.txt"; "hud.health", "hud.ammo",);
+    assert_eq!(menu::TITLE, id!("menu.title"));
+    assert_eq!(hud::AMMO, id!("hud.ammo"));
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "wasm")]
+
+use name_id::{id, NameId};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+#[test]
+fn parts_round_trip() {
+    let id = id!(js_handle);
+    assert_eq!(
+        (id.value_hi() as u64) << 32 | id.value_lo() as u64,
+        id.value()
+    );
+    assert_eq!(NameId::from_parts(id.value_hi(), id.value_lo()), id);
+    assert_eq!(
+        NameId::from_parts(u32::MAX, 0).value(),
+        0xffff_ffff_0000_0000
+    );
+}
+
+#[test]
+fn string_round_trip() {
+    let id = NameId::from_value(u64::MAX - 1);
+    assert_eq!(id.to_js_string(), "18446744073709551614");
+    assert_eq!(NameId::from_js_string(&id.to_js_string()), Some(id));
+    assert_eq!(NameId::from_js_string("0xfffffffffffffffe"), Some(id));
+    assert_eq!(NameId::from_js_string("-1"), None);
+    assert_eq!(NameId::from_js_string("js_handle"), None);
+}
+
+#[cfg(target_arch = "wasm32")]
+#[test]
+fn bigint_round_trip() {
+    let id = NameId::from_value(u64::MAX - 1);
+    let bigint = js_sys::BigInt::from(id);
+    assert_eq!(NameId::try_from(bigint), Ok(id));
+    assert!(NameId::try_from(js_sys::BigInt::from(-1i64)).is_err());
+}
+
+// values of `ahash` on wasm32 only match reference values with `portable_hash`
+#[cfg(feature = "portable_hash")]
+#[test]
+fn golden_vectors() {
+    name_id::self_test().unwrap();
+}
@@ -0,0 +1,73 @@
+// Collision in fixture crates is specific to the hasher.
+#![cfg(feature = "ahash")]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/collision_crates");
+
+fn build(registry: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO"))
+        .arg("build")
+        .args(args)
+        .current_dir(FIXTURES)
+        .env("NAME_ID_REGISTRY", registry)
+        .env(
+            "CARGO_TARGET_DIR",
+            concat!(env!("CARGO_TARGET_TMPDIR"), "/collision_crates"),
+        )
+        .output()
+        .expect("can't run cargo")
+}
+
+fn registry_path() -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("name-id-registry.jsonl");
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn collision_across_crates() {
+    let registry = registry_path();
+    // fixtures aren't rebuilt unless they're changed, so they wouldn't be
+    // recorded in the new registry
+    let clean = Command::new(env!("CARGO"))
+        .args(["clean", "-p", "alpha", "-p", "beta"])
+        .current_dir(FIXTURES)
+        .env(
+            "CARGO_TARGET_DIR",
+            concat!(env!("CARGO_TARGET_TMPDIR"), "/collision_crates"),
+        )
+        .output()
+        .expect("can't run cargo");
+    assert!(clean.status.success());
+
+    let output = build(&registry, &["-p", "beta"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "build succeeded:\n{stderr}");
+    assert!(
+        stderr.contains(
+            "id collision: `ce6aa744e6a6c1f41` has the same hash as `cbd4938f3322cbc5f` declared in crate `alpha` at alpha/src/lib.rs:5"
+        ),
+        "unexpected error:\n{stderr}"
+    );
+    assert!(
+        stderr.contains("beta/src/lib.rs:4"),
+        "unexpected error:\n{stderr}"
+    );
+
+    let contents = std::fs::read_to_string(&registry).unwrap();
+    assert!(contents.contains(r#""name":"cbd4938f3322cbc5f","crate":"alpha""#));
+    assert!(contents.contains(r#""name":"shared","crate":"beta""#));
+
+    // rebuilding alpha removes its stale entry
+    let output = build(&registry, &["-p", "beta", "--features", "alpha/renamed"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "build failed:\n{stderr}");
+
+    let contents = std::fs::read_to_string(&registry).unwrap();
+    assert!(!contents.contains("cbd4938f3322cbc5f"));
+    assert!(contents.contains(r#""name":"alpha renamed","crate":"alpha""#));
+    assert!(contents.contains(r#""name":"ce6aa744e6a6c1f41","crate":"beta""#));
+    assert_eq!(contents.matches(r#""name":"shared""#).count(), 2);
+}
@@ -0,0 +1,140 @@
+use name_id::{id, BufferTooSmall, NameId};
+
+fn check_parity(id: NameId) {
+    let mut buf = [0; 64];
+    assert_eq!(id.write_display(&mut buf).unwrap(), id.to_string());
+    assert_eq!(id.write_hex(&mut buf).unwrap(), format!("{:x}", id));
+}
+
+#[test]
+fn parity_with_fmt() {
+    for value in [0, 1, 9, 10, 1234, 1 << 63, u64::MAX] {
+        check_parity(NameId::from_value(value));
+    }
+    check_parity(id!(player_spawn));
+    check_parity(id!(""));
+}
+
+#[test]
+fn hex_matches_u64() {
+    let mut buf = [0; 16];
+    for value in [0, 0xf, 0x10, 0xdead_beef, u64::MAX] {
+        assert_eq!(
+            NameId::from_value(value).write_hex(&mut buf).unwrap(),
+            format!("{:x}", value)
+        );
+    }
+    assert_eq!(
+        format!("{:#018X}", NameId::from_value(0xab)),
+        "0x00000000000000AB"
+    );
+}
+
+#[test]
+fn exact_fit() {
+    let id = NameId::from_value(1234);
+    let mut buf = [0; "NameId(1234)".len()];
+    assert_eq!(id.write_display(&mut buf).unwrap(), "NameId(1234)");
+    let mut buf = [0; 3];
+    assert_eq!(
+        NameId::from_value(0xabc).write_hex(&mut buf).unwrap(),
+        "abc"
+    );
+}
+
+#[test]
+fn too_small() {
+    let id = NameId::from_value(1234);
+    let mut buf = [0; "NameId(1234)".len() - 1];
+    assert_eq!(id.write_display(&mut buf), Err(BufferTooSmall));
+    let mut buf = [0; 2];
+    assert_eq!(
+        NameId::from_value(0xabc).write_hex(&mut buf),
+        Err(BufferTooSmall)
+    );
+    assert_eq!(
+        NameId::from_value(0).write_hex(&mut []),
+        Err(BufferTooSmall)
+    );
+}
+
+#[test]
+fn max_len_always_fits() {
+    let mut buf = [0; NameId::MAX_DISPLAY_LEN];
+    assert_eq!(
+        NameId::from_value(u64::MAX)
+            .write_display(&mut buf)
+            .unwrap(),
+        "NameId(18446744073709551615)"
+    );
+    assert_eq!(
+        NameId::from_value(u64::MAX).write_hex(&mut buf).unwrap(),
+        "ffffffffffffffff"
+    );
+}
+
+#[cfg(any(
+    all(debug_assertions, feature = "debug_name"),
+    feature = "registry",
+    feature = "registry_static"
+))]
+mod named {
+    use super::*;
+
+    fn named(id: NameId, name: &'static str) -> NameId {
+        #[cfg(any(feature = "registry", feature = "registry_static"))]
+        name_id::register(id, name);
+        let _ = name;
+        id
+    }
+
+    #[test]
+    fn exact_fit() {
+        let id = named(id!(player_spawn), "player_spawn");
+        let mut buf = [0; "#{player_spawn}".len()];
+        assert_eq!(id.write_display(&mut buf).unwrap(), "#{player_spawn}");
+    }
+
+    #[test]
+    fn truncated() {
+        let id = named(id!(player_spawn), "player_spawn");
+        let mut buf = [0; "#{player_spawn}".len() - 1];
+        assert_eq!(id.write_display(&mut buf).unwrap(), "#{player_s...}");
+        let mut buf = [0; "#{...}".len()];
+        assert_eq!(id.write_display(&mut buf).unwrap(), "#{...}");
+        let mut buf = [0; "#{...}".len() - 1];
+        assert_eq!(id.write_display(&mut buf), Err(BufferTooSmall));
+    }
+
+    #[test]
+    fn truncated_at_char_boundary() {
+        let id = named(id!("ünïcødé"), "ünïcødé");
+        // fits "#{", "ün" and the marker; "ï" would be cut in half
+        let mut buf = [0; "#{ün...}".len()];
+        assert_eq!(id.write_display(&mut buf).unwrap(), "#{ün...}");
+        let mut buf = [0; "#{ü...}".len() + 1];
+        assert_eq!(id.write_display(&mut buf).unwrap(), "#{ün...}");
+        let mut buf = [0; "#{ü...}".len() - 1];
+        assert_eq!(id.write_display(&mut buf).unwrap(), "#{...}");
+    }
+
+    #[test]
+    fn parity_with_fmt() {
+        check_parity(named(id!(player_spawn), "player_spawn"));
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "spin")))]
+#[test]
+fn parity_with_global_name_table() {
+    use name_id::NameTable;
+
+    let id = NameId::from_value(0x7ab1e);
+    let mut table = NameTable::new();
+    table.insert(id, "from_table");
+    table.install_global();
+    let mut buf = [0; 64];
+    assert_eq!(id.write_display(&mut buf).unwrap(), "#{from_table}");
+    check_parity(id);
+    NameTable::uninstall_global();
+}
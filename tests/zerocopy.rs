@@ -0,0 +1,35 @@
+#![cfg(all(feature = "zerocopy", not(feature = "fixed_size")))]
+
+use name_id::{id, NameId};
+use zerocopy::{FromBytes, IntoBytes};
+
+#[repr(C, align(8))]
+struct Aligned([u8; 8]);
+
+#[test]
+fn cast_from_bytes() {
+    let id = id!(packet_kind);
+    let buffer = Aligned(id.value().to_ne_bytes());
+    let cast = NameId::ref_from_bytes(&buffer.0).unwrap();
+    assert_eq!(*cast, id);
+    assert_eq!(cast.value(), id.value());
+}
+
+#[test]
+fn cast_to_bytes() {
+    let id = id!(packet_kind);
+    assert_eq!(id.as_bytes(), id.value().to_ne_bytes());
+
+    let ids = [id!(packet_a), id!(packet_b)];
+    let bytes = ids.as_bytes();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(<[NameId]>::ref_from_bytes(bytes).unwrap(), ids);
+}
+
+#[test]
+fn read_unaligned() {
+    let id = id!(packet_kind);
+    let mut buffer = [0u8; 9];
+    buffer[1..].copy_from_slice(id.as_bytes());
+    assert_eq!(NameId::read_from_bytes(&buffer[1..]).unwrap(), id);
+}